@@ -1,3 +1,4 @@
+use crate::export_claims::ExportClaimsCmd;
 use sc_cli::RunCmd;
 
 #[derive(Debug, clap::Parser)]
@@ -43,4 +44,7 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Export the claims of the template pallet as JSON.
+	ExportClaims(ExportClaimsCmd),
 }
@@ -0,0 +1,110 @@
+//! The `export-claims` subcommand, which dumps the claims registry of the template pallet as JSON.
+
+use pallet_template_rpc::TemplateRuntimeApi;
+use sc_cli::{CliConfiguration, DatabaseParams, PruningParams, SharedParams};
+use solochain_template_runtime::{opaque::Block, AccountId, BlockNumber, Hash};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use std::{
+	fs::File,
+	io::{self, BufWriter, Write},
+	path::PathBuf,
+	sync::Arc,
+};
+
+/// The number of claims asked for in every runtime API call. The runtime may return fewer.
+const PAGE_SIZE: u32 = 50;
+
+/// The first version of the template runtime API that can list the claims of every namespace.
+const ALL_CLAIMS_PAGED_VERSION: u32 = 11;
+
+/// Export the claims of the template pallet, in every namespace.
+///
+/// The claims are written as a JSON array of `{namespace, claim, owner, created_at}` objects, one
+/// page at a time, so the whole registry is never held in memory.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportClaimsCmd {
+	/// The hash of the block to export the claims at. Defaults to the best block.
+	#[arg(long, value_name = "HASH")]
+	pub at: Option<Hash>,
+
+	/// The file to write the claims to. Defaults to stdout.
+	#[arg(long, short, value_name = "PATH")]
+	pub output: Option<PathBuf>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl ExportClaimsCmd {
+	/// Run the command against `client`.
+	pub fn run<C>(&self, client: Arc<C>) -> sc_cli::Result<()>
+	where
+		C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+		C::Api: TemplateRuntimeApi<Block, AccountId, Hash, BlockNumber>,
+	{
+		let at = self.at.unwrap_or_else(|| client.info().best_hash);
+		let api = client.runtime_api();
+		let version = api
+			.api_version::<dyn TemplateRuntimeApi<Block, AccountId, Hash, BlockNumber>>(at)
+			.map_err(|e| e.to_string())?;
+		if version.unwrap_or_default() < ALL_CLAIMS_PAGED_VERSION {
+			return Err(format!("The runtime at block {at:?} cannot list all claims.").into());
+		}
+
+		let mut output: Box<dyn Write> = match &self.output {
+			Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+			None => Box::new(BufWriter::new(io::stdout().lock())),
+		};
+		write!(output, "[")?;
+		let mut start_key = None;
+		let mut first = true;
+		loop {
+			let (claims, next) =
+				api.all_claims_paged(at, start_key, PAGE_SIZE).map_err(|e| e.to_string())?;
+			for (namespace, claim, owner, created_at) in claims {
+				if !first {
+					write!(output, ",")?;
+				}
+				first = false;
+				let claim = serde_json::json!({
+					"namespace": namespace,
+					"claim": claim,
+					"owner": owner,
+					"created_at": created_at,
+				});
+				serde_json::to_writer(&mut output, &claim).map_err(|e| e.to_string())?;
+			}
+			match next {
+				Some(next) => start_key = Some(next),
+				None => break,
+			}
+		}
+		writeln!(output, "]")?;
+		output.flush()?;
+		Ok(())
+	}
+}
+
+impl CliConfiguration for ExportClaimsCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
@@ -0,0 +1,224 @@
+//! RPC interface for the template pallet.
+//!
+//! Exposes the claims registered in the pallet over JSON-RPC, backed by the
+//! [`TemplateRuntimeApi`].
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_template_runtime_api::{ClaimStats, NamespaceId, TemplateApi as TemplateRuntimeApi};
+
+#[rpc(client, server)]
+pub trait TemplateApi<BlockHash, Hash, AccountId, BlockNumber> {
+	/// Returns the owner of `claim` and the block number at which it was claimed, if any.
+	#[method(name = "template_getClaim")]
+	fn get_claim(
+		&self,
+		claim: Hash,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<(AccountId, BlockNumber)>>;
+
+	/// Returns a page of at most `limit` claims owned by `owner`, starting at `start_key`, and
+	/// the `start_key` of the next page if there is one.
+	#[method(name = "template_claimsOfPaged")]
+	fn claims_of_paged(
+		&self,
+		owner: AccountId,
+		start_key: Option<Hash>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<(Vec<Hash>, Option<Hash>)>;
+
+	/// Returns the owner of `claim`, the block number at which it was claimed and the number of
+	/// blocks since then, as of the block queried, if any.
+	#[method(name = "template_resolveClaim")]
+	fn resolve_claim(
+		&self,
+		claim: Hash,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<(AccountId, BlockNumber, BlockNumber)>>;
+
+	/// Hashes `data` as the runtime does and returns the owner of the resulting claim in
+	/// `namespace`, or the default namespace if `None`, and the block number at which it was
+	/// claimed, if any.
+	#[method(name = "template_verifyPreimage")]
+	fn verify_preimage(
+		&self,
+		namespace: Option<NamespaceId>,
+		data: Bytes,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<(AccountId, BlockNumber)>>;
+
+	/// Returns all the claims created from block `from` to block `to`, both included, with the
+	/// owner of each. Fails if the range spans more blocks than the runtime allows.
+	#[method(name = "template_claimsBetween")]
+	fn claims_between(
+		&self,
+		from: BlockNumber,
+		to: BlockNumber,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(Hash, AccountId)>>;
+
+	/// Returns the number of claims in every namespace, the number of them scheduled to expire in
+	/// the next block, and whether claim creation is halted, in a single call.
+	#[method(name = "template_stats")]
+	fn stats(&self, at: Option<BlockHash>) -> RpcResult<ClaimStats>;
+}
+
+/// Provides RPC methods to query the claims registered in the template pallet.
+pub struct Template<C, Block> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Template<C, Block> {
+	/// Creates a new instance of the Template RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to the runtime failed.
+	RuntimeError,
+	/// The requested block is not known to the node.
+	BlockNotFound,
+	/// The requested block range is wider than the runtime allows.
+	RangeTooWide,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::BlockNotFound => 2,
+			Error::RangeTooWide => 3,
+		}
+	}
+}
+
+impl<C, Block> Template<C, Block>
+where
+	Block: BlockT,
+	C: HeaderBackend<Block>,
+{
+	/// Resolves `at` to the hash of a block known to the node, defaulting to the best block.
+	fn block_hash(&self, at: Option<Block::Hash>) -> RpcResult<Block::Hash> {
+		let Some(at) = at else { return Ok(self.client.info().best_hash) };
+		match self.client.header(at) {
+			Ok(Some(_)) => Ok(at),
+			Ok(None) => Err(ErrorObject::owned(
+				Error::BlockNotFound.into(),
+				"Block not found.",
+				Some(format!("{:?}", at)),
+			)),
+			Err(e) => Err(ErrorObject::owned(
+				Error::BlockNotFound.into(),
+				"Unable to look up block.",
+				Some(e.to_string()),
+			)),
+		}
+	}
+}
+
+fn runtime_error(error: impl ToString, desc: &'static str) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string()))
+}
+
+impl<C, Block, Hash, AccountId, BlockNumber>
+	TemplateApiServer<<Block as BlockT>::Hash, Hash, AccountId, BlockNumber> for Template<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: TemplateRuntimeApi<Block, AccountId, Hash, BlockNumber>,
+	Hash: Codec + Send + Sync + 'static,
+	AccountId: Codec + Send + Sync + 'static,
+	BlockNumber: Codec + Send + Sync + 'static,
+{
+	fn get_claim(
+		&self,
+		claim: Hash,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<(AccountId, BlockNumber)>> {
+		let at_hash = self.block_hash(at)?;
+		self.client
+			.runtime_api()
+			.get_claim(at_hash, claim)
+			.map_err(|e| runtime_error(e, "Unable to query claim."))
+	}
+
+	fn claims_of_paged(
+		&self,
+		owner: AccountId,
+		start_key: Option<Hash>,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<(Vec<Hash>, Option<Hash>)> {
+		let at_hash = self.block_hash(at)?;
+		self.client
+			.runtime_api()
+			.claims_of_paged(at_hash, owner, start_key, limit)
+			.map_err(|e| runtime_error(e, "Unable to query claims."))
+	}
+
+	fn resolve_claim(
+		&self,
+		claim: Hash,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<(AccountId, BlockNumber, BlockNumber)>> {
+		let at_hash = self.block_hash(at)?;
+		self.client
+			.runtime_api()
+			.resolve_claim(at_hash, claim)
+			.map_err(|e| runtime_error(e, "Unable to resolve claim."))
+	}
+
+	fn verify_preimage(
+		&self,
+		namespace: Option<NamespaceId>,
+		data: Bytes,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<(AccountId, BlockNumber)>> {
+		let at_hash = self.block_hash(at)?;
+		self.client
+			.runtime_api()
+			.verify_preimage(at_hash, namespace, data.to_vec())
+			.map_err(|e| runtime_error(e, "Unable to verify preimage."))
+	}
+
+	fn claims_between(
+		&self,
+		from: BlockNumber,
+		to: BlockNumber,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(Hash, AccountId)>> {
+		let at_hash = self.block_hash(at)?;
+		self.client
+			.runtime_api()
+			.claims_between(at_hash, from, to)
+			.map_err(|e| runtime_error(e, "Unable to query claims."))?
+			.ok_or_else(|| {
+				ErrorObject::owned(Error::RangeTooWide.into(), "Block range too wide.", None::<()>)
+			})
+	}
+
+	fn stats(&self, at: Option<Block::Hash>) -> RpcResult<ClaimStats> {
+		let at_hash = self.block_hash(at)?;
+		self.client
+			.runtime_api()
+			.stats(at_hash)
+			.map_err(|e| runtime_error(e, "Unable to query stats."))
+	}
+}
@@ -0,0 +1,123 @@
+//! Runtime API definition for the template pallet.
+//!
+//! Lets front-ends query the claims registered in the pallet without having to build storage
+//! keys and decode the stored values themselves.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+pub use pallet_template::{ClaimKind, ClaimStats, NamespaceId};
+
+/// A page of claims, each with its namespace, owner and the block number at which it was
+/// claimed, and the key of the next page if there is one, as returned by `all_claims_paged`.
+pub type AllClaimsPage<AccountId, Hash, BlockNumber> =
+	(Vec<(NamespaceId, Hash, AccountId, BlockNumber)>, Option<(NamespaceId, Hash)>);
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the claims registered in the template pallet.
+	///
+	/// Unless they take a namespace, methods only see the claims of the pallet's default
+	/// namespace.
+	#[api_version(11)]
+	pub trait TemplateApi<AccountId, Hash, BlockNumber>
+	where
+		AccountId: Codec,
+		Hash: Codec,
+		BlockNumber: Codec,
+	{
+		/// Returns the owner of `claim` and the block number at which it was claimed, if any.
+		fn get_claim(claim: Hash) -> Option<(AccountId, BlockNumber)>;
+
+		/// Returns all the claims owned by `owner`.
+		fn claims_of(owner: AccountId) -> Vec<Hash>;
+
+		/// Returns all the claims of the given `kind`.
+		///
+		/// Added in version 2 of the API.
+		fn claims_of_kind(kind: ClaimKind) -> Vec<Hash>;
+
+		/// Returns a page of at most `limit` claims owned by `owner`, starting at `start_key`, and
+		/// the `start_key` of the next page if there is one.
+		///
+		/// The runtime caps `limit`, so every page but the last may hold fewer claims than asked.
+		///
+		/// Added in version 3 of the API.
+		fn claims_of_paged(
+			owner: AccountId,
+			start_key: Option<Hash>,
+			limit: u32,
+		) -> (Vec<Hash>, Option<Hash>);
+
+		/// Returns a page of at most `limit` claims, with the owner of each and the block number
+		/// at which it was claimed, starting at `start_key`, and the `start_key` of the next page
+		/// if there is one.
+		///
+		/// As with `claims_of_paged`, the runtime caps `limit`.
+		///
+		/// Added in version 4 of the API.
+		fn claims_paged(
+			start_key: Option<Hash>,
+			limit: u32,
+		) -> (Vec<(Hash, AccountId, BlockNumber)>, Option<Hash>);
+
+		/// Returns the owner of `claim`, the block number at which it was claimed and its age, the
+		/// number of blocks since then, as of the block queried, if any.
+		///
+		/// Added in version 5 of the API.
+		fn resolve_claim(claim: Hash) -> Option<(AccountId, BlockNumber, BlockNumber)>;
+
+		/// Hashes `data` with the runtime's hasher and returns the owner of the resulting claim in
+		/// `namespace`, or the default namespace if `None`, and the block number at which it was
+		/// claimed, if any.
+		///
+		/// This lets verifiers holding the original bytes check a claim without reproducing the
+		/// hashing done by the chain.
+		///
+		/// Added in version 6 of the API.
+		fn verify_preimage(
+			namespace: Option<NamespaceId>,
+			data: Vec<u8>,
+		) -> Option<(AccountId, BlockNumber)>;
+
+		/// Returns all the claims created from block `from` to block `to`, both included, with
+		/// the owner of each, or `None` if the range spans more blocks than the runtime allows.
+		///
+		/// Added in version 7 of the API.
+		fn claims_between(from: BlockNumber, to: BlockNumber) -> Option<Vec<(Hash, AccountId)>>;
+
+		/// Returns the hash committing to every claim created or removed, in every namespace, in
+		/// the order it happened.
+		///
+		/// This is an order-dependent accumulator, not a Merkle root: it can be recomputed from
+		/// the sequence of changes, but it cannot prove that a single claim exists.
+		///
+		/// Added in version 8 of the API.
+		fn claims_root() -> Hash;
+
+		/// Returns the claim named `alias`, if any.
+		///
+		/// Added in version 9 of the API.
+		fn resolve_alias(alias: Vec<u8>) -> Option<Hash>;
+
+		/// Returns the number of claims in every namespace, the number of them scheduled to
+		/// expire in the next block, and whether claim creation is halted.
+		///
+		/// Added in version 10 of the API.
+		fn stats() -> ClaimStats;
+
+		/// Returns a page of at most `limit` claims of every namespace, with the namespace and
+		/// owner of each and the block number at which it was claimed, starting at `start_key`,
+		/// and the `start_key` of the next page if there is one.
+		///
+		/// As with `claims_of_paged`, the runtime caps `limit`.
+		///
+		/// Added in version 11 of the API.
+		fn all_claims_paged(
+			start_key: Option<(NamespaceId, Hash)>,
+			limit: u32,
+		) -> AllClaimsPage<AccountId, Hash, BlockNumber>;
+	}
+}
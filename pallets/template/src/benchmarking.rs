@@ -4,8 +4,115 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as Template;
+use alloc::{vec, vec::Vec};
+use codec::Decode;
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::{
+	traits::{
+		fungible::{Inspect, Mutate},
+		EnsureOrigin, Get, Hooks,
+	},
+	weights::Weight,
+	BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::{BlockNumberProvider, Bounded, Hash, Saturating, TrailingZeroInput};
+
+// Give `who` enough balance to pay any claim deposit.
+fn fund<T: Config>(who: &T::AccountId) {
+	T::Fungible::set_balance(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+// The `i`th claim identifier, made from the hash of `i` so that it is never the default one.
+fn claim_id<T: Config>(i: u32) -> T::ClaimId {
+	let hash = T::Hashing::hash_of(&i);
+	T::ClaimId::decode(&mut TrailingZeroInput::new(hash.as_ref())).expect("infinite input; qed")
+}
+
+// An expiry for a claim created now. Scheduling the expiry is the worst case. The harness may
+// move to the next block before the call is dispatched, so leave some room.
+fn expiry<T: Config>() -> Option<BlockNumberFor<T>> {
+	Some(T::BlockNumberProvider::current_block_number().saturating_add(10u32.into()))
+}
+
+// The longest IPFS identifier that can be attached to a claim.
+fn longest_cid() -> Option<Cid> {
+	let mut cid = vec![b'a'; MAX_CID_LEN as usize];
+	cid[0] = b'b';
+	Some(Cid(cid.try_into().expect("exactly the maximum length; qed")))
+}
+
+// Add `n` placeholder claims to the owner index of `who`, so that the call being measured runs
+// for an account that owns as many claims as it may. The placeholders are not claims themselves.
+fn fill_owner_index<T: Config>(who: &T::AccountId, n: u32) {
+	let start = ClaimCountByOwner::<T>::get(who);
+	for i in start..start.saturating_add(n) {
+		ClaimsByOwner::<T>::insert(who, (DEFAULT_NAMESPACE, claim_id::<T>(u32::MAX - i)), ());
+	}
+	ClaimCountByOwner::<T>::insert(who, start.saturating_add(n));
+}
+
+// Have `who` own the claim `claim`, in the middle of an otherwise full owner index, which is
+// the worst case for removing it from the index.
+fn claim_in_full_index<T: Config>(
+	who: &T::AccountId,
+	claim: &T::ClaimId,
+) -> Result<(), BenchmarkError> {
+	let others = T::MaxClaimsPerAccount::get().saturating_sub(1);
+	fill_owner_index::<T>(who, others / 2);
+	Template::<T>::create_claim(
+		RawOrigin::Signed(who.clone()).into(),
+		DEFAULT_NAMESPACE,
+		claim.clone(),
+		ClaimKind::Document,
+		HashAlgo::Blake2_256,
+		Some(vec![0u8; T::MaxClaimMetadataLen::get() as usize]),
+		expiry::<T>(),
+		None,
+		longest_cid(),
+	)?;
+	fill_owner_index::<T>(who, others - others / 2);
+	Ok(())
+}
+
+// Have `who` own the claim `claim` in the default namespace, without metadata or expiry.
+fn own_claim<T: Config>(who: &T::AccountId, claim: &T::ClaimId) -> Result<(), BenchmarkError> {
+	Template::<T>::create_claim(
+		RawOrigin::Signed(who.clone()).into(),
+		DEFAULT_NAMESPACE,
+		claim.clone(),
+		ClaimKind::Document,
+		HashAlgo::Blake2_256,
+		None,
+		None,
+		None,
+		None,
+	)?;
+	Ok(())
+}
+
+// The `i`th tag of the longest allowed length.
+fn longest_tag<T: Config>(i: u32) -> Vec<u8> {
+	let mut tag = i.to_le_bytes().to_vec();
+	tag.resize(T::MaxTagLen::get() as usize, b'a');
+	tag
+}
+
+// The `i`th alias of the longest allowed length.
+fn longest_alias<T: Config>(i: u32) -> Vec<u8> {
+	let mut alias = i.to_le_bytes().to_vec();
+	alias.resize(T::MaxAliasLen::get() as usize, b'a');
+	alias
+}
+
+// Add `n` placeholder entries to the claims expiring in block `at`.
+fn fill_expiry_bucket<T: Config>(at: BlockNumberFor<T>, n: u32) -> Result<(), BenchmarkError> {
+	for i in 0..n {
+		ExpiringAt::<T>::try_append(at, (DEFAULT_NAMESPACE, claim_id::<T>(u32::MAX - i)))
+			.map_err(|_| BenchmarkError::Stop("expiry bucket full"))?;
+	}
+	Ok(())
+}
 
 #[benchmarks]
 mod benchmarks {
@@ -31,5 +138,622 @@ mod benchmarks {
 		assert_eq!(Something::<T>::get(), Some(101u32));
 	}
 
+	#[benchmark]
+	fn create_claim() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let claim = claim_id::<T>(0);
+		// Attaching the largest allowed metadata and IPFS identifier, and filling the last free
+		// slot of the owner index, is the worst case.
+		let metadata = vec![0u8; T::MaxClaimMetadataLen::get() as usize];
+		fill_owner_index::<T>(&caller, T::MaxClaimsPerAccount::get().saturating_sub(1));
+		#[extrinsic_call]
+		create_claim(
+			RawOrigin::Signed(caller.clone()),
+			DEFAULT_NAMESPACE,
+			claim.clone(),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			Some(metadata),
+			expiry::<T>(),
+			None,
+			longest_cid(),
+		);
+
+		assert_eq!(
+			Template::<T>::claim_info(DEFAULT_NAMESPACE, claim),
+			Some((caller, T::BlockNumberProvider::current_block_number()))
+		);
+	}
+
+	// The worst case of `create_claim` while `Config::EnforceAllowlist` is set, which also reads
+	// the allowlist. The caller is allowlisted, so the call goes through either way.
+	#[benchmark]
+	fn create_claim_allowlisted() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		Allowlist::<T>::insert(&caller, ());
+		let claim = claim_id::<T>(0);
+		let metadata = vec![0u8; T::MaxClaimMetadataLen::get() as usize];
+		fill_owner_index::<T>(&caller, T::MaxClaimsPerAccount::get().saturating_sub(1));
+		#[extrinsic_call]
+		create_claim(
+			RawOrigin::Signed(caller.clone()),
+			DEFAULT_NAMESPACE,
+			claim.clone(),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			Some(metadata),
+			expiry::<T>(),
+			None,
+			longest_cid(),
+		);
+
+		assert_eq!(
+			Template::<T>::claim_info(DEFAULT_NAMESPACE, claim),
+			Some((caller, T::BlockNumberProvider::current_block_number()))
+		);
+	}
+
+	#[benchmark]
+	fn revoke_claim() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let claim = claim_id::<T>(0);
+		claim_in_full_index::<T>(&caller, &claim)?;
+		#[extrinsic_call]
+		revoke_claim(RawOrigin::Signed(caller.clone()), DEFAULT_NAMESPACE, claim.clone());
+
+		assert!(!Claims::<T>::contains_key(DEFAULT_NAMESPACE, &claim));
+		assert!(!ClaimMetadata::<T>::contains_key(DEFAULT_NAMESPACE, &claim));
+		assert_eq!(
+			Template::<T>::claim_count_of(&caller),
+			T::MaxClaimsPerAccount::get().saturating_sub(1)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_claim_by_preimage(
+		n: Linear<0, { T::MaxPreimageLen::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let data = vec![0u8; n as usize];
+		let claim =
+			Template::<T>::claim_of_preimage(&data).ok_or(BenchmarkError::Stop("claim id"))?;
+		claim_in_full_index::<T>(&caller, &claim)?;
+		#[extrinsic_call]
+		revoke_claim_by_preimage(RawOrigin::Signed(caller.clone()), data);
+
+		assert!(!Claims::<T>::contains_key(DEFAULT_NAMESPACE, &claim));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_revoke_claim() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		fund::<T>(&owner);
+		let claim = claim_id::<T>(0);
+		claim_in_full_index::<T>(&owner, &claim)?;
+		let origin =
+			T::ClaimAdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		// Slashing the deposit is the worst case.
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, DEFAULT_NAMESPACE, claim.clone(), true);
+
+		assert!(!Claims::<T>::contains_key(DEFAULT_NAMESPACE, &claim));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_claim() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let dest: T::AccountId = account("dest", 0, 0);
+		fund::<T>(&caller);
+		// The deposit is held for `dest`, which only needs to exist. Funding it like the caller
+		// would overflow the total issuance when moving the deposit.
+		T::Fungible::set_balance(&dest, T::Fungible::minimum_balance());
+		let claim = claim_id::<T>(0);
+		// Moving the claim out of a full owner index, and into the last free slot of the
+		// destination's, is the worst case.
+		claim_in_full_index::<T>(&caller, &claim)?;
+		fill_owner_index::<T>(&dest, T::MaxClaimsPerAccount::get().saturating_sub(1));
+		#[extrinsic_call]
+		transfer_claim(
+			RawOrigin::Signed(caller.clone()),
+			DEFAULT_NAMESPACE,
+			claim.clone(),
+			dest.clone(),
+		);
+
+		assert_eq!(Template::<T>::owner_of(DEFAULT_NAMESPACE, &claim), Some(dest.clone()));
+		assert_eq!(Template::<T>::claim_count_of(&dest), T::MaxClaimsPerAccount::get());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_claims(n: Linear<1, { T::MaxBatchSize::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let claims: Vec<_> = (0..n).map(claim_id::<T>).collect();
+		let claims = BoundedVec::try_from(claims).unwrap();
+		// Filling the owner index up to its bound is the worst case.
+		fill_owner_index::<T>(&caller, T::MaxClaimsPerAccount::get().saturating_sub(n));
+		#[extrinsic_call]
+		create_claims(
+			RawOrigin::Signed(caller.clone()),
+			DEFAULT_NAMESPACE,
+			claims,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			false,
+		);
+
+		assert_eq!(Template::<T>::claim_count_of(&caller), T::MaxClaimsPerAccount::get().max(n));
+	}
+
+	#[benchmark]
+	fn set_claim_metadata() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let claim = claim_id::<T>(0);
+		Template::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			DEFAULT_NAMESPACE,
+			claim.clone(),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		// Growing the metadata to the largest allowed length is the worst case, as it holds the
+		// largest extra deposit.
+		let metadata = vec![0u8; T::MaxClaimMetadataLen::get() as usize];
+		#[extrinsic_call]
+		set_claim_metadata(
+			RawOrigin::Signed(caller),
+			DEFAULT_NAMESPACE,
+			claim.clone(),
+			Some(metadata.clone()),
+		);
+
+		assert_eq!(
+			ClaimMetadata::<T>::get(DEFAULT_NAMESPACE, &claim)
+				.map(|metadata| metadata.into_inner()),
+			Some(metadata)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_owner_note() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		claim_in_full_index::<T>(&caller, &claim_id::<T>(0))?;
+		// Replacing the longest note is the worst case, so give one to every claim of the caller.
+		let note = vec![0u8; T::MaxNoteLen::get() as usize];
+		let claims: Vec<_> = Template::<T>::iter_claims()
+			.filter(|(_, _, (owner, _))| *owner == caller)
+			.map(|(namespace, claim, _)| (namespace, claim))
+			.collect();
+		for (namespace, claim) in &claims {
+			ClaimNotes::<T>::insert(
+				namespace,
+				claim,
+				ClaimNoteOf::<T>::truncate_from(note.clone()),
+			);
+		}
+		let (namespace, claim) = claims.first().cloned().ok_or(BenchmarkError::Stop("no claim"))?;
+		#[extrinsic_call]
+		set_owner_note(RawOrigin::Signed(caller), namespace, claim.clone(), Some(note.clone()));
+
+		assert_eq!(
+			ClaimNotes::<T>::get(namespace, &claim).map(|note| note.into_inner()),
+			Some(note)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn renew_claim() -> Result<(), BenchmarkError> {
+		// Start past the genesis block, which the harness would move away from before the call.
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let claim = claim_id::<T>(0);
+		Template::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			DEFAULT_NAMESPACE,
+			claim.clone(),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			expiry::<T>(),
+			None,
+			None,
+		)?;
+		let old_expiry = Claims::<T>::get(DEFAULT_NAMESPACE, &claim)
+			.and_then(|info| info.expires_at)
+			.ok_or(BenchmarkError::Stop("no expiry"))?;
+		// Moving the claim out of a full bucket and into the last free slot of another one is the
+		// worst case. The lifetime is set so that the two buckets differ.
+		let lifetime: BlockNumberFor<T> = 100u32.into();
+		ClaimLifetimeOverride::<T>::put(lifetime);
+		let new_expiry = T::BlockNumberProvider::current_block_number().saturating_add(lifetime);
+		let others = T::MaxExpiringPerBlock::get().saturating_sub(1);
+		fill_expiry_bucket::<T>(old_expiry, others)?;
+		fill_expiry_bucket::<T>(new_expiry, others)?;
+		#[extrinsic_call]
+		renew_claim(RawOrigin::Signed(caller), DEFAULT_NAMESPACE, claim.clone());
+
+		assert_eq!(
+			Claims::<T>::get(DEFAULT_NAMESPACE, &claim).and_then(|info| info.expires_at),
+			Some(new_expiry)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn approve_transfer() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, 0);
+		let claim = claim_id::<T>(0);
+		fund::<T>(&caller);
+		own_claim::<T>(&caller, &claim)?;
+		#[extrinsic_call]
+		approve_transfer(RawOrigin::Signed(caller), DEFAULT_NAMESPACE, claim.clone(), to.clone());
+
+		assert_eq!(Approvals::<T>::get(DEFAULT_NAMESPACE, &claim), Some(to));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_manager() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let manager: T::AccountId = account("manager", 0, 0);
+		let claim = claim_id::<T>(0);
+		fund::<T>(&caller);
+		own_claim::<T>(&caller, &claim)?;
+		#[extrinsic_call]
+		set_manager(
+			RawOrigin::Signed(caller),
+			DEFAULT_NAMESPACE,
+			claim.clone(),
+			Some(manager.clone()),
+		);
+
+		assert_eq!(
+			Claims::<T>::get(DEFAULT_NAMESPACE, &claim).and_then(|info| info.manager),
+			Some(manager)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_tag() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let claim = claim_id::<T>(0);
+		fund::<T>(&caller);
+		own_claim::<T>(&caller, &claim)?;
+		// Adding the last tag of the longest allowed length is the worst case.
+		let max = T::MaxTagsPerClaim::get();
+		for i in 1..max {
+			Template::<T>::add_tag(
+				RawOrigin::Signed(caller.clone()).into(),
+				DEFAULT_NAMESPACE,
+				claim.clone(),
+				longest_tag::<T>(i),
+			)?;
+		}
+		#[extrinsic_call]
+		add_tag(RawOrigin::Signed(caller), DEFAULT_NAMESPACE, claim.clone(), longest_tag::<T>(0));
+
+		assert_eq!(Template::<T>::tags_of(DEFAULT_NAMESPACE, &claim).len() as u32, max);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_tag() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let claim = claim_id::<T>(0);
+		fund::<T>(&caller);
+		own_claim::<T>(&caller, &claim)?;
+		// Removing a tag from a claim carrying as many tags as it may is the worst case.
+		let max = T::MaxTagsPerClaim::get();
+		for i in 0..max {
+			Template::<T>::add_tag(
+				RawOrigin::Signed(caller.clone()).into(),
+				DEFAULT_NAMESPACE,
+				claim.clone(),
+				longest_tag::<T>(i),
+			)?;
+		}
+		#[extrinsic_call]
+		remove_tag(
+			RawOrigin::Signed(caller),
+			DEFAULT_NAMESPACE,
+			claim.clone(),
+			longest_tag::<T>(0),
+		);
+
+		assert_eq!(
+			Template::<T>::tags_of(DEFAULT_NAMESPACE, &claim).len() as u32,
+			max.saturating_sub(1)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_alias() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let claim = claim_id::<T>(0);
+		fund::<T>(&caller);
+		own_claim::<T>(&caller, &claim)?;
+		// Replacing an alias, which frees the previous one, is the worst case.
+		Template::<T>::set_alias(
+			RawOrigin::Signed(caller.clone()).into(),
+			DEFAULT_NAMESPACE,
+			claim.clone(),
+			longest_alias::<T>(1),
+		)?;
+		let alias = longest_alias::<T>(0);
+		#[extrinsic_call]
+		set_alias(RawOrigin::Signed(caller), DEFAULT_NAMESPACE, claim.clone(), alias.clone());
+
+		assert_eq!(Template::<T>::resolve_alias(DEFAULT_NAMESPACE, &alias), Some(claim));
+		assert_eq!(Template::<T>::resolve_alias(DEFAULT_NAMESPACE, &longest_alias::<T>(1)), None);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clear_alias() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let claim = claim_id::<T>(0);
+		fund::<T>(&caller);
+		own_claim::<T>(&caller, &claim)?;
+		let alias = longest_alias::<T>(0);
+		Template::<T>::set_alias(
+			RawOrigin::Signed(caller.clone()).into(),
+			DEFAULT_NAMESPACE,
+			claim.clone(),
+			alias.clone(),
+		)?;
+		#[extrinsic_call]
+		clear_alias(RawOrigin::Signed(caller), DEFAULT_NAMESPACE, claim.clone());
+
+		assert_eq!(Template::<T>::resolve_alias(DEFAULT_NAMESPACE, &alias), None);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn freeze_claim() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let claim = claim_id::<T>(0);
+		fund::<T>(&owner);
+		own_claim::<T>(&owner, &claim)?;
+		let origin =
+			T::ClaimAdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, DEFAULT_NAMESPACE, claim.clone());
+
+		assert!(Template::<T>::is_frozen(DEFAULT_NAMESPACE, &claim));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn thaw_claim() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let claim = claim_id::<T>(0);
+		fund::<T>(&owner);
+		own_claim::<T>(&owner, &claim)?;
+		FrozenClaims::<T>::insert(DEFAULT_NAMESPACE, &claim, ());
+		let origin =
+			T::ClaimAdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, DEFAULT_NAMESPACE, claim.clone());
+
+		assert!(!Template::<T>::is_frozen(DEFAULT_NAMESPACE, &claim));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_paused() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ClaimAdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, true);
+
+		assert!(Paused::<T>::get());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reindex_claims(n: Linear<0, { T::MaxBatchSize::get() }>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		// Make `n` claims, and one more so that the call reports more claims to visit, and drop
+		// them all from the owner index as if they were made before it existed.
+		let claims: Vec<_> = (0..n).map(claim_id::<T>).collect();
+		Template::<T>::create_claims(
+			RawOrigin::Signed(caller.clone()).into(),
+			DEFAULT_NAMESPACE,
+			BoundedVec::try_from(claims).unwrap(),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			false,
+		)?;
+		own_claim::<T>(&caller, &claim_id::<T>(n))?;
+		let _ = ClaimsByOwner::<T>::clear_prefix(&caller, u32::MAX, None);
+		ClaimCountByOwner::<T>::remove(&caller);
+		#[extrinsic_call]
+		reindex_claims(RawOrigin::Signed(caller.clone()), n);
+
+		assert_eq!(Template::<T>::claim_count_of(&caller), n);
+		Ok(())
+	}
+
+	// Revoke `n` claims of the caller, and skip `s` more that are locked.
+	#[benchmark]
+	fn revoke_all(
+		n: Linear<0, { T::MaxBatchSize::get() }>,
+		s: Linear<0, { T::MaxClaimsPerAccount::get().saturating_sub(T::MaxBatchSize::get()) }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		for i in n..n.saturating_add(s) {
+			Template::<T>::create_claim(
+				RawOrigin::Signed(caller.clone()).into(),
+				DEFAULT_NAMESPACE,
+				claim_id::<T>(i),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				Some(BlockNumberFor::<T>::max_value()),
+				None,
+			)?;
+		}
+		let claims: Vec<_> = (0..n).map(claim_id::<T>).collect();
+		Template::<T>::create_claims(
+			RawOrigin::Signed(caller.clone()).into(),
+			DEFAULT_NAMESPACE,
+			BoundedVec::try_from(claims).unwrap(),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			false,
+		)?;
+		#[extrinsic_call]
+		revoke_all(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(Template::<T>::claim_count_of(&caller), s);
+		Ok(())
+	}
+
+	// The caller is allowlisted, so that the call goes through whether or not
+	// `Config::EnforceAllowlist` is set.
+	#[benchmark]
+	fn create_pending_claim() -> Result<(), BenchmarkError> {
+		// Start past the genesis block, which the harness would move away from before the call.
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		let caller: T::AccountId = whitelisted_caller();
+		let witness: T::AccountId = account("witness", 0, 0);
+		fund::<T>(&caller);
+		Allowlist::<T>::insert(&caller, ());
+		let claim = claim_id::<T>(0);
+		// Taking the last free slot of the block the claim times out in is the worst case.
+		let timeout =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::PendingTimeout::get());
+		for i in 1..T::MaxExpiringPerBlock::get() {
+			PendingExpiringAt::<T>::try_append(
+				timeout,
+				(DEFAULT_NAMESPACE, claim_id::<T>(u32::MAX - i)),
+			)
+			.map_err(|_| BenchmarkError::Stop("timeout bucket full"))?;
+		}
+		#[extrinsic_call]
+		create_pending_claim(
+			RawOrigin::Signed(caller.clone()),
+			DEFAULT_NAMESPACE,
+			claim.clone(),
+			witness,
+		);
+
+		assert_eq!(
+			PendingClaims::<T>::get(DEFAULT_NAMESPACE, &claim).map(|pending| pending.owner),
+			Some(caller)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn on_initialize_pending_timeout(
+		x: Linear<0, { T::MaxExpiringPerBlock::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let witness: T::AccountId = account("witness", 0, 0);
+		let deposit = T::ClaimDeposit::get().saturating_add(T::Fungible::minimum_balance());
+		// Time out `x` pending claims of distinct owners, so that each one releases the deposit
+		// of its own account.
+		for i in 0..x {
+			let owner: T::AccountId = account("owner", i, 0);
+			T::Fungible::set_balance(&owner, deposit);
+			Template::<T>::create_pending_claim(
+				RawOrigin::Signed(owner).into(),
+				DEFAULT_NAMESPACE,
+				claim_id::<T>(i),
+				witness.clone(),
+			)?;
+		}
+		let timeout =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::PendingTimeout::get());
+		assert_eq!(PendingExpiringAt::<T>::decode_len(timeout).unwrap_or_default(), x as usize);
+
+		#[block]
+		{
+			Template::<T>::on_initialize(timeout);
+		}
+
+		assert_eq!(PendingClaims::<T>::iter().count(), 0);
+		Ok(())
+	}
+
+	// The expiry sweep run by `Hooks::on_idle`, visiting `x` overdue blocks in which no claim
+	// expires. Removing the claims that do expire is measured by `expire_claims`.
+	#[benchmark]
+	fn on_initialize_expire(x: Linear<0, { T::MaxBatchSize::get() }>) {
+		let start = T::BlockNumberProvider::current_block_number().saturating_add(1u32.into());
+		let now = start.saturating_add(x.into()).saturating_sub(1u32.into());
+		CleanupCursor::<T>::put(start);
+
+		#[block]
+		{
+			Template::<T>::remove_expired_claims(now, Weight::MAX, u32::MAX);
+		}
+
+		assert_eq!(CleanupCursor::<T>::get(), Some(now.saturating_add(1u32.into())));
+	}
+
+	#[benchmark]
+	fn expire_claims(
+		x: Linear<0, { T::MaxExpiringPerBlock::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let at = expiry::<T>().expect("always some");
+		let metadata_len = T::MaxClaimMetadataLen::get();
+		let deposit = T::ClaimDeposit::get()
+			.saturating_add(T::MetadataDepositPerByte::get().saturating_mul(metadata_len.into()))
+			.saturating_add(T::Fungible::minimum_balance());
+		// Fill the bucket of `at` with claims of distinct owners carrying the largest metadata, so
+		// that each removal releases its own deposit.
+		for i in 0..x {
+			let owner: T::AccountId = account("owner", i, 0);
+			T::Fungible::set_balance(&owner, deposit);
+			Template::<T>::create_claim(
+				RawOrigin::Signed(owner).into(),
+				DEFAULT_NAMESPACE,
+				claim_id::<T>(i),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				Some(vec![0u8; metadata_len as usize]),
+				Some(at),
+				None,
+				longest_cid(),
+			)?;
+		}
+		assert_eq!(ExpiringAt::<T>::decode_len(at).unwrap_or_default() as u32, x);
+		CleanupCursor::<T>::put(at);
+		let removed;
+
+		#[block]
+		{
+			removed = Template::<T>::remove_expired_claims(at, Weight::MAX, u32::MAX).1;
+		}
+
+		assert_eq!(removed, x);
+		assert!(!ExpiringAt::<T>::contains_key(at));
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
 }
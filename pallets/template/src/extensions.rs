@@ -0,0 +1,95 @@
+//! Transaction extensions that keep doomed claim transactions out of the pool.
+
+use crate::{Call, Claims, Config, PendingClaims, RevokedClaims, DEFAULT_NAMESPACE};
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use frame_support::{traits::IsSubType, DefaultNoBound};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{BlockNumberProvider, DispatchInfoOf, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+};
+
+/// Reject [`Pallet::create_claim`](crate::Pallet::create_claim) and
+/// [`Pallet::create_claim_default`](crate::Pallet::create_claim_default) transactions for a hash
+/// that has already been claimed, is pending confirmation, or is tombstoned, in the same
+/// namespace.
+///
+/// A claim that has expired, but that the sweep has not removed yet, does not count: creating
+/// the hash again takes it over, as [`Pallet::create_claim`](crate::Pallet::create_claim) does.
+///
+/// Such a transaction would fail with [`Error::AlreadyClaimed`](crate::Error::AlreadyClaimed) or
+/// [`Error::ClaimTombstoned`](crate::Error::ClaimTombstoned) anyway, but only once it is included
+/// in a block, after taking up block space and charging the sender. With this extension it is
+/// reported as [`InvalidTransaction::Stale`] and dropped from the pool instead.
+#[derive(Encode, Decode, DefaultNoBound, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckDuplicateClaim<T>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> core::fmt::Debug for CheckDuplicateClaim<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "CheckDuplicateClaim")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> CheckDuplicateClaim<T> {
+	/// Create a new `CheckDuplicateClaim` extension.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckDuplicateClaim<T>
+where
+	T::RuntimeCall: IsSubType<Call<T>>,
+{
+	type AccountId = T::AccountId;
+	type Call = T::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+	const IDENTIFIER: &'static str = "CheckDuplicateClaim";
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		self.validate(who, call, info, len).map(|_| ())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		let (namespace, claim) = match call.is_sub_type() {
+			Some(Call::create_claim { namespace, claim, .. }) => (*namespace, claim),
+			Some(Call::create_claim_default { claim, .. }) => (DEFAULT_NAMESPACE, claim),
+			_ => return Ok(ValidTransaction::default()),
+		};
+		let now = T::BlockNumberProvider::current_block_number();
+		if Claims::<T>::get(namespace, claim).is_some_and(|info| !info.has_expired(now)) ||
+			PendingClaims::<T>::contains_key(namespace, claim) ||
+			RevokedClaims::<T>::contains_key(namespace, claim)
+		{
+			return Err(InvalidTransaction::Stale.into());
+		}
+		Ok(ValidTransaction::default())
+	}
+}
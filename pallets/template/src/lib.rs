@@ -13,6 +13,9 @@
 //! - a dispatchable function that allows a user to set a new value to storage and emits an event
 //!   upon success
 //! - another dispatchable function that causes a custom error to be thrown
+//! - a minimal proof-of-existence registry, where accounts can claim, revoke and transfer ownership
+//!   of a hash within a namespace
+//! - an off-chain worker that mirrors the registry into the node's off-chain storage
 //!
 //! Each pallet section is annotated with an attribute using the `#[pallet::...]` procedural macro.
 //! This macro generates the necessary code for a pallet to be aggregated into a FRAME runtime.
@@ -27,31 +30,60 @@
 //!   (denoted by the `#[pallet::config]` attribute). See: [`Config`].
 //! - A **means to store pallet-specific data** (denoted by the `#[pallet::storage]` attribute).
 //!   See: [`storage_types`].
+//! - A **genesis configuration** to seed pallet storage when the chain is launched (denoted by the
+//!   `#[pallet::genesis_config]` attribute). See: [`GenesisConfig`].
 //! - A **declaration of the events** this pallet emits (denoted by the `#[pallet::event]`
 //!   attribute). See: [`Event`].
 //! - A **declaration of the errors** that this pallet can throw (denoted by the `#[pallet::error]`
 //!   attribute). See: [`Error`].
+//! - A **set of hooks** that are executed at specific points of a block's lifecycle (denoted by the
+//!   `#[pallet::hooks]` attribute). See: [`Hooks`](frame_support::traits::Hooks).
 //! - A **set of dispatchable functions** that define the pallet's functionality (denoted by the
 //!   `#[pallet::call]` attribute). See: [`dispatchables`].
 //!
 //! Run `cargo doc --package pallet-template --open` to view this pallet's documentation.
+//!
+//! ## Testing pallets built on this one
+//!
+//! With the `test-utils` feature, the mock runtime used by this pallet's own tests is exported as
+//! `pallet_template::mock`. Other crates can add `pallet-template` with that feature to their
+//! `dev-dependencies` and set up a test environment with claims already registered:
+//!
+//! ```ignore
+//! use pallet_template::mock::{new_test_ext_with_claims, TemplateModule};
+//!
+//! new_test_ext_with_claims(vec![(claim, 1)]).execute_with(|| {
+//!     assert_eq!(TemplateModule::owner_of(pallet_template::DEFAULT_NAMESPACE, &claim), Some(1));
+//! });
+//! ```
+//!
+//! The same feature, or `runtime-benchmarks`, adds [`Pallet::iter_claims`] to enumerate the
+//! registry without reaching into its storage.
 
 // We make sure this pallet uses `no_std` for compiling to Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
 // FRAME pallets require their own "mock runtimes" to be able to run unit tests. This module
-// contains a mock runtime specific for testing this pallet's functionality.
-#[cfg(test)]
-mod mock;
+// contains a mock runtime specific for testing this pallet's functionality, and is exported with
+// the `test-utils` feature so that the tests of pallets built on this one can reuse it.
+#[cfg(any(test, feature = "test-utils"))]
+pub mod mock;
 
 // This module contains the unit tests for this pallet.
 // Learn about pallet unit testing here: https://docs.substrate.io/test/unit-testing/
 #[cfg(test)]
 mod tests;
 
+// Property tests that run random sequences of calls against the mock runtime. They need the
+// `property-tests` feature: `cargo test -p pallet-template --features property-tests`.
+#[cfg(all(test, feature = "property-tests"))]
+mod property_tests;
+
 // Every callable function or "dispatchable" a pallet exposes must have weight values that correctly
 // estimate a dispatchable's execution time. The benchmarking module is used to calculate weights
 // for each dispatchable and generates this pallet's weight.rs file. Learn more about benchmarking here: https://docs.substrate.io/test/benchmark/
@@ -60,32 +92,568 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+// Migrations of the pallet's storage from one version to the next.
+pub mod migrations;
+
+// The off-chain worker mirroring the claims into the node's off-chain storage.
+pub mod offchain;
+
+// The transaction extension dropping duplicate claims from the transaction pool.
+pub mod extensions;
+pub use extensions::CheckDuplicateClaim;
+
+// Traits through which other pallets can read and make claims.
+pub mod traits;
+pub use traits::{ClaimInspect, ClaimRegistry, OnClaimChanged};
+
+/// The target of the pallet's log lines, so that `-l pallet_template=debug` shows them.
+pub const LOG_TARGET: &str = "pallet_template";
+
 // All pallet logic is defined in its own module and must be annotated by the `pallet` attribute.
+//
+// The dispatch code generated for calls returning `DispatchResultWithPostInfo` converts their
+// results into the same type, which newer versions of clippy flag. The constructors generated
+// for calls take all of their arguments, which clippy flags for `create_claim`. The hooks
+// generated with `try-runtime` log errors through `map_err`, which clippy flags as well.
 #[frame_support::pallet]
+#[allow(clippy::useless_conversion, clippy::too_many_arguments, clippy::manual_inspect)]
 pub mod pallet {
 	// Import various useful types required by all FRAME pallets.
 	use super::*;
-	use frame_support::pallet_prelude::*;
+	use alloc::vec::Vec;
+	use frame_support::{
+		dispatch::WithPostDispatchInfo,
+		pallet_prelude::*,
+		storage::StoragePrefixedMap,
+		traits::{
+			fungible::{self, BalancedHold, Inspect, MutateHold},
+			tokens::{Fortitude, Precision, Restriction},
+			Imbalance, OnUnbalanced,
+		},
+		StorageHasher,
+	};
 	use frame_system::pallet_prelude::*;
+	use serde::{Deserialize, Serialize};
+	use sp_runtime::traits::{
+		BlockNumberProvider, CheckedAdd, Hash as _, MaybeSerializeDeserialize, One, Saturating,
+		Zero,
+	};
+
+	/// The in-code storage version.
+	///
+	/// Bump this and add a migration to [`crate::migrations`] whenever the layout of the pallet's
+	/// storage changes.
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(13);
 
 	// The `Pallet` struct serves as a placeholder to implement traits, methods and dispatchables
 	// (`Call`s) in this pallet.
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	/// Default implementations of [`DefaultConfig`], which can be used to implement [`Config`].
+	pub mod config_preludes {
+		use super::*;
+		use frame_support::{
+			derive_impl,
+			traits::{ConstBool, ConstU32},
+		};
+
+		/// The defaults of a solochain like this template's.
+		///
+		/// The types that depend on the runtime, such as its currency, its origins or the type of
+		/// its block numbers, have no default and must still be given.
+		pub struct SolochainDefaultConfig;
+
+		#[derive_impl(frame_system::config_preludes::SolochainDefaultConfig, no_aggregated_types)]
+		impl frame_system::DefaultConfig for SolochainDefaultConfig {}
+
+		#[frame_support::register_default_impl(SolochainDefaultConfig)]
+		impl DefaultConfig for SolochainDefaultConfig {
+			#[inject_runtime_type]
+			type RuntimeEvent = ();
+			#[inject_runtime_type]
+			type RuntimeHoldReason = ();
+			type WeightInfo = ();
+			type MaxClaimMetadataLen = ConstU32<256>;
+			type MaxExpiringPerBlock = ConstU32<100>;
+			type MaxClaimsPerAccount = ConstU32<100>;
+			type MaxTotalClaims = ConstU32<{ u32::MAX }>;
+			type MaxClaimsPerBlock = ConstU32<100>;
+			type SlashDestination = ();
+			type DustDestination = ();
+			type MaxBatchSize = ConstU32<50>;
+			type RejectDefaultHash = ConstBool<true>;
+			type EnforceAllowlist = ConstBool<false>;
+			type TombstoneRevoked = ConstBool<false>;
+			type MaxQueryPageSize = ConstU32<50>;
+			type OnClaimChanged = ();
+			type TrackHistory = ConstBool<true>;
+			type MaxHistoryLen = ConstU32<32>;
+			type MaxTagLen = ConstU32<32>;
+			type MaxTagsPerClaim = ConstU32<16>;
+			type MaxAliasLen = ConstU32<64>;
+			type MaxIterationItems = ConstU32<100>;
+			type MaxNoteLen = ConstU32<128>;
+			type MaxPreimageLen = ConstU32<{ 16 * 1024 }>;
+		}
+	}
+
 	/// The pallet's configuration trait.
 	///
 	/// All our types and constants a pallet depends on must be declared here.
 	/// These types are defined generically and made concrete when the pallet is declared in the
 	/// `runtime/src/lib.rs` file of your chain.
-	#[pallet::config]
+	///
+	/// Most of them have a default in [`DefaultConfig`], so a runtime can derive its
+	/// implementation from one of the [`config_preludes`] with
+	/// [`derive_impl`](frame_support::derive_impl), and only give the rest.
+	#[pallet::config(with_default)]
 	pub trait Config: frame_system::Config {
 		/// The overarching runtime event type.
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		#[pallet::no_default_bounds]
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>
+			+ TryInto<Event<Self>>;
 		/// A type representing the weights required by the dispatchables of this pallet.
 		type WeightInfo: WeightInfo;
+		/// The identifier under which things are claimed, usually the runtime's `Hash`.
+		///
+		/// Changing it on a live chain changes the keys of every claim, so it takes a migration of
+		/// all the pallet's storage.
+		#[pallet::no_default]
+		type ClaimId: Member + Parameter + MaxEncodedLen + MaybeSerializeDeserialize + Default;
+		/// The maximum length, in bytes, of the metadata that can be attached to a claim.
+		///
+		/// Must not exceed [`MAX_CLAIM_METADATA_LEN`].
+		#[pallet::constant]
+		type MaxClaimMetadataLen: Get<u32>;
+		/// The number of blocks after which claims created with [`Pallet::create_claims`] or
+		/// renewed with [`Pallet::renew_claim`] expire and are removed.
+		///
+		/// A lifetime of zero disables expiry for those claims, so they live until they are
+		/// revoked. Claims created with [`Pallet::create_claim`] pick their own expiry instead.
+		///
+		/// This is only the default: [`Pallet::set_claim_lifetime`] can override it.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type ClaimLifetime: Get<BlockNumberFor<Self>>;
+		/// The maximum number of claims that can be scheduled to expire in a single block.
+		type MaxExpiringPerBlock: Get<u32>;
+		/// The maximum number of claims a single account can own.
+		///
+		/// This is enforced with [`ClaimCountByOwner`], and does not bound the [`ClaimsByOwner`]
+		/// index itself, so raising it does not make adding or removing a claim any heavier. It
+		/// does bound the calls that walk all the claims of an account, such as
+		/// [`Pallet::revoke_all`], whose weight is declared for this many claims. Raising it makes
+		/// those calls heavier, and it cannot be lifted altogether.
+		#[pallet::constant]
+		type MaxClaimsPerAccount: Get<u32>;
+		/// The maximum number of claims that can exist at once, across all accounts and
+		/// namespaces, as counted by [`ClaimCount`].
+		///
+		/// Once it is reached, new claims are rejected until some are removed. Set it to
+		/// `u32::MAX` to leave the registry unbounded.
+		#[pallet::constant]
+		type MaxTotalClaims: Get<u32>;
+		/// The maximum number of claims that can be created in a single block, as recorded in
+		/// [`ClaimsByBlock`].
+		///
+		/// Must not be below [`Config::MaxBatchSize`].
+		type MaxClaimsPerBlock: Get<u32>;
+		/// The overarching hold reason.
+		#[pallet::no_default_bounds]
+		type RuntimeHoldReason: From<HoldReason>;
+		/// The currency in which claim deposits are held.
+		#[pallet::no_default]
+		type Fungible: fungible::Inspect<Self::AccountId>
+			+ fungible::Mutate<Self::AccountId>
+			+ fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ fungible::BalancedHold<Self::AccountId>;
+		/// Where the deposits of claims slashed by [`Pallet::force_revoke_claim`] go, such as a
+		/// treasury.
+		#[pallet::no_default_bounds]
+		type SlashDestination: OnUnbalanced<CreditOf<Self>>;
+		/// Where returned deposits go when they cannot be credited to their owner, because the
+		/// owner's balance would stay below the existential deposit. Usually the dust handler of
+		/// the balances pallet.
+		#[pallet::no_default_bounds]
+		type DustDestination: OnUnbalanced<CreditOf<Self>>;
+		/// The amount held from the owner of a claim until it is revoked.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type ClaimDeposit: Get<BalanceOf<Self>>;
+		/// The amount held for every byte of metadata attached to a claim, on top of
+		/// [`Config::ClaimDeposit`].
+		#[pallet::no_default]
+		type MetadataDepositPerByte: Get<BalanceOf<Self>>;
+		/// The origin of the claim administrators, who can revoke any claim, regardless of who
+		/// owns it, slash its deposit, and halt the creation of claims.
+		///
+		/// This is separate from the runtime's root origin so that governance can hand claim
+		/// administration to a dedicated body, such as a `pallet_collective` instance.
+		#[pallet::no_default]
+		type ClaimAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The origin allowed to register claims without paying transaction fees, with
+		/// [`Pallet::create_claim_free`]. The account it resolves to owns the claim.
+		#[pallet::no_default]
+		type FeeExemptOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+		/// The maximum number of claims that can be created in a single call to
+		/// [`Pallet::create_claims`].
+		///
+		/// Must not be zero, nor exceed [`Config::MaxClaimsPerAccount`],
+		/// [`Config::MaxExpiringPerBlock`] or [`Config::MaxClaimsPerBlock`], as a full batch could
+		/// never succeed otherwise.
+		type MaxBatchSize: Get<u32>;
+		/// Whether claims of the default [`Config::ClaimId`] are rejected. For a hash, this is the
+		/// hash of all zeros.
+		///
+		/// Clients that submit the default hash usually do so by mistake, because they never
+		/// actually hashed anything.
+		type RejectDefaultHash: Get<bool>;
+		/// Whether only the accounts in the [`Allowlist`] may create claims.
+		///
+		/// The allowlist is managed by [`Config::ClaimAdminOrigin`] with
+		/// [`Pallet::add_to_allowlist`] and [`Pallet::remove_from_allowlist`]. When this is not
+		/// set, anyone can claim and the allowlist is ignored.
+		type EnforceAllowlist: Get<bool>;
+		/// Whether claims revoked by their owner leave a tombstone in [`RevokedClaims`].
+		///
+		/// A tombstoned hash cannot be claimed again in its namespace, so the registry keeps a
+		/// record that it was claimed and revoked. Only [`Config::ClaimAdminOrigin`] can bring it
+		/// back, with [`Pallet::restore_claim`]. Claims that expire or are force-revoked never
+		/// leave a tombstone.
+		type TombstoneRevoked: Get<bool>;
+		/// The maximum number of claims returned by a single call to
+		/// [`Pallet::claims_of_paged`], whatever limit the caller asks for.
+		type MaxQueryPageSize: Get<u32>;
+		/// The maximum number of blocks spanned by a single call to [`Pallet::claims_between`].
+		///
+		/// Wider ranges are rejected rather than cut short, so callers never mistake a partial
+		/// answer for a complete one.
+		#[pallet::no_default]
+		type MaxQueryRange: Get<BlockNumberFor<Self>>;
+		/// Handler notified when claims are created or removed.
+		#[pallet::no_default_bounds]
+		type OnClaimChanged: OnClaimChanged<Self::AccountId, Self::ClaimId>;
+		/// Whether the lifecycle of every claim is recorded in [`ClaimHistory`].
+		///
+		/// The history outlives the claims and is not covered by their deposits, so chains that do
+		/// not need it should leave it off. Recording it costs one extra storage access for every
+		/// change to a claim.
+		type TrackHistory: Get<bool>;
+		/// The maximum number of entries kept in the [`ClaimHistory`] of a claim.
+		///
+		/// Once a history is full, the oldest entry is dropped to make room for a new one.
+		#[pallet::constant]
+		type MaxHistoryLen: Get<u32>;
+		/// The number of blocks a claim made with [`Pallet::create_pending_claim`] waits for its
+		/// witness before it is dropped. Must not be zero.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type PendingTimeout: Get<BlockNumberFor<Self>>;
+		/// The maximum length of a tag attached to a claim with [`Pallet::add_tag`].
+		#[pallet::constant]
+		type MaxTagLen: Get<u32>;
+		/// The maximum number of tags a claim can carry.
+		#[pallet::constant]
+		type MaxTagsPerClaim: Get<u32>;
+		/// The maximum length of an alias given to a claim with [`Pallet::set_alias`].
+		#[pallet::constant]
+		type MaxAliasLen: Get<u32>;
+		/// The number of blocks an account must wait between two claims, as recorded in
+		/// [`LastClaimAt`].
+		///
+		/// This rate-limits [`Pallet::create_claim`], [`Pallet::create_claims`], where a batch
+		/// counts as a single claim, and [`Pallet::create_pending_claim`]. Zero disables the limit,
+		/// and nothing is recorded then.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type MinClaimInterval: Get<BlockNumberFor<Self>>;
+		/// The maximum number of storage entries visited by a getter that iterates storage and
+		/// may run within a block, such as [`Pallet::try_claims_of`].
+		///
+		/// Such getters fail with [`Error::QueryTooLarge`] rather than go past the cap. The
+		/// getters only meant for the runtime API, such as [`Pallet::claims_of`], are not capped.
+		#[pallet::constant]
+		type MaxIterationItems: Get<u32>;
+		/// The number of blocks that must pass between [`Pallet::commit_claim`] and the
+		/// [`Pallet::reveal_claim`] that creates the committed claim.
+		///
+		/// A claim of the same hash made after the commitment, such as by a front-runner who saw
+		/// the reveal in the pool, does not stand in the way: the earlier commitment wins when it
+		/// is revealed. Zero lets a claim be revealed in the block it was committed in.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type RevealDelay: Get<BlockNumberFor<Self>>;
+		/// The maximum length of the note attached to a claim with [`Pallet::set_owner_note`].
+		#[pallet::constant]
+		type MaxNoteLen: Get<u32>;
+		/// The maximum length of the data hashed by [`Pallet::revoke_claim_by_preimage`], which
+		/// keeps the cost of hashing it bounded.
+		#[pallet::constant]
+		type MaxPreimageLen: Get<u32>;
+		/// The source of the block numbers claims are created, expire, unlock and are rate-limited
+		/// at, and that their history and reveal delays are counted in.
+		///
+		/// This is usually [`frame_system::Pallet`]. A chain that follows the block numbers of
+		/// another chain, such as a parachain following its relay chain, can provide those
+		/// instead. Pending claims still time out in this chain's own blocks.
+		#[pallet::no_default]
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+	}
+
+	/// The balance type of the pallet's [`Config::Fungible`].
+	pub type BalanceOf<T> = <<T as Config>::Fungible as fungible::Inspect<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
+	/// The imbalance created by slashing a deposit in the pallet's [`Config::Fungible`].
+	pub type CreditOf<T> =
+		fungible::Credit<<T as frame_system::Config>::AccountId, <T as Config>::Fungible>;
+
+	/// The reasons for which the pallet holds funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The funds are held as the deposit of a claim.
+		ClaimDeposit,
+	}
+
+	/// The kind of artifact whose hash is claimed.
+	///
+	/// Claims whose kind is unknown, such as those registered at genesis or before kinds were
+	/// recorded, are of kind `Other(0)`.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum ClaimKind {
+		/// A document, such as a contract or a report.
+		Document,
+		/// An image.
+		Image,
+		/// An archive, such as a source tarball.
+		Archive,
+		/// Any other kind of artifact, identified by a number chosen by the users of the chain.
+		Other(u8),
+	}
+
+	/// The hashing algorithm that produced a claimed hash.
+	///
+	/// The algorithm only tells users how to interpret the claimed bytes. It is not part of the
+	/// key of a claim, so the same bytes can be claimed only once in a namespace, whichever
+	/// algorithm produced them. Claims whose algorithm is unknown, such as those registered at
+	/// genesis or before algorithms were recorded, are tagged `Other(0)`.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum HashAlgo {
+		/// BLAKE2b with a 256-bit output.
+		Blake2_256,
+		/// SHA-256.
+		Sha2_256,
+		/// Keccak-256, as used by Ethereum.
+		Keccak256,
+		/// Any other algorithm, identified by a number chosen by the users of the chain.
+		Other(u8),
+	}
+
+	/// The largest [`Config::MaxClaimMetadataLen`] a runtime may set.
+	///
+	/// Metadata is meant to describe or point to the claimed artifact, not to hold it, and all of
+	/// it counts towards the proof size of the calls that read it.
+	pub const MAX_CLAIM_METADATA_LEN: u32 = 4 * 1024;
+
+	/// The maximum length, in bytes, of a [`Cid`].
+	pub const MAX_CID_LEN: u32 = 64;
+
+	/// An IPFS content identifier (CID) attached to a claim, in its string form.
+	///
+	/// Only the form of the identifier is checked, with [`Cid::is_valid`]. Whether it points to
+	/// the claimed content is up to the users of the chain.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Cid(pub BoundedVec<u8, ConstU32<MAX_CID_LEN>>);
+
+	impl Cid {
+		/// Whether this is a CIDv0, 46 base58 characters starting with `Qm`, or a CIDv1 in base32,
+		/// the `b` multibase prefix followed by lowercase base32 characters.
+		pub fn is_valid(&self) -> bool {
+			let base58 = |c: &u8| c.is_ascii_alphanumeric() && !b"0OIl".contains(c);
+			let base32 = |c: &u8| matches!(c, b'a'..=b'z' | b'2'..=b'7');
+			match self.0.as_slice() {
+				cid @ [b'Q', b'm', ..] => cid.len() == 46 && cid.iter().all(base58),
+				[b'b', rest @ ..] => !rest.is_empty() && rest.iter().all(base32),
+				_ => false,
+			}
+		}
+	}
+
+	/// A change to a claim, as recorded in [`ClaimHistory`].
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum HistoryAction {
+		/// The claim was created.
+		Created,
+		/// The claim changed owner.
+		Transferred,
+		/// The claim's expiry was postponed.
+		Renewed,
+		/// The claim was removed, whether it was revoked, force-revoked or expired.
+		Revoked,
 	}
 
+	/// The recorded history of a claim, oldest first.
+	pub type ClaimHistoryOf<T> =
+		BoundedVec<(HistoryAction, BlockNumberFor<T>), <T as Config>::MaxHistoryLen>;
+
+	/// Everything recorded about a claim.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ClaimInfo<AccountId, BlockNumber, Balance> {
+		/// The sequence number assigned to the claim when it was created, from [`NextClaimId`].
+		pub id: u64,
+		/// The account that owns the claim.
+		pub owner: AccountId,
+		/// The block number at which the claim was originally made.
+		pub created_at: BlockNumber,
+		/// The deposit held from the owner.
+		///
+		/// It is recorded rather than read from [`Config::ClaimDeposit`] so that changing the
+		/// constant never releases more or less than was actually held.
+		pub deposit: Balance,
+		/// The block number at which the claim is scheduled to expire, if any.
+		pub expires_at: Option<BlockNumber>,
+		/// The kind of artifact whose hash is claimed.
+		pub kind: ClaimKind,
+		/// The hashing algorithm that produced the claimed hash.
+		pub algo: HashAlgo,
+		/// The last block in which the claim cannot be revoked or transferred by its owner, if it
+		/// is locked.
+		///
+		/// The lock does not hold back [`Pallet::force_revoke_claim`], nor the expiry of the
+		/// claim.
+		pub lock_until: Option<BlockNumber>,
+		/// The account allowed to update the metadata of the claim and to renew it on behalf of
+		/// its owner, if any, as set with [`Pallet::set_manager`].
+		///
+		/// It is cleared when the claim changes owner.
+		pub manager: Option<AccountId>,
+	}
+
+	impl<AccountId, BlockNumber: PartialOrd, Balance> ClaimInfo<AccountId, BlockNumber, Balance> {
+		/// Whether the claim has expired at block `now`, whether or not the sweep has removed it
+		/// yet.
+		///
+		/// Such a claim no longer stands in the way of claiming its hash again.
+		pub fn has_expired(&self, now: BlockNumber) -> bool {
+			self.expires_at.as_ref().is_some_and(|at| *at <= now)
+		}
+	}
+
+	/// The [`ClaimInfo`] type used by a runtime.
+	pub type ClaimInfoOf<T> =
+		ClaimInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, BalanceOf<T>>;
+
+	/// A claim waiting for its witness to confirm it with [`Pallet::confirm_claim`].
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct PendingClaim<AccountId, BlockNumber, Balance> {
+		/// The account that will own the claim once it is confirmed.
+		pub owner: AccountId,
+		/// The only account that can confirm the claim.
+		pub witness: AccountId,
+		/// The deposit held from the owner until the claim is confirmed or dropped.
+		pub deposit: Balance,
+		/// The block at the start of which the claim is dropped if it has not been confirmed.
+		pub expires_at: BlockNumber,
+	}
+
+	/// What is kept of a claim revoked while [`Config::TombstoneRevoked`] is set.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct RevokedClaim<AccountId, BlockNumber> {
+		/// The account that owned the claim.
+		pub owner: AccountId,
+		/// The block number at which the claim was originally made.
+		pub created_at: BlockNumber,
+		/// The block number at which the claim was revoked.
+		pub revoked_at: BlockNumber,
+		/// The kind of artifact whose hash was claimed.
+		pub kind: ClaimKind,
+		/// The hashing algorithm that produced the claimed hash.
+		pub algo: HashAlgo,
+	}
+
+	/// The [`RevokedClaim`] type used by a runtime.
+	pub type RevokedClaimOf<T> =
+		RevokedClaim<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+	/// The [`PendingClaim`] type used by a runtime.
+	pub type PendingClaimOf<T> =
+		PendingClaim<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, BalanceOf<T>>;
+
+	/// An overview of the claims registry, as returned by [`Pallet::stats`].
+	#[derive(
+		Clone,
+		Copy,
+		Default,
+		Encode,
+		Decode,
+		Eq,
+		PartialEq,
+		RuntimeDebug,
+		TypeInfo,
+		MaxEncodedLen,
+		Serialize,
+		Deserialize,
+	)]
+	#[serde(rename_all = "camelCase")]
+	pub struct ClaimStats {
+		/// The number of claims, across all namespaces.
+		pub total: u32,
+		/// The number of claims scheduled to expire in the next block.
+		pub expiring_next_block: u32,
+		/// Whether the creation of new claims is halted with [`Pallet::set_paused`].
+		pub paused: bool,
+	}
+
+	/// A claim, with its owner and the block number at which it was claimed, as listed by
+	/// [`Pallet::claims_paged`].
+	pub type ClaimRecordOf<T> =
+		(<T as Config>::ClaimId, <T as frame_system::Config>::AccountId, BlockNumberFor<T>);
+
+	/// A claim, with its namespace, owner and the block number at which it was claimed, as
+	/// listed by [`Pallet::all_claims_paged`].
+	pub type NamespacedClaimRecordOf<T> = (
+		NamespaceId,
+		<T as Config>::ClaimId,
+		<T as frame_system::Config>::AccountId,
+		BlockNumberFor<T>,
+	);
+
+	/// The key of a claim in [`Claims`], naming its namespace as well as its hash.
+	pub type NamespacedClaimKeyOf<T> = (NamespaceId, <T as Config>::ClaimId);
+
+	/// A claim, with its namespace and owner, as listed by [`Pallet::claims_between`].
+	pub type NamespacedClaimOf<T> =
+		(NamespaceId, <T as Config>::ClaimId, <T as frame_system::Config>::AccountId);
+
+	/// The metadata that can be attached to a claim.
+	pub type ClaimMetadataOf<T> = BoundedVec<u8, <T as Config>::MaxClaimMetadataLen>;
+
+	/// A short label attached to a claim, such as a project name.
+	pub type ClaimTagOf<T> = BoundedVec<u8, <T as Config>::MaxTagLen>;
+
+	/// The tags attached to a claim.
+	pub type ClaimTagsOf<T> = BoundedBTreeSet<ClaimTagOf<T>, <T as Config>::MaxTagsPerClaim>;
+
+	/// A human-readable name for a claim, unique within its namespace.
+	pub type ClaimAliasOf<T> = BoundedVec<u8, <T as Config>::MaxAliasLen>;
+
+	/// A note from the owner of a claim, such as a contact or payment pointer.
+	pub type ClaimNoteOf<T> = BoundedVec<u8, <T as Config>::MaxNoteLen>;
+
+	/// Identifies the application a claim belongs to.
+	///
+	/// The same hash can be claimed once in every namespace, so two applications hashing the same
+	/// document do not conflict.
+	pub type NamespaceId = u16;
+
+	/// The namespace of claims made without naming one, such as with
+	/// [`Pallet::create_claim_default`], at genesis or before namespaces existed.
+	pub const DEFAULT_NAMESPACE: NamespaceId = 0;
+
 	/// A storage item for this pallet.
 	///
 	/// In this template, we are declaring a storage item called `Something` that stores a single
@@ -93,6 +661,342 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Something<T> = StorageValue<_, u32>;
 
+	/// The proof-of-existence claims registered in this pallet.
+	///
+	/// Maps a namespace and a hash claimed in it to its [`ClaimInfo`].
+	#[pallet::storage]
+	pub(super) type Claims<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		T::ClaimId,
+		ClaimInfoOf<T>,
+	>;
+
+	/// The number of entries in [`Claims`].
+	#[pallet::storage]
+	pub(super) type ClaimCount<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// The [`ClaimInfo::id`] of the next claim to be created.
+	///
+	/// Ids are assigned in the order claims are created, and are never reused, even once a claim
+	/// is removed. They give indexers an ordering of the claims that does not depend on block or
+	/// extrinsic indices.
+	#[pallet::storage]
+	pub(super) type NextClaimId<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// Whether the creation of new claims is halted, as set by [`Pallet::set_paused`].
+	#[pallet::storage]
+	pub(super) type Paused<T> = StorageValue<_, bool, ValueQuery>;
+
+	/// The claim lifetime set with [`Pallet::set_claim_lifetime`], in place of
+	/// [`Config::ClaimLifetime`].
+	#[pallet::storage]
+	pub(super) type ClaimLifetimeOverride<T> = StorageValue<_, BlockNumberFor<T>>;
+
+	/// The claims owned by each account, keyed by the account and then by the namespace and hash
+	/// of the claim.
+	///
+	/// This is a reverse index of [`Claims`], and must be updated every time a claim is created,
+	/// removed or changes owner. Each claim is its own entry, so adding or removing one never
+	/// reads the others, and the claims of an account are iterated with its prefix, in the order
+	/// of the map.
+	#[pallet::storage]
+	pub(super) type ClaimsByOwner<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		(NamespaceId, T::ClaimId),
+		(),
+	>;
+
+	/// The number of claims owned by each account.
+	///
+	/// This is the number of entries of each account in [`ClaimsByOwner`], kept alongside it so
+	/// that the count can be read, and [`Config::MaxClaimsPerAccount`] enforced, without
+	/// iterating the index.
+	#[pallet::storage]
+	pub(super) type ClaimCountByOwner<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The claims created in each block that still exist.
+	///
+	/// This is a reverse index of the [`ClaimInfo::created_at`] of every claim in [`Claims`], and
+	/// must be updated every time a claim is created or removed. Claims created before this index
+	/// was introduced are only in it if they fitted when it was built by
+	/// [`crate::migrations::v7::MigrateV6ToV7`].
+	#[pallet::storage]
+	pub(super) type ClaimsByBlock<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(NamespaceId, T::ClaimId), T::MaxClaimsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Optional metadata attached to a claim, such as a short description or an external URI.
+	#[pallet::storage]
+	pub(super) type ClaimMetadata<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		T::ClaimId,
+		ClaimMetadataOf<T>,
+	>;
+
+	/// The tags attached to a claim by its owner with [`Pallet::add_tag`].
+	#[pallet::storage]
+	pub(super) type ClaimTags<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		T::ClaimId,
+		ClaimTagsOf<T>,
+		ValueQuery,
+	>;
+
+	/// The claims scheduled to expire at a given block number.
+	///
+	/// Entries are not removed when a claim is revoked early, so the expiry sweep has to check
+	/// that each scheduled claim still exists and is actually due, according to
+	/// [`ClaimInfo::expires_at`]. The sweep runs in `on_idle`, from the block in
+	/// [`CleanupCursor`] on, so a claim may outlive its expiry by a few blocks when blocks are
+	/// full.
+	#[pallet::storage]
+	pub(super) type ExpiringAt<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(NamespaceId, T::ClaimId), T::MaxExpiringPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The first block whose expired claims may not all have been removed yet.
+	///
+	/// The expiry sweep in `on_idle` starts here, and moves it forward as it clears
+	/// [`ExpiringAt`]. It is unset until the sweep first runs, which then starts at the current
+	/// block.
+	#[pallet::storage]
+	pub(super) type CleanupCursor<T> = StorageValue<_, BlockNumberFor<T>>;
+
+	/// The last claim visited by [`Pallet::reindex_claims`], which resumes after it.
+	///
+	/// It is unset before the first call and once a walk over all of [`Claims`] is complete, so
+	/// the next call starts again from the first claim.
+	#[pallet::storage]
+	pub(super) type ReindexCursor<T: Config> = StorageValue<_, (NamespaceId, T::ClaimId)>;
+
+	/// The claims waiting for their witness, made with [`Pallet::create_pending_claim`].
+	///
+	/// A hash that is pending in a namespace cannot be claimed there by anyone else until it is
+	/// confirmed or dropped.
+	#[pallet::storage]
+	pub(super) type PendingClaims<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		T::ClaimId,
+		PendingClaimOf<T>,
+	>;
+
+	/// The pending claims to be dropped at a given block number, unless they are confirmed first.
+	///
+	/// Entries are not removed when a claim is confirmed, so the sweep has to check that each
+	/// scheduled claim is still pending and actually due, according to
+	/// [`PendingClaim::expires_at`].
+	#[pallet::storage]
+	pub(super) type PendingExpiringAt<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(NamespaceId, T::ClaimId), T::MaxExpiringPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The account each claim's owner has approved to take it over with
+	/// [`Pallet::accept_transfer`].
+	///
+	/// A claim has at most one pending approval. It is cleared whenever the claim changes owner or
+	/// is removed.
+	#[pallet::storage]
+	pub(super) type Approvals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		T::ClaimId,
+		T::AccountId,
+	>;
+
+	/// The changes made to each claim, with the block they were made in, if
+	/// [`Config::TrackHistory`] is set.
+	///
+	/// Unlike [`Claims`], the history is kept when a claim is removed, so the lifecycle of a hash
+	/// can be reconstructed even after the events recording it have been pruned. A hash claimed
+	/// again after its removal carries on with the same history.
+	#[pallet::storage]
+	pub(super) type ClaimHistory<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		T::ClaimId,
+		ClaimHistoryOf<T>,
+		ValueQuery,
+	>;
+
+	/// The accounts allowed to create claims while [`Config::EnforceAllowlist`] is set.
+	#[pallet::storage]
+	pub(super) type Allowlist<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// A single hash committing to every claim created or removed, in the order it happened.
+	///
+	/// Each change folds into the previous value as `hash(root ++ action ++ namespace ++ claim)`,
+	/// starting from the default hash. This is an order-dependent accumulator, not a Merkle root:
+	/// a light client holding the full sequence of changes, as given by the events, can check it
+	/// against a storage proof of this one value, but it cannot prove that a single claim exists.
+	/// On chains that had claims before it was introduced, it only commits to the changes made
+	/// since.
+	#[pallet::storage]
+	pub(super) type ClaimsRoot<T: Config> = StorageValue<_, T::Hash, ValueQuery>;
+
+	/// The claim each alias set with [`Pallet::set_alias`] names, by namespace.
+	#[pallet::storage]
+	pub(super) type Aliases<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		ClaimAliasOf<T>,
+		T::ClaimId,
+	>;
+
+	/// The alias of each claim that has one, the reverse of [`Aliases`].
+	#[pallet::storage]
+	pub(super) type ClaimAliases<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		T::ClaimId,
+		ClaimAliasOf<T>,
+	>;
+
+	/// The block in which each account last made a claim, while [`Config::MinClaimInterval`] is
+	/// not zero.
+	#[pallet::storage]
+	pub(super) type LastClaimAt<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+	/// The claims frozen by [`Config::ClaimAdminOrigin`], for instance while they are disputed.
+	///
+	/// A frozen claim cannot be revoked, transferred or have its metadata changed by its owner,
+	/// until it is thawed or removed.
+	#[pallet::storage]
+	pub(super) type FrozenClaims<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, NamespaceId, Blake2_128Concat, T::ClaimId, ()>;
+
+	/// The tombstones of the claims revoked while [`Config::TombstoneRevoked`] is set.
+	#[pallet::storage]
+	pub(super) type RevokedClaims<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		T::ClaimId,
+		RevokedClaimOf<T>,
+	>;
+
+	/// The IPFS identifiers attached to claims when they were created.
+	#[pallet::storage]
+	pub(super) type ClaimCids<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, NamespaceId, Blake2_128Concat, T::ClaimId, Cid>;
+
+	/// The notes attached to claims by their owners with [`Pallet::set_owner_note`].
+	///
+	/// A note is kept apart from the claim itself, so changing it does not change the claim.
+	#[pallet::storage]
+	pub(super) type ClaimNotes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		T::ClaimId,
+		ClaimNoteOf<T>,
+	>;
+
+	/// The block in which the claims created with [`Pallet::reveal_claim`] were committed to.
+	///
+	/// A revealed claim counts as made at its commitment rather than at its creation, so that a
+	/// later commitment cannot take it over.
+	#[pallet::storage]
+	pub(super) type ClaimCommittedAt<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		T::ClaimId,
+		BlockNumberFor<T>,
+	>;
+
+	/// The commitment each account made with [`Pallet::commit_claim`], and the block it was made
+	/// in.
+	///
+	/// An account has at most one commitment, which is removed once it is revealed. Making
+	/// another one replaces it.
+	#[pallet::storage]
+	pub(super) type Commitments<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (T::Hash, BlockNumberFor<T>)>;
+
+	/// The pallet's genesis configuration.
+	///
+	/// Allows a chain to be launched with claims already registered, e.g. when migrating an
+	/// existing off-chain registry. Claims registered at genesis belong to [`DEFAULT_NAMESPACE`],
+	/// do not carry a deposit and do not expire.
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The claims to register at genesis, as `(claim, owner, block number)` tuples.
+		pub initial_claims: Vec<(T::ClaimId, T::AccountId, BlockNumberFor<T>)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (claim, owner, block_number) in &self.initial_claims {
+				// Catch a misconfigured genesis early rather than silently overwriting claims.
+				assert!(
+					!Claims::<T>::contains_key(DEFAULT_NAMESPACE, claim),
+					"duplicate claim in genesis config"
+				);
+				Pallet::<T>::index_claim(owner, DEFAULT_NAMESPACE, claim.clone())
+					.expect("too many claims for one account in genesis config");
+				Pallet::<T>::index_block(*block_number, DEFAULT_NAMESPACE, claim.clone())
+					.expect("too many claims for one block in genesis config");
+				let id = Pallet::<T>::next_claim_id().expect("too many claims in genesis config");
+				let info = ClaimInfo {
+					id,
+					owner: owner.clone(),
+					created_at: *block_number,
+					deposit: Zero::zero(),
+					expires_at: None,
+					kind: ClaimKind::Other(0),
+					algo: HashAlgo::Other(0),
+					lock_until: None,
+					manager: None,
+				};
+				Claims::<T>::insert(DEFAULT_NAMESPACE, claim, info);
+				ClaimCount::<T>::mutate(|count| count.saturating_inc());
+				Pallet::<T>::accumulate_root(DEFAULT_NAMESPACE, claim, HistoryAction::Created);
+			}
+		}
+	}
+
 	/// Events that functions in this pallet can emit.
 	///
 	/// Events are a simple means of indicating to the outside world (such as dApps, chain explorers
@@ -100,11 +1004,13 @@ pub mod pallet {
 	/// documentation for each event field and its parameters is added to a node's metadata so it
 	/// can be used by external interfaces or tools.
 	///
-	///	The `generate_deposit` macro generates a function on `Pallet` called `deposit_event` which
-	/// will convert the event type of your pallet into `RuntimeEvent` (declared in the pallet's
-	/// [`Config`] trait) and deposit it using [`frame_system::Pallet::deposit_event`].
+	/// ## Topics
+	///
+	/// Every event about a single claim, the one named by [`Event::claim`], is deposited with
+	/// one topic: [`frame_system::Config::Hashing`] of the SCALE encoding of the claim. Clients can
+	/// follow a claim through [`frame_system::Pallet::event_topics`] without decoding every event
+	/// of every block. Other events have no topics.
 	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A user has successfully set a new value.
 		SomethingStored {
@@ -113,6 +1019,423 @@ pub mod pallet {
 			/// The account who set the new value.
 			who: T::AccountId,
 		},
+		/// A hash has been claimed.
+		ClaimCreated {
+			/// The account that now owns the claim.
+			who: T::AccountId,
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The claimed hash.
+			claim: T::ClaimId,
+			/// The kind of artifact whose hash was claimed.
+			kind: ClaimKind,
+			/// The hashing algorithm that produced the claimed hash.
+			algo: HashAlgo,
+			/// The sequence number assigned to the claim.
+			id: u64,
+			/// The IPFS identifier attached to the claim, if any.
+			cid: Option<Cid>,
+		},
+		/// A claim has been revoked by its owner.
+		ClaimRevoked {
+			/// The account that owned the claim.
+			who: T::AccountId,
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The revoked hash.
+			claim: T::ClaimId,
+			/// The block number at which the claim was originally made, so indexers can tell how
+			/// long it existed.
+			created_at: BlockNumberFor<T>,
+			/// The deposit released to the owner, including the deposit for the claim's metadata.
+			/// Zero for claims that were made without a deposit, such as genesis claims.
+			deposit_returned: BalanceOf<T>,
+			/// The sequence number of the claim.
+			id: u64,
+		},
+		/// A batch of claims has been created with [`Pallet::create_claims`].
+		ClaimsBatchCreated {
+			/// The account that now owns the claims.
+			who: T::AccountId,
+			/// The namespace of the claims.
+			namespace: NamespaceId,
+			/// The number of claims created.
+			count: u32,
+		},
+		/// A batch of claims has been created with [`Pallet::create_claims`], skipping some hashes
+		/// that were already claimed.
+		ClaimsBatchPartiallyCreated {
+			/// The account that now owns the claims.
+			who: T::AccountId,
+			/// The namespace of the claims.
+			namespace: NamespaceId,
+			/// The number of claims created.
+			created: u32,
+			/// The hashes that were skipped, in the order of the batch.
+			skipped: BoundedVec<T::ClaimId, T::MaxBatchSize>,
+		},
+		/// Ownership of a claim has been transferred to another account.
+		ClaimTransferred {
+			/// The previous owner of the claim.
+			from: T::AccountId,
+			/// The new owner of the claim.
+			to: T::AccountId,
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The transferred hash.
+			claim: T::ClaimId,
+			/// The sequence number of the claim.
+			id: u64,
+		},
+		/// The owner of a claim has approved another account to take it over.
+		TransferApproved {
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The approved hash.
+			claim: T::ClaimId,
+			/// The account that owns the claim.
+			owner: T::AccountId,
+			/// The account that may now accept the claim.
+			to: T::AccountId,
+		},
+		/// A claim has been revoked by [`Config::ClaimAdminOrigin`].
+		ClaimForceRevoked {
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The revoked hash.
+			claim: T::ClaimId,
+			/// The account that owned the claim.
+			by_owner: T::AccountId,
+			/// The sequence number of the claim.
+			id: u64,
+		},
+		/// The deposit of a claim revoked by [`Config::ClaimAdminOrigin`] has been slashed.
+		ClaimDepositSlashed {
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The revoked hash.
+			claim: T::ClaimId,
+			/// The amount sent to [`Config::SlashDestination`].
+			amount: BalanceOf<T>,
+		},
+		/// The deposit returned for a claim was too small to keep its owner's account alive, and
+		/// has been sent to [`Config::DustDestination`] instead.
+		ClaimDepositDusted {
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The hash whose deposit was returned.
+			claim: T::ClaimId,
+			/// The amount sent to [`Config::DustDestination`].
+			amount: BalanceOf<T>,
+		},
+		/// A claim has been renewed by its owner.
+		ClaimRenewed {
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The renewed hash.
+			claim: T::ClaimId,
+			/// The block number at which the claim now expires.
+			new_expiry: BlockNumberFor<T>,
+		},
+		/// A claim has reached the end of its lifetime and has been removed.
+		ClaimExpired {
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The expired hash.
+			claim: T::ClaimId,
+			/// The sequence number of the claim.
+			id: u64,
+		},
+		/// Expired claims have been removed with [`Pallet::purge_expired`].
+		ExpiredClaimsPurged {
+			/// The number of claims removed by this call.
+			count: u32,
+			/// Whether some blocks may still hold expired claims.
+			more: bool,
+		},
+		/// Claims have been revoked by their owner with [`Pallet::revoke_all`].
+		AllClaimsRevoked {
+			/// The account that owned the claims.
+			who: T::AccountId,
+			/// The number of claims revoked by this call.
+			count: u32,
+			/// The number of claims the account still owns, to be revoked by calling
+			/// [`Pallet::revoke_all`] again.
+			remaining: u32,
+		},
+		/// The metadata attached to a claim has been replaced or cleared by its owner.
+		ClaimMetadataUpdated {
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The hash whose metadata changed.
+			claim: T::ClaimId,
+		},
+		/// A claim has been made that only becomes effective once `witness` confirms it.
+		ClaimPending {
+			/// The account that will own the claim.
+			who: T::AccountId,
+			/// The account that must confirm the claim.
+			witness: T::AccountId,
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The pending hash.
+			claim: T::ClaimId,
+			/// The block at the start of which the claim is dropped if it is not confirmed.
+			expires_at: BlockNumberFor<T>,
+		},
+		/// A pending claim has been confirmed by its witness, and created for its owner.
+		ClaimConfirmed {
+			/// The account that confirmed the claim.
+			witness: T::AccountId,
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The confirmed hash.
+			claim: T::ClaimId,
+		},
+		/// A pending claim was not confirmed in time and has been dropped.
+		PendingClaimExpired {
+			/// The account that made the claim, and got its deposit back.
+			who: T::AccountId,
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The dropped hash.
+			claim: T::ClaimId,
+		},
+		/// Claims have been transferred together with [`Pallet::transfer_claim_bulk`].
+		ClaimsBulkTransferred {
+			/// The previous owner of the claims.
+			from: T::AccountId,
+			/// The new owner of the claims.
+			to: T::AccountId,
+			/// The namespace of the claims.
+			namespace: NamespaceId,
+			/// The number of claims transferred, counting repeated hashes once.
+			count: u32,
+		},
+		/// The creation of new claims has been halted or resumed by [`Config::ClaimAdminOrigin`].
+		PausedStateChanged {
+			/// Whether new claims are now rejected.
+			paused: bool,
+		},
+		/// The manager of a claim has been set or cleared by its owner.
+		ClaimManagerSet {
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The managed hash.
+			claim: T::ClaimId,
+			/// The new manager, or `None` if the claim no longer has one.
+			manager: Option<T::AccountId>,
+		},
+		/// An account has been added to the [`Allowlist`] by [`Config::ClaimAdminOrigin`].
+		AddedToAllowlist {
+			/// The account that may now create claims.
+			who: T::AccountId,
+		},
+		/// An account has been removed from the [`Allowlist`] by [`Config::ClaimAdminOrigin`].
+		RemovedFromAllowlist {
+			/// The account that may no longer create claims.
+			who: T::AccountId,
+		},
+		/// The claim lifetime has been changed by [`Config::ClaimAdminOrigin`].
+		ClaimLifetimeChanged {
+			/// The new lifetime, or `None` if [`Config::ClaimLifetime`] applies again.
+			lifetime: Option<BlockNumberFor<T>>,
+		},
+		/// Some claims have been added to the owner index by [`Pallet::reindex_claims`].
+		ClaimsReindexed {
+			/// The number of claims that were missing from the index and have been added to it.
+			count: u32,
+			/// Whether some claims remain to be visited by another call.
+			more: bool,
+		},
+		/// A tag has been attached to a claim by its owner.
+		ClaimTagAdded {
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The tagged hash.
+			claim: T::ClaimId,
+			/// The added tag.
+			tag: ClaimTagOf<T>,
+		},
+		/// A tag has been removed from a claim by its owner.
+		ClaimTagRemoved {
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The hash the tag was removed from.
+			claim: T::ClaimId,
+			/// The removed tag.
+			tag: ClaimTagOf<T>,
+		},
+		/// A claim has been given an alias by its owner.
+		ClaimAliasSet {
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The hash the alias names.
+			claim: T::ClaimId,
+			/// The new alias.
+			alias: ClaimAliasOf<T>,
+		},
+		/// The alias of a claim has been cleared by its owner.
+		ClaimAliasCleared {
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The hash the alias named.
+			claim: T::ClaimId,
+			/// The cleared alias, free to be given to another claim.
+			alias: ClaimAliasOf<T>,
+		},
+		/// A claim has been frozen by [`Config::ClaimAdminOrigin`].
+		ClaimFrozen {
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The frozen hash.
+			claim: T::ClaimId,
+		},
+		/// A claim has been thawed by [`Config::ClaimAdminOrigin`].
+		ClaimThawed {
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The thawed hash.
+			claim: T::ClaimId,
+		},
+		/// A tombstoned claim has been restored to its former owner by
+		/// [`Config::ClaimAdminOrigin`].
+		ClaimRestored {
+			/// The account that owns the claim again.
+			who: T::AccountId,
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The restored hash.
+			claim: T::ClaimId,
+		},
+		/// An account has committed to a claim it is about to reveal.
+		ClaimCommitted {
+			/// The account that made the commitment.
+			who: T::AccountId,
+			/// The hash of the claim and salt to be revealed.
+			commitment: T::Hash,
+		},
+		/// A committed claim has been revealed and created by the account that committed to it.
+		ClaimRevealed {
+			/// The account that owns the claim.
+			who: T::AccountId,
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The revealed hash.
+			claim: T::ClaimId,
+		},
+		/// The note of a claim has been set or cleared by its owner.
+		OwnerNoteSet {
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The hash the note is attached to.
+			claim: T::ClaimId,
+			/// The new note, or `None` if the claim no longer has one.
+			note: Option<ClaimNoteOf<T>>,
+		},
+		/// A claim made after an earlier commitment to the same hash has been removed, and its
+		/// deposit released, for the commitment to be revealed.
+		ClaimSuperseded {
+			/// The namespace of the claim.
+			namespace: NamespaceId,
+			/// The hash that was claimed.
+			claim: T::ClaimId,
+			/// The account that owned the removed claim.
+			by_owner: T::AccountId,
+			/// The sequence number of the removed claim.
+			id: u64,
+		},
+	}
+
+	impl<T: Config> Event<T> {
+		/// The hash the event is about, if it is about a single claim.
+		///
+		/// This lets generic event handlers follow a claim without matching every variant. The
+		/// hash only names a claim within the event's [`Event::namespace`].
+		pub fn claim(&self) -> Option<&T::ClaimId> {
+			match self {
+				Self::ClaimCreated { claim, .. } |
+				Self::ClaimRevoked { claim, .. } |
+				Self::ClaimTransferred { claim, .. } |
+				Self::TransferApproved { claim, .. } |
+				Self::ClaimForceRevoked { claim, .. } |
+				Self::ClaimDepositSlashed { claim, .. } |
+				Self::ClaimDepositDusted { claim, .. } |
+				Self::ClaimRenewed { claim, .. } |
+				Self::ClaimExpired { claim, .. } |
+				Self::ClaimMetadataUpdated { claim, .. } |
+				Self::ClaimPending { claim, .. } |
+				Self::ClaimConfirmed { claim, .. } |
+				Self::PendingClaimExpired { claim, .. } |
+				Self::ClaimManagerSet { claim, .. } |
+				Self::ClaimTagAdded { claim, .. } |
+				Self::ClaimTagRemoved { claim, .. } |
+				Self::ClaimAliasSet { claim, .. } |
+				Self::ClaimAliasCleared { claim, .. } |
+				Self::ClaimFrozen { claim, .. } |
+				Self::ClaimThawed { claim, .. } |
+				Self::ClaimRestored { claim, .. } |
+				Self::ClaimRevealed { claim, .. } |
+				Self::OwnerNoteSet { claim, .. } |
+				Self::ClaimSuperseded { claim, .. } => Some(claim),
+				Self::SomethingStored { .. } |
+				Self::ClaimsBatchCreated { .. } |
+				Self::ClaimsBatchPartiallyCreated { .. } |
+				Self::ExpiredClaimsPurged { .. } |
+				Self::AllClaimsRevoked { .. } |
+				Self::ClaimsBulkTransferred { .. } |
+				Self::PausedStateChanged { .. } |
+				Self::AddedToAllowlist { .. } |
+				Self::RemovedFromAllowlist { .. } |
+				Self::ClaimLifetimeChanged { .. } |
+				Self::ClaimsReindexed { .. } |
+				Self::ClaimCommitted { .. } |
+				Self::__Ignore(..) => None,
+			}
+		}
+
+		/// The namespace the event happened in, if it is about claims of a single namespace.
+		pub fn namespace(&self) -> Option<NamespaceId> {
+			match self {
+				Self::ClaimCreated { namespace, .. } |
+				Self::ClaimRevoked { namespace, .. } |
+				Self::ClaimsBatchCreated { namespace, .. } |
+				Self::ClaimsBatchPartiallyCreated { namespace, .. } |
+				Self::ClaimTransferred { namespace, .. } |
+				Self::TransferApproved { namespace, .. } |
+				Self::ClaimForceRevoked { namespace, .. } |
+				Self::ClaimDepositSlashed { namespace, .. } |
+				Self::ClaimDepositDusted { namespace, .. } |
+				Self::ClaimRenewed { namespace, .. } |
+				Self::ClaimExpired { namespace, .. } |
+				Self::ClaimMetadataUpdated { namespace, .. } |
+				Self::ClaimPending { namespace, .. } |
+				Self::ClaimConfirmed { namespace, .. } |
+				Self::PendingClaimExpired { namespace, .. } |
+				Self::ClaimsBulkTransferred { namespace, .. } |
+				Self::ClaimManagerSet { namespace, .. } |
+				Self::ClaimTagAdded { namespace, .. } |
+				Self::ClaimTagRemoved { namespace, .. } |
+				Self::ClaimAliasSet { namespace, .. } |
+				Self::ClaimAliasCleared { namespace, .. } |
+				Self::ClaimFrozen { namespace, .. } |
+				Self::ClaimThawed { namespace, .. } |
+				Self::ClaimRestored { namespace, .. } |
+				Self::ClaimRevealed { namespace, .. } |
+				Self::OwnerNoteSet { namespace, .. } |
+				Self::ClaimSuperseded { namespace, .. } => Some(*namespace),
+				Self::SomethingStored { .. } |
+				Self::ExpiredClaimsPurged { .. } |
+				Self::AllClaimsRevoked { .. } |
+				Self::PausedStateChanged { .. } |
+				Self::AddedToAllowlist { .. } |
+				Self::RemovedFromAllowlist { .. } |
+				Self::ClaimLifetimeChanged { .. } |
+				Self::ClaimsReindexed { .. } |
+				Self::ClaimCommitted { .. } |
+				Self::__Ignore(..) => None,
+			}
+		}
 	}
 
 	/// Errors that can be returned by this pallet.
@@ -123,12 +1446,175 @@ pub mod pallet {
 	///
 	/// This type of runtime error can be up to 4 bytes in size should you want to return additional
 	/// information.
+	///
+	/// Clients identify an error by its index in this enum, as carried by
+	/// [`DispatchError::Module`](sp_runtime::DispatchError::Module). The order of the variants is
+	/// therefore stable: new variants are only ever added at the end, and existing ones are
+	/// never removed or reordered.
 	#[pallet::error]
 	pub enum Error<T> {
 		/// The value retrieved was `None` as no value was previously set.
 		NoneValue,
 		/// There was an attempt to increment the value in storage over `u32::MAX`.
 		StorageOverflow,
+		/// The hash has already been claimed.
+		AlreadyClaimed,
+		/// The hash has not been claimed.
+		NoSuchClaim,
+		/// The claim is owned by another account.
+		NotClaimOwner,
+		/// A claim cannot be transferred to its current owner.
+		CannotTransferToSelf,
+		/// The metadata is longer than [`Config::MaxClaimMetadataLen`].
+		MetadataTooLong,
+		/// The block in which the claim would expire already has
+		/// [`Config::MaxExpiringPerBlock`] claims scheduled.
+		TooManyExpiringInBlock,
+		/// The account already owns [`Config::MaxClaimsPerAccount`] claims.
+		TooManyClaims,
+		/// The account cannot afford the deposit of the claim.
+		InsufficientBalance,
+		/// Claims cannot be renewed because the claim lifetime is zero.
+		ExpiryDisabled,
+		/// The signer has not been approved to take over the claim.
+		NotApproved,
+		/// The default hash cannot be claimed while [`Config::RejectDefaultHash`] is set.
+		InvalidClaimHash,
+		/// The requested expiry is not after the current block.
+		ExpiryInPast,
+		/// The claim would expire after the last representable block number.
+		ExpiryOverflow,
+		/// [`Config::MaxClaimsPerBlock`] claims have already been created in this block.
+		BlockClaimLimitReached,
+		/// Every claim id has been assigned, so no more claims can be created.
+		ClaimIdsExhausted,
+		/// The hash has no pending claim.
+		NoSuchPendingClaim,
+		/// The signer is not the witness named by the pending claim.
+		WitnessMismatch,
+		/// New claims cannot be created while the pallet is paused.
+		Paused,
+		/// The claim is locked against revocation and transfer until a later block.
+		ClaimLocked,
+		/// The tag is longer than [`Config::MaxTagLen`].
+		TagTooLong,
+		/// The claim already carries [`Config::MaxTagsPerClaim`] tags.
+		TooManyTags,
+		/// The claim already carries this tag.
+		DuplicateTag,
+		/// The claim does not carry this tag.
+		NoSuchTag,
+		/// [`Config::MaxTotalClaims`] claims already exist.
+		ClaimCapacityReached,
+		/// The account is not in the [`Allowlist`], which [`Config::EnforceAllowlist`] requires to
+		/// create claims.
+		NotAllowlisted,
+		/// The alias already names a claim in the namespace.
+		AliasTaken,
+		/// The claim has no alias.
+		AliasNotFound,
+		/// The alias is longer than [`Config::MaxAliasLen`].
+		AliasTooLong,
+		/// The account made a claim less than [`Config::MinClaimInterval`] blocks ago.
+		TooSoon,
+		/// Answering the query would visit more than [`Config::MaxIterationItems`] storage
+		/// entries.
+		QueryTooLarge,
+		/// The claim is frozen with [`Pallet::freeze_claim`].
+		ClaimFrozen,
+		/// The IPFS identifier is neither a CIDv0 nor a base32 CIDv1.
+		InvalidCid,
+		/// The hash was claimed and revoked in this namespace, and its tombstone keeps it from
+		/// being claimed again.
+		ClaimTombstoned,
+		/// The hash has no tombstone in this namespace.
+		NotTombstoned,
+		/// The signer has no commitment made with [`Pallet::commit_claim`].
+		CommitmentNotFound,
+		/// Fewer than [`Config::RevealDelay`] blocks have passed since the commitment was made.
+		RevealTooEarly,
+		/// The claim and salt do not hash to the signer's commitment.
+		BadReveal,
+		/// The note is longer than [`Config::MaxNoteLen`].
+		NoteTooLong,
+		/// The preimage is longer than [`Config::MaxPreimageLen`].
+		PreimageTooLong,
+		/// Only the owner of the claim can make a change that holds more of its deposit.
+		DepositIncreaseByManager,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Drop the pending claims whose timeout ends in this block.
+		///
+		/// The hook is charged as if every claim scheduled in this block were due, and for reading
+		/// the schedule even when it is empty. Expired claims are removed in [`Hooks::on_idle`]
+		/// instead, so that they do not compete with extrinsics for the block's weight.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let expiring = PendingExpiringAt::<T>::take(now);
+			let weight = T::WeightInfo::on_initialize_pending_timeout(expiring.len() as u32);
+			for (namespace, claim) in expiring {
+				// The claim may have been confirmed, or confirmed and made pending again, since it
+				// was scheduled. Only drop it if it is really due.
+				let Some(pending) = PendingClaims::<T>::get(namespace, &claim) else { continue };
+				if pending.expires_at != now {
+					continue;
+				}
+				PendingClaims::<T>::remove(namespace, &claim);
+				Self::release_deposit(namespace, &claim, &pending.owner, pending.deposit);
+				Self::deposit_event(Event::PendingClaimExpired {
+					who: pending.owner,
+					namespace,
+					claim,
+				});
+			}
+			weight
+		}
+
+		/// Remove as many expired claims as fit in the weight left in the block.
+		///
+		/// Claims expire at the block numbers of [`Config::BlockNumberProvider`], which are
+		/// looked up rather than taken from the hook, as they need not match this chain's own.
+		///
+		/// The sweep is charged [`WeightInfo::on_initialize_expire`] for the blocks it visits, and
+		/// [`WeightInfo::expire_claims`] for the claims scheduled in them.
+		fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let now = T::BlockNumberProvider::current_block_number();
+			Self::remove_expired_claims(now, remaining_weight, u32::MAX).0
+		}
+
+		fn integrity_test() {
+			assert!(!T::MaxBatchSize::get().is_zero(), "`MaxBatchSize` must not be zero");
+			assert!(
+				T::MaxClaimMetadataLen::get() <= MAX_CLAIM_METADATA_LEN,
+				"`MaxClaimMetadataLen` must not exceed `MAX_CLAIM_METADATA_LEN`"
+			);
+			assert!(
+				T::MaxBatchSize::get() <= T::MaxClaimsPerAccount::get(),
+				"`MaxBatchSize` must not exceed `MaxClaimsPerAccount`"
+			);
+			assert!(
+				T::MaxBatchSize::get() <= T::MaxExpiringPerBlock::get(),
+				"`MaxBatchSize` must not exceed `MaxExpiringPerBlock`"
+			);
+			assert!(
+				T::MaxBatchSize::get() <= T::MaxClaimsPerBlock::get(),
+				"`MaxBatchSize` must not exceed `MaxClaimsPerBlock`"
+			);
+			assert!(!T::PendingTimeout::get().is_zero(), "`PendingTimeout` must not be zero");
+		}
+
+		/// Mirror the claims changed in this block into off-chain storage.
+		///
+		/// See [`crate::offchain`] for the layout of the mirror.
+		fn offchain_worker(block: BlockNumberFor<T>) {
+			crate::offchain::index_block::<T>(block);
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state(T::BlockNumberProvider::current_block_number())
+		}
 	}
 
 	/// The pallet's dispatchable functions ([`Call`]s).
@@ -160,6 +1646,7 @@ pub mod pallet {
 			Something::<T>::put(something);
 
 			// Emit an event.
+			log::debug!(target: LOG_TARGET, "stored {something} for {who:?}");
 			Self::deposit_event(Event::SomethingStored { something, who });
 
 			// Return a successful `DispatchResult`
@@ -198,5 +1685,2568 @@ pub mod pallet {
 				},
 			}
 		}
+
+		/// Claim ownership of a hash in `namespace`.
+		///
+		/// The signer becomes the owner of `claim`, and the current block number is recorded as
+		/// the moment the claim was made, along with the `kind` of artifact that was hashed and the
+		/// `algo` it was hashed with. Optional `metadata` can be attached to the claim.
+		/// [`Config::ClaimDeposit`], plus [`Config::MetadataDepositPerByte`] for every byte of
+		/// metadata, is held from the signer until the claim is removed.
+		///
+		/// If an `expiry` block is given, the claim is scheduled to be removed in that block, or in
+		/// the first block after it with weight to spare. Otherwise it never expires, and lives
+		/// until it is revoked.
+		///
+		/// If `lock_until` is given, the signer cannot revoke or transfer the claim until that
+		/// block has passed. The lock does not prevent the claim from expiring.
+		///
+		/// An IPFS `cid` can be attached to the claim. It is kept until the claim is removed, and
+		/// can be read back with [`Pallet::cid_of`].
+		///
+		/// A claim of `claim` that has expired but has not been swept yet does not stand in the
+		/// way. It is removed first, its deposit is released to its owner and
+		/// [`Event::ClaimExpired`] is emitted, as if the sweep had got to it.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::Paused`] if claim creation is halted with [`Pallet::set_paused`].
+		/// - [`Error::NotAllowlisted`] if [`Config::EnforceAllowlist`] is set and the signer is not
+		///   in the [`Allowlist`].
+		/// - [`Error::TooSoon`] if the signer made a claim less than [`Config::MinClaimInterval`]
+		///   blocks ago.
+		/// - [`Error::InvalidClaimHash`] if `claim` is the default hash and
+		///   [`Config::RejectDefaultHash`] is set.
+		/// - [`Error::ExpiryInPast`] if `expiry` is not after the current block.
+		/// - [`Error::MetadataTooLong`] if `metadata` is longer than
+		///   [`Config::MaxClaimMetadataLen`].
+		/// - [`Error::InvalidCid`] if `cid` is not a valid IPFS identifier.
+		/// - [`Error::AlreadyClaimed`] if the hash is already owned by some account in `namespace`,
+		///   and that claim has not expired.
+		/// - [`Error::ClaimTombstoned`] if the hash was revoked in `namespace` while
+		///   [`Config::TombstoneRevoked`] was set.
+		/// - [`Error::TooManyExpiringInBlock`] if no more claims can expire in the block this claim
+		///   would expire in.
+		/// - [`Error::ClaimCapacityReached`] if [`Config::MaxTotalClaims`] claims already exist.
+		/// - [`Error::TooManyClaims`] if the signer already owns [`Config::MaxClaimsPerAccount`]
+		///   claims.
+		/// - [`Error::BlockClaimLimitReached`] if [`Config::MaxClaimsPerBlock`] claims have already
+		///   been created in this block.
+		/// - [`Error::InsufficientBalance`] if the signer cannot afford the deposit.
+		///
+		/// [`Error::InvalidClaimHash`], [`Error::ExpiryInPast`], [`Error::MetadataTooLong`],
+		/// [`Error::InvalidCid`] and [`Error::AlreadyClaimed`] are returned before anything is
+		/// written, so they only charge for the storage that was read. This keeps the cost low for
+		/// callers who lose a race to claim the same hash.
+		#[pallet::call_index(2)]
+		#[pallet::weight(
+			Pallet::<T>::create_claim_weight()
+				.saturating_add(Pallet::<T>::history_weight(1))
+				.saturating_add(Pallet::<T>::expired_claim_weight())
+		)]
+		#[allow(clippy::too_many_arguments)]
+		pub fn create_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+			kind: ClaimKind,
+			algo: HashAlgo,
+			metadata: Option<Vec<u8>>,
+			expiry: Option<BlockNumberFor<T>>,
+			lock_until: Option<BlockNumberFor<T>>,
+			cid: Option<Cid>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_not_paused()?;
+			Self::ensure_valid_hash(&claim).map_err(|e| e.with_weight(Weight::zero()))?;
+			if let Some(expiry) = expiry {
+				ensure!(
+					expiry > T::BlockNumberProvider::current_block_number(),
+					Error::<T>::ExpiryInPast.with_weight(Weight::zero())
+				);
+			}
+			let metadata = metadata
+				.map(ClaimMetadataOf::<T>::try_from)
+				.transpose()
+				.map_err(|_| Error::<T>::MetadataTooLong.with_weight(Weight::zero()))?;
+			ensure!(
+				cid.as_ref().is_none_or(Cid::is_valid),
+				Error::<T>::InvalidCid.with_weight(Weight::zero())
+			);
+			let now = T::BlockNumberProvider::current_block_number();
+			let stale = match Claims::<T>::get(namespace, &claim) {
+				Some(info) if info.has_expired(now) => Some(info),
+				Some(_) =>
+					return Err(Error::<T>::AlreadyClaimed.with_weight(T::DbWeight::get().reads(1))),
+				None => None,
+			};
+			Self::note_claim_interval(&who)?;
+			if let Some(info) = &stale {
+				Self::take_over_expired(namespace, &claim, info);
+			}
+			Self::do_create_claim(
+				&who, namespace, claim, kind, algo, metadata, expiry, lock_until, cid,
+			)?;
+			if stale.is_none() {
+				let weight = Self::create_claim_weight().saturating_add(Self::history_weight(1));
+				return Ok(Some(weight).into());
+			}
+			Ok(().into())
+		}
+
+		/// Revoke a claim owned by the signer, making the hash available to be claimed again in
+		/// `namespace`.
+		///
+		/// Any metadata attached to the claim is removed as well, and the deposit held for the
+		/// claim is released to the signer. While [`Config::TombstoneRevoked`] is set, a tombstone
+		/// is left in [`RevokedClaims`] instead, and the hash cannot be claimed again.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NoSuchClaim`] if the hash has not been claimed in `namespace`.
+		/// - [`Error::NotClaimOwner`] if the claim is owned by another account.
+		/// - [`Error::ClaimLocked`] if the claim is locked.
+		/// - [`Error::ClaimFrozen`] if the claim is frozen with [`Pallet::freeze_claim`].
+		#[pallet::call_index(3)]
+		#[pallet::weight(
+			T::WeightInfo::revoke_claim().saturating_add(Pallet::<T>::history_weight(1))
+		)]
+		pub fn revoke_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_revoke_claim(who, namespace, claim)
+		}
+
+		/// Transfer ownership of a claim owned by the signer to `dest`.
+		///
+		/// The block number at which the claim was originally made is kept, so the provenance of
+		/// the claim survives the transfer. The deposit held for the claim moves with it: it is
+		/// transferred from the signer and put on hold for `dest`, which gets it back when the
+		/// claim is removed.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NoSuchClaim`] if the hash has not been claimed in `namespace`.
+		/// - [`Error::NotClaimOwner`] if the claim is owned by another account.
+		/// - [`Error::ClaimLocked`] if the claim is locked.
+		/// - [`Error::ClaimFrozen`] if the claim is frozen with [`Pallet::freeze_claim`].
+		/// - [`Error::CannotTransferToSelf`] if `dest` already owns the claim.
+		/// - [`Error::TooManyClaims`] if `dest` already owns [`Config::MaxClaimsPerAccount`]
+		///   claims.
+		#[pallet::call_index(4)]
+		#[pallet::weight(
+			T::WeightInfo::transfer_claim().saturating_add(Pallet::<T>::history_weight(1))
+		)]
+		pub fn transfer_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+			dest: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let info = Self::ensure_owner(namespace, &claim, &who)?;
+			Self::ensure_unlocked(&info)?;
+			Self::ensure_not_frozen(namespace, &claim)?;
+			ensure!(dest != who, Error::<T>::CannotTransferToSelf);
+
+			Self::do_transfer_claim(namespace, claim, info, dest)
+		}
+
+		/// Revoke any claim, regardless of who owns it.
+		///
+		/// This is meant for governance to remove claims that must not stay on chain. The origin
+		/// must be [`Config::ClaimAdminOrigin`]. As with [`Pallet::revoke_claim`], the metadata is
+		/// removed. The deposit is returned to the owner, unless `slash` is set, in which case it
+		/// is sent to [`Config::SlashDestination`] and [`Event::ClaimDepositSlashed`] is emitted.
+		///
+		/// ## Errors
+		///
+		/// - [`BadOrigin`](sp_runtime::DispatchError::BadOrigin) if the origin is not
+		///   [`Config::ClaimAdminOrigin`].
+		/// - [`Error::NoSuchClaim`] if the hash has not been claimed in `namespace`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(
+			T::WeightInfo::force_revoke_claim().saturating_add(Pallet::<T>::history_weight(1))
+		)]
+		pub fn force_revoke_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+			slash: bool,
+		) -> DispatchResult {
+			T::ClaimAdminOrigin::ensure_origin(origin)?;
+
+			let info = Claims::<T>::get(namespace, &claim).ok_or(Error::<T>::NoSuchClaim)?;
+			if slash {
+				Self::clear_claim(namespace, &claim, &info.owner);
+				let amount = Self::slash_deposit(&info.owner, info.deposit);
+				Self::deposit_event(Event::ClaimDepositSlashed {
+					namespace,
+					claim: claim.clone(),
+					amount,
+				});
+			} else {
+				Self::remove_claim(namespace, &claim, &info.owner, info.deposit);
+			}
+
+			log::debug!(target: LOG_TARGET, "force-revoked claim {claim:?} in namespace {namespace} of {:?}", info.owner);
+			Self::deposit_event(Event::ClaimForceRevoked {
+				namespace,
+				claim,
+				by_owner: info.owner,
+				id: info.id,
+			});
+			Ok(())
+		}
+
+		/// Claim ownership of several hashes in `namespace` at once.
+		///
+		/// Each hash is claimed as if by [`Pallet::create_claim`] with the same `kind` and `algo`
+		/// and without metadata, emitting a [`Event::ClaimCreated`] for each of them, followed by a
+		/// single [`Event::ClaimsBatchCreated`] for the whole batch. Unless expiry is disabled,
+		/// the claims are scheduled to be removed after [`Pallet::claim_lifetime`] blocks.
+		///
+		/// As with [`Pallet::create_claim`], a claim that has expired but has not been swept yet
+		/// is removed first and does not stand in the way.
+		///
+		/// If `skip_existing` is set, hashes that cannot be claimed, because they are already
+		/// claimed, pending confirmation or tombstoned in `namespace`, including duplicates within
+		/// `claims`, are skipped, and [`Event::ClaimsBatchPartiallyCreated`] lists them in place of
+		/// [`Event::ClaimsBatchCreated`]. Otherwise they make the whole batch fail.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::AlreadyClaimed`] if one of the hashes is already claimed or pending and
+		///   `skip_existing` is not set.
+		/// - [`Error::ClaimTombstoned`] if one of the hashes is tombstoned and `skip_existing` is
+		///   not set.
+		/// - [`Error::ExpiryOverflow`] if the claims would expire past the last representable
+		///   block.
+		/// - [`Error::TooSoon`] if the signer made a claim less than [`Config::MinClaimInterval`]
+		///   blocks ago.
+		/// - Any other error [`Pallet::create_claim`] can return for one of the hashes.
+		#[pallet::call_index(6)]
+		#[pallet::weight(
+			T::WeightInfo::create_claims(claims.len() as u32)
+				.saturating_add(Pallet::<T>::history_weight(claims.len() as u32))
+				.saturating_add(
+					Pallet::<T>::expired_claim_weight().saturating_mul(claims.len() as u64)
+				)
+		)]
+		pub fn create_claims(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claims: BoundedVec<T::ClaimId, T::MaxBatchSize>,
+			kind: ClaimKind,
+			algo: HashAlgo,
+			skip_existing: bool,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_not_paused()?;
+			Self::note_claim_interval(&who)?;
+			let lifetime = Self::claim_lifetime();
+			let expiry =
+				if lifetime.is_zero() { None } else { Some(Self::expiry_after(lifetime)?) };
+			let now = T::BlockNumberProvider::current_block_number();
+			let len = claims.len() as u32;
+			let mut count = 0u32;
+			let mut taken_over = 0u32;
+			let mut skipped = Vec::new();
+			for claim in claims {
+				let stale =
+					Claims::<T>::get(namespace, &claim).map(|info| (info.has_expired(now), info));
+				let available = stale.as_ref().is_none_or(|(expired, _)| *expired) &&
+					!PendingClaims::<T>::contains_key(namespace, &claim) &&
+					!RevokedClaims::<T>::contains_key(namespace, &claim);
+				if skip_existing && !available {
+					skipped.push(claim);
+					continue;
+				}
+				if let Some((true, info)) = &stale {
+					Self::take_over_expired(namespace, &claim, info);
+					taken_over += 1;
+				}
+				Self::do_create_claim(
+					&who, namespace, claim, kind, algo, None, expiry, None, None,
+				)?;
+				count += 1;
+			}
+
+			if skipped.is_empty() {
+				Self::deposit_event(Event::ClaimsBatchCreated { who, namespace, count });
+			} else {
+				Self::deposit_event(Event::ClaimsBatchPartiallyCreated {
+					who,
+					namespace,
+					created: count,
+					// Never longer than `claims`, so nothing is truncated.
+					skipped: BoundedVec::truncate_from(skipped),
+				});
+			}
+			// Refund the removal of the expired claims that were not there.
+			let weight = T::WeightInfo::create_claims(len)
+				.saturating_add(Self::history_weight(len))
+				.saturating_add(Self::expired_claim_weight().saturating_mul(taken_over.into()));
+			Ok(Some(weight).into())
+		}
+
+		/// Renew a claim owned or managed by the signer, so that it expires
+		/// [`Pallet::claim_lifetime`] blocks from now instead of at its current expiry.
+		///
+		/// Claims that were never scheduled to expire, such as those registered at genesis, are
+		/// scheduled from now on.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::ExpiryDisabled`] if the claim lifetime is zero.
+		/// - [`Error::NoSuchClaim`] if the hash has not been claimed in `namespace`.
+		/// - [`Error::NotClaimOwner`] if the signer is neither the owner nor the manager of the
+		///   claim.
+		/// - [`Error::ExpiryOverflow`] if the new expiry is past the last representable block.
+		/// - [`Error::TooManyExpiringInBlock`] if no more claims can expire in the block this claim
+		///   would now expire in.
+		#[pallet::call_index(7)]
+		#[pallet::weight(
+			T::WeightInfo::renew_claim().saturating_add(Pallet::<T>::history_weight(1))
+		)]
+		pub fn renew_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let lifetime = Self::claim_lifetime();
+			ensure!(!lifetime.is_zero(), Error::<T>::ExpiryDisabled);
+			let mut info = Self::ensure_owner_or_manager(namespace, &claim, &who)?;
+
+			let new_expiry = Self::expiry_after(lifetime)?;
+			if let Some(old_expiry) = info.expires_at {
+				Self::unschedule_expiry(old_expiry, namespace, &claim);
+			}
+			ExpiringAt::<T>::try_append(new_expiry, (namespace, claim.clone()))
+				.map_err(|_| Error::<T>::TooManyExpiringInBlock)?;
+			info.expires_at = Some(new_expiry);
+			Claims::<T>::insert(namespace, &claim, info);
+			Self::record_history(namespace, &claim, HistoryAction::Renewed);
+
+			log::debug!(target: LOG_TARGET, "renewed claim {claim:?} in namespace {namespace} until {new_expiry:?} by {who:?}");
+			Self::deposit_event(Event::ClaimRenewed { namespace, claim, new_expiry });
+			Ok(())
+		}
+
+		/// Approve `to` to take over a claim owned by the signer with [`Pallet::accept_transfer`].
+		///
+		/// This replaces any approval the claim already had. The approval is cleared when the
+		/// claim changes owner or is removed.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NoSuchClaim`] if the hash has not been claimed in `namespace`.
+		/// - [`Error::NotClaimOwner`] if the claim is owned by another account.
+		/// - [`Error::CannotTransferToSelf`] if `to` already owns the claim.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::approve_transfer())]
+		pub fn approve_transfer(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_owner(namespace, &claim, &who)?;
+			ensure!(to != who, Error::<T>::CannotTransferToSelf);
+
+			Approvals::<T>::insert(namespace, &claim, &to);
+
+			log::debug!(target: LOG_TARGET, "approved the transfer of claim {claim:?} in namespace {namespace} to {to:?} by {who:?}");
+			Self::deposit_event(Event::TransferApproved { namespace, claim, owner: who, to });
+			Ok(())
+		}
+
+		/// Take over a claim whose owner has approved the signer with
+		/// [`Pallet::approve_transfer`].
+		///
+		/// The claim moves exactly as with [`Pallet::transfer_claim`], including its deposit.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NoSuchClaim`] if the hash has not been claimed in `namespace`.
+		/// - [`Error::NotApproved`] if the signer is not the approved account.
+		/// - [`Error::ClaimLocked`] if the claim is locked.
+		/// - [`Error::ClaimFrozen`] if the claim is frozen with [`Pallet::freeze_claim`].
+		/// - [`Error::TooManyClaims`] if the signer already owns [`Config::MaxClaimsPerAccount`]
+		///   claims.
+		#[pallet::call_index(9)]
+		#[pallet::weight(
+			T::WeightInfo::transfer_claim()
+				.saturating_add(T::DbWeight::get().reads(1))
+				.saturating_add(Pallet::<T>::history_weight(1))
+		)]
+		pub fn accept_transfer(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let info = Claims::<T>::get(namespace, &claim).ok_or(Error::<T>::NoSuchClaim)?;
+			ensure!(
+				Approvals::<T>::get(namespace, &claim).as_ref() == Some(&who),
+				Error::<T>::NotApproved
+			);
+			Self::ensure_unlocked(&info)?;
+			Self::ensure_not_frozen(namespace, &claim)?;
+
+			Self::do_transfer_claim(namespace, claim, info, who)
+		}
+
+		/// Claim ownership of a hash in [`DEFAULT_NAMESPACE`].
+		///
+		/// This is [`Pallet::create_claim`] for chains that only have a single application, and
+		/// can return the same errors, with the same refunds.
+		#[pallet::call_index(10)]
+		#[pallet::weight(
+			Pallet::<T>::create_claim_weight()
+				.saturating_add(Pallet::<T>::history_weight(1))
+				.saturating_add(Pallet::<T>::expired_claim_weight())
+		)]
+		#[allow(clippy::too_many_arguments)]
+		pub fn create_claim_default(
+			origin: OriginFor<T>,
+			claim: T::ClaimId,
+			kind: ClaimKind,
+			algo: HashAlgo,
+			metadata: Option<Vec<u8>>,
+			expiry: Option<BlockNumberFor<T>>,
+			lock_until: Option<BlockNumberFor<T>>,
+			cid: Option<Cid>,
+		) -> DispatchResultWithPostInfo {
+			Self::create_claim(
+				origin,
+				DEFAULT_NAMESPACE,
+				claim,
+				kind,
+				algo,
+				metadata,
+				expiry,
+				lock_until,
+				cid,
+			)
+		}
+
+		/// Replace the metadata attached to a claim owned or managed by the signer.
+		///
+		/// An empty or missing `metadata` clears it. The part of the deposit of the claim that
+		/// covers its metadata is re-priced for the new length, at the current
+		/// [`Config::MetadataDepositPerByte`]: the difference is held from, or released to, the
+		/// owner of the claim. The manager of the claim can only make changes that release part
+		/// of the deposit or leave it as it is, as the owner has to agree to any larger hold.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::MetadataTooLong`] if `metadata` is longer than
+		///   [`Config::MaxClaimMetadataLen`].
+		/// - [`Error::NoSuchClaim`] if the hash has not been claimed in `namespace`.
+		/// - [`Error::NotClaimOwner`] if the signer is neither the owner nor the manager of the
+		///   claim.
+		/// - [`Error::ClaimFrozen`] if the claim is frozen with [`Pallet::freeze_claim`].
+		/// - [`Error::DepositIncreaseByManager`] if the signer is the manager of the claim and the
+		///   new metadata needs a larger deposit.
+		/// - [`Error::InsufficientBalance`] if the owner cannot afford the larger deposit.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_claim_metadata())]
+		pub fn set_claim_metadata(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+			metadata: Option<Vec<u8>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let metadata = metadata
+				.filter(|metadata| !metadata.is_empty())
+				.map(ClaimMetadataOf::<T>::try_from)
+				.transpose()
+				.map_err(|_| Error::<T>::MetadataTooLong)?;
+			let mut info = Self::ensure_owner_or_manager(namespace, &claim, &who)?;
+			Self::ensure_not_frozen(namespace, &claim)?;
+
+			let old_len = ClaimMetadata::<T>::decode_len(namespace, &claim).unwrap_or(0);
+			let new_len = metadata.as_ref().map_or(0, |metadata| metadata.len());
+			let deposit = info
+				.deposit
+				.saturating_sub(Self::metadata_deposit(old_len))
+				.saturating_add(Self::metadata_deposit(new_len));
+			let reason = HoldReason::ClaimDeposit.into();
+			if deposit > info.deposit {
+				ensure!(info.owner == who, Error::<T>::DepositIncreaseByManager);
+				T::Fungible::hold(&reason, &info.owner, deposit - info.deposit)
+					.map_err(|_| Error::<T>::InsufficientBalance)?;
+			} else {
+				Self::release_deposit(namespace, &claim, &info.owner, info.deposit - deposit);
+			}
+			info.deposit = deposit;
+			Claims::<T>::insert(namespace, &claim, info);
+			match metadata {
+				Some(metadata) => ClaimMetadata::<T>::insert(namespace, &claim, metadata),
+				None => ClaimMetadata::<T>::remove(namespace, &claim),
+			}
+
+			log::debug!(target: LOG_TARGET, "updated the metadata of claim {claim:?} in namespace {namespace} by {who:?}");
+			Self::deposit_event(Event::ClaimMetadataUpdated { namespace, claim });
+			Ok(())
+		}
+
+		/// Revoke the claims owned by the signer, in every namespace.
+		///
+		/// Each claim is revoked as if by [`Pallet::revoke_claim`], leaving a tombstone while
+		/// [`Config::TombstoneRevoked`] is set, and emitting a
+		/// [`Event::ClaimRevoked`] for each of them, followed by a single
+		/// [`Event::AllClaimsRevoked`]. At most [`Config::MaxBatchSize`] claims are revoked per
+		/// call, so that it fits in a block. If the signer owns more, the event reports how many
+		/// remain, and the call must be repeated to revoke them.
+		///
+		/// Claims that are locked or frozen are skipped, and counted as remaining. They do not take
+		/// up a place in the batch: the call goes on through the claims of the signer until it has
+		/// revoked a full batch or visited all of them.
+		///
+		/// Only the claims actually visited are charged for.
+		#[pallet::call_index(12)]
+		#[pallet::weight(
+			T::WeightInfo::revoke_all(
+				T::MaxBatchSize::get(),
+				T::MaxClaimsPerAccount::get().saturating_sub(T::MaxBatchSize::get()),
+			)
+			.saturating_add(Pallet::<T>::history_weight(T::MaxBatchSize::get()))
+		)]
+		pub fn revoke_all(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let total = ClaimCountByOwner::<T>::get(&who);
+			// The index of the signer holds at most `MaxClaimsPerAccount` claims, so it can be
+			// walked past the claims that are skipped.
+			let (mut claims, mut skipped) = (Vec::new(), 0u32);
+			for (namespace, claim) in ClaimsByOwner::<T>::iter_key_prefix(&who) {
+				if claims.len() as u32 == T::MaxBatchSize::get() {
+					break;
+				}
+				match Claims::<T>::get(namespace, &claim) {
+					Some(info)
+						if Self::ensure_unlocked(&info).is_ok() &&
+							Self::ensure_not_frozen(namespace, &claim).is_ok() =>
+						claims.push((namespace, claim, info)),
+					_ => skipped += 1,
+				}
+			}
+			let count = claims.len() as u32;
+			for (namespace, claim, info) in claims {
+				Self::remove_claim(namespace, &claim, &who, info.deposit);
+				Self::tombstone(namespace, &claim, &info);
+				log::debug!(target: LOG_TARGET, "revoked claim {claim:?} in namespace {namespace} by {who:?}");
+				Self::deposit_event(Event::ClaimRevoked {
+					who: who.clone(),
+					namespace,
+					claim,
+					created_at: info.created_at,
+					deposit_returned: info.deposit,
+					id: info.id,
+				});
+			}
+
+			let remaining = total.saturating_sub(count);
+			Self::deposit_event(Event::AllClaimsRevoked { who, count, remaining });
+			Ok(Some(
+				T::WeightInfo::revoke_all(count, skipped)
+					.saturating_add(Self::history_weight(count)),
+			)
+			.into())
+		}
+
+		/// Claim a hash in `namespace` on the condition that `witness` confirms it.
+		///
+		/// The claim is only created, as a [`ClaimKind::Document`] of algorithm
+		/// [`HashAlgo::Other(0)`](HashAlgo::Other) owned by the signer, once
+		/// `witness` calls [`Pallet::confirm_claim`]. Until then the hash cannot be claimed by
+		/// anyone else in `namespace`, and [`Config::ClaimDeposit`] is held from the signer. If
+		/// the claim is not confirmed within [`Config::PendingTimeout`] blocks, it is dropped and
+		/// the deposit released.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::Paused`] if claim creation is halted with [`Pallet::set_paused`].
+		/// - [`Error::NotAllowlisted`] if [`Config::EnforceAllowlist`] is set and the signer is not
+		///   in the [`Allowlist`].
+		/// - [`Error::TooSoon`] if the signer made a claim less than [`Config::MinClaimInterval`]
+		///   blocks ago.
+		/// - [`Error::InvalidClaimHash`] if `claim` is the default hash and
+		///   [`Config::RejectDefaultHash`] is set.
+		/// - [`Error::AlreadyClaimed`] if the hash is already claimed or pending in `namespace`.
+		/// - [`Error::ExpiryOverflow`] if the claim would be dropped after the last representable
+		///   block number.
+		/// - [`Error::TooManyExpiringInBlock`] if no more pending claims can be dropped in the
+		///   block this one would be dropped in.
+		/// - [`Error::InsufficientBalance`] if the signer cannot afford the deposit.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::create_pending_claim())]
+		pub fn create_pending_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+			witness: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_not_paused()?;
+			Self::ensure_allowlisted(&who)?;
+			Self::ensure_valid_hash(&claim)?;
+			Self::note_claim_interval(&who)?;
+			ensure!(
+				!Claims::<T>::contains_key(namespace, &claim) &&
+					!PendingClaims::<T>::contains_key(namespace, &claim),
+				Error::<T>::AlreadyClaimed
+			);
+			ensure!(
+				!RevokedClaims::<T>::contains_key(namespace, &claim),
+				Error::<T>::ClaimTombstoned
+			);
+			// Pending claims are dropped in the block their timeout ends in, so their timeout is
+			// counted in this chain's blocks whatever the block number provider.
+			let expires_at = frame_system::Pallet::<T>::block_number()
+				.checked_add(&T::PendingTimeout::get())
+				.ok_or(Error::<T>::ExpiryOverflow)?;
+			PendingExpiringAt::<T>::try_append(expires_at, (namespace, claim.clone()))
+				.map_err(|_| Error::<T>::TooManyExpiringInBlock)?;
+			let deposit = T::ClaimDeposit::get();
+			T::Fungible::hold(&HoldReason::ClaimDeposit.into(), &who, deposit)
+				.map_err(|_| Error::<T>::InsufficientBalance)?;
+			let pending = PendingClaim { owner: who.clone(), witness, deposit, expires_at };
+			PendingClaims::<T>::insert(namespace, &claim, &pending);
+
+			log::debug!(target: LOG_TARGET, "claim {claim:?} in namespace {namespace} pending for {who:?}, to be confirmed by {:?}", pending.witness);
+			Self::deposit_event(Event::ClaimPending {
+				who,
+				witness: pending.witness,
+				namespace,
+				claim,
+				expires_at,
+			});
+			Ok(())
+		}
+
+		/// Confirm a claim made with [`Pallet::create_pending_claim`] that names the signer as its
+		/// witness.
+		///
+		/// The claim is created for its owner as if by [`Pallet::create_claim`], without metadata
+		/// or expiry, emitting [`Event::ClaimCreated`] followed by [`Event::ClaimConfirmed`]. This
+		/// works while claim creation is halted with [`Pallet::set_paused`], so that claims made
+		/// pending before the pause can still be completed.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NoSuchPendingClaim`] if the hash has no pending claim in `namespace`.
+		/// - [`Error::WitnessMismatch`] if the signer is not the witness of the pending claim.
+		/// - Any error of [`Pallet::create_claim`] that prevents the owner from making the claim,
+		///   such as [`Error::TooManyClaims`].
+		#[pallet::call_index(14)]
+		#[pallet::weight(
+			Pallet::<T>::create_claim_weight()
+				.saturating_add(T::DbWeight::get().reads_writes(1, 3))
+				.saturating_add(Pallet::<T>::history_weight(1))
+		)]
+		pub fn confirm_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pending =
+				PendingClaims::<T>::get(namespace, &claim).ok_or(Error::<T>::NoSuchPendingClaim)?;
+			ensure!(pending.witness == who, Error::<T>::WitnessMismatch);
+
+			PendingClaims::<T>::remove(namespace, &claim);
+			Self::release_deposit(namespace, &claim, &pending.owner, pending.deposit);
+			Self::do_create_claim(
+				&pending.owner,
+				namespace,
+				claim.clone(),
+				ClaimKind::Document,
+				HashAlgo::Other(0),
+				None,
+				None,
+				None,
+				None,
+			)?;
+
+			log::debug!(target: LOG_TARGET, "confirmed claim {claim:?} in namespace {namespace} by {who:?}");
+			Self::deposit_event(Event::ClaimConfirmed { witness: who, namespace, claim });
+			Ok(())
+		}
+
+		/// Transfer several claims in `namespace` owned by the signer to `dest` at once.
+		///
+		/// Each claim is transferred as if by [`Pallet::transfer_claim`], emitting a
+		/// [`Event::ClaimTransferred`] for each of them, followed by a single
+		/// [`Event::ClaimsBulkTransferred`]. The transfer is all or nothing: every claim is checked
+		/// before any is moved, and if one cannot be transferred, none are.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NoSuchClaim`] if one of the hashes has not been claimed in `namespace`.
+		/// - [`Error::NotClaimOwner`] if one of the claims is owned by another account.
+		/// - [`Error::ClaimLocked`] if one of the claims is locked.
+		/// - [`Error::ClaimFrozen`] if one of the claims is frozen with [`Pallet::freeze_claim`].
+		/// - [`Error::CannotTransferToSelf`] if `dest` is the signer.
+		/// - [`Error::TooManyClaims`] if `dest` cannot own that many more claims.
+		#[pallet::call_index(15)]
+		#[pallet::weight(
+			T::WeightInfo::transfer_claim()
+				.saturating_add(Pallet::<T>::history_weight(1))
+				.saturating_mul(claims.len() as u64)
+		)]
+		pub fn transfer_claim_bulk(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claims: BoundedVec<T::ClaimId, T::MaxBatchSize>,
+			dest: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(dest != who, Error::<T>::CannotTransferToSelf);
+			for claim in claims.iter() {
+				Self::ensure_unlocked(&Self::ensure_owner(namespace, claim, &who)?)?;
+				Self::ensure_not_frozen(namespace, claim)?;
+			}
+
+			let mut count = 0u32;
+			for claim in claims {
+				// A hash listed twice has already been moved to `dest`.
+				let Some(info) = Claims::<T>::get(namespace, &claim) else { continue };
+				if info.owner != who {
+					continue;
+				}
+				Self::do_transfer_claim(namespace, claim, info, dest.clone())?;
+				count += 1;
+			}
+
+			log::debug!(target: LOG_TARGET, "transferred {count} claims in namespace {namespace} from {who:?} to {dest:?}");
+			Self::deposit_event(Event::ClaimsBulkTransferred {
+				from: who,
+				to: dest,
+				namespace,
+				count,
+			});
+			Ok(())
+		}
+
+		/// Halt or resume the creation of new claims.
+		///
+		/// This is meant for responding to incidents without a runtime upgrade. The origin must be
+		/// [`Config::ClaimAdminOrigin`]. While `paused` is set, the calls through which users make
+		/// new claims, [`Pallet::create_claim`], [`Pallet::create_claims`],
+		/// [`Pallet::create_pending_claim`], [`Pallet::create_claim_free`] and
+		/// [`Pallet::reveal_claim`], fail with [`Error::Paused`], as do claims made through
+		/// [`ClaimRegistry`](crate::traits::ClaimRegistry). Claims can still be revoked,
+		/// transferred and renewed, so that owners can always get their deposits back.
+		///
+		/// Two calls are exempt. [`Pallet::restore_claim`] is itself an admin call, and
+		/// [`Pallet::confirm_claim`] completes a claim that was made pending before the pause, so
+		/// that its witness is not held up and its deposit is not left held until it times out.
+		///
+		/// ## Errors
+		///
+		/// - [`BadOrigin`](sp_runtime::DispatchError::BadOrigin) if the origin is not
+		///   [`Config::ClaimAdminOrigin`].
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::set_paused())]
+		pub fn set_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+			T::ClaimAdminOrigin::ensure_origin(origin)?;
+
+			Paused::<T>::put(paused);
+			log::debug!(target: LOG_TARGET, "set claim creation paused to {paused}");
+			Self::deposit_event(Event::PausedStateChanged { paused });
+			Ok(())
+		}
+
+		/// Set the number of blocks after which claims created with [`Pallet::create_claims`] or
+		/// renewed with [`Pallet::renew_claim`] expire, in place of [`Config::ClaimLifetime`].
+		///
+		/// The origin must be [`Config::ClaimAdminOrigin`]. `None` restores
+		/// [`Config::ClaimLifetime`], and zero disables expiry for those claims. Claims that are
+		/// already scheduled to expire keep their expiry.
+		///
+		/// ## Errors
+		///
+		/// - [`BadOrigin`](sp_runtime::DispatchError::BadOrigin) if the origin is not
+		///   [`Config::ClaimAdminOrigin`].
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn set_claim_lifetime(
+			origin: OriginFor<T>,
+			blocks: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::ClaimAdminOrigin::ensure_origin(origin)?;
+
+			ClaimLifetimeOverride::<T>::set(blocks);
+			log::debug!(target: LOG_TARGET, "set the claim lifetime override to {blocks:?}");
+			Self::deposit_event(Event::ClaimLifetimeChanged { lifetime: blocks });
+			Ok(())
+		}
+
+		/// Allow `who` to create claims while [`Config::EnforceAllowlist`] is set.
+		///
+		/// The origin must be [`Config::ClaimAdminOrigin`]. Adding an account that is already in
+		/// the [`Allowlist`] has no effect beyond the event.
+		///
+		/// ## Errors
+		///
+		/// - [`BadOrigin`](sp_runtime::DispatchError::BadOrigin) if the origin is not
+		///   [`Config::ClaimAdminOrigin`].
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn add_to_allowlist(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::ClaimAdminOrigin::ensure_origin(origin)?;
+
+			Allowlist::<T>::insert(&who, ());
+			log::debug!(target: LOG_TARGET, "added {who:?} to the allowlist");
+			Self::deposit_event(Event::AddedToAllowlist { who });
+			Ok(())
+		}
+
+		/// Stop `who` from creating claims while [`Config::EnforceAllowlist`] is set.
+		///
+		/// The origin must be [`Config::ClaimAdminOrigin`]. The claims `who` already owns are
+		/// kept.
+		///
+		/// ## Errors
+		///
+		/// - [`BadOrigin`](sp_runtime::DispatchError::BadOrigin) if the origin is not
+		///   [`Config::ClaimAdminOrigin`].
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn remove_from_allowlist(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::ClaimAdminOrigin::ensure_origin(origin)?;
+
+			Allowlist::<T>::remove(&who);
+			log::debug!(target: LOG_TARGET, "removed {who:?} from the allowlist");
+			Self::deposit_event(Event::RemovedFromAllowlist { who });
+			Ok(())
+		}
+
+		/// Let `manager` update the metadata of a claim owned by the signer and renew it, with
+		/// [`Pallet::set_claim_metadata`] and [`Pallet::renew_claim`], or take that right away with
+		/// `None`.
+		///
+		/// This replaces any manager the claim already had. Revoking and transferring the claim
+		/// remain up to its owner, and the manager is cleared when the claim changes owner.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NoSuchClaim`] if the hash has not been claimed in `namespace`.
+		/// - [`Error::NotClaimOwner`] if the claim is owned by another account.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::set_manager())]
+		pub fn set_manager(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+			manager: Option<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut info = Self::ensure_owner(namespace, &claim, &who)?;
+			info.manager = manager.clone();
+			Claims::<T>::insert(namespace, &claim, info);
+
+			log::debug!(target: LOG_TARGET, "set the manager of claim {claim:?} in namespace {namespace} to {manager:?} by {who:?}");
+			Self::deposit_event(Event::ClaimManagerSet { namespace, claim, manager });
+			Ok(())
+		}
+
+		/// Add up to `limit` claims that are missing from the [`ClaimsByOwner`] index to it.
+		///
+		/// Claims made before the index existed are not in it. This walks [`Claims`] from where
+		/// the previous call stopped, recorded in [`ReindexCursor`], so that anyone can complete
+		/// the index over several blocks. At most `limit` claims, capped to
+		/// [`Config::MaxBatchSize`], are visited, and only the weight of those is charged. Claims
+		/// that are already indexed are left as they are, and so are claims whose owner already
+		/// has [`Config::MaxClaimsPerAccount`] claims indexed.
+		///
+		/// Emits [`Event::ClaimsReindexed`] telling whether more claims remain to be visited.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::reindex_claims((*limit).min(T::MaxBatchSize::get())))]
+		pub fn reindex_claims(origin: OriginFor<T>, limit: u32) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let limit = limit.min(T::MaxBatchSize::get());
+			let mut claims = match ReindexCursor::<T>::get() {
+				Some((namespace, claim)) =>
+					Claims::<T>::iter_from(Claims::<T>::hashed_key_for(namespace, &claim)),
+				None => Claims::<T>::iter(),
+			};
+			let (mut visited, mut count) = (0u32, 0u32);
+			let mut last = None;
+			for (namespace, claim, info) in claims.by_ref().take(limit as usize) {
+				visited += 1;
+				let indexed = ClaimsByOwner::<T>::contains_key(&info.owner, (namespace, &claim));
+				// An owner with a full index keeps the claims indexed first.
+				if !indexed && Self::index_claim(&info.owner, namespace, claim.clone()).is_ok() {
+					count += 1;
+				}
+				last = Some((namespace, claim));
+			}
+			let more = claims.next().is_some();
+			if !more {
+				ReindexCursor::<T>::kill();
+			} else if let Some(last) = last {
+				ReindexCursor::<T>::put(last);
+			}
+
+			log::debug!(target: LOG_TARGET, "reindexed {count} claims, more to go: {more}");
+			Self::deposit_event(Event::ClaimsReindexed { count, more });
+			Ok(Some(T::WeightInfo::reindex_claims(visited)).into())
+		}
+
+		/// Claim a hash in [`DEFAULT_NAMESPACE`] without paying the transaction fee.
+		///
+		/// This is meant for institutions registering public-good proofs. The origin must be
+		/// [`Config::FeeExemptOrigin`], and the account it resolves to becomes the owner of a
+		/// [`ClaimKind::Document`] of algorithm [`HashAlgo::Other(0)`](HashAlgo::Other), without
+		/// metadata, expiry or lock. The deposit is held as for any other claim, and the claim
+		/// counts towards [`Config::MinClaimInterval`] as any other.
+		///
+		/// Only a successful call is free: an origin that is not exempt pays for its attempt.
+		///
+		/// ## Errors
+		///
+		/// - [`BadOrigin`](sp_runtime::DispatchError::BadOrigin) if the origin is not
+		///   [`Config::FeeExemptOrigin`].
+		/// - [`Error::TooSoon`] if the account made a claim less than [`Config::MinClaimInterval`]
+		///   blocks ago.
+		/// - Any error of [`Pallet::create_claim`] that prevents the claim from being made, such as
+		///   [`Error::AlreadyClaimed`].
+		#[pallet::call_index(18)]
+		#[pallet::weight(
+			Pallet::<T>::create_claim_weight().saturating_add(Pallet::<T>::history_weight(1))
+		)]
+		pub fn create_claim_free(
+			origin: OriginFor<T>,
+			claim: T::ClaimId,
+		) -> DispatchResultWithPostInfo {
+			let who = T::FeeExemptOrigin::ensure_origin(origin)?;
+
+			Self::ensure_not_paused()?;
+			Self::note_claim_interval(&who)?;
+			Self::do_create_claim(
+				&who,
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Other(0),
+				None,
+				None,
+				None,
+				None,
+			)?;
+			Ok(Pays::No.into())
+		}
+
+		/// Remove up to `max` claims whose expiry has passed, as the expiry sweep in
+		/// [`Hooks::on_idle`] would.
+		///
+		/// This lets anyone catch up with the expired claims when blocks have been too full for
+		/// the sweep to keep up. The claims are removed oldest first, from [`CleanupCursor`], with
+		/// their deposits released to their owners and an [`Event::ClaimExpired`] for each of
+		/// them, followed by a single [`Event::ExpiredClaimsPurged`]. At most `max` scheduled
+		/// claims, capped to [`Config::MaxBatchSize`], are visited, and only the weight actually
+		/// used is charged.
+		#[pallet::call_index(19)]
+		#[pallet::weight(Pallet::<T>::purge_expired_weight(*max))]
+		pub fn purge_expired(origin: OriginFor<T>, max: u32) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let max = max.min(T::MaxBatchSize::get());
+			let (used, count) =
+				Self::remove_expired_claims(now, Self::purge_expired_weight(max), max);
+			let more = CleanupCursor::<T>::get().is_some_and(|cursor| cursor <= now);
+
+			log::debug!(target: LOG_TARGET, "purged {count} expired claims, more to go: {more}");
+			Self::deposit_event(Event::ExpiredClaimsPurged { count, more });
+			Ok(Some(used).into())
+		}
+
+		/// Attach `tag` to a claim owned by the signer.
+		///
+		/// Tags are free-form labels, such as project names, and stay with the claim when it
+		/// changes owner. They are cleared when the claim is removed.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::TagTooLong`] if `tag` is longer than [`Config::MaxTagLen`].
+		/// - [`Error::NoSuchClaim`] if the hash has not been claimed in `namespace`.
+		/// - [`Error::NotClaimOwner`] if the claim is owned by another account.
+		/// - [`Error::DuplicateTag`] if the claim already carries `tag`.
+		/// - [`Error::TooManyTags`] if the claim already carries [`Config::MaxTagsPerClaim`] tags.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::add_tag())]
+		pub fn add_tag(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+			tag: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let tag = ClaimTagOf::<T>::try_from(tag).map_err(|_| Error::<T>::TagTooLong)?;
+			Self::ensure_owner(namespace, &claim, &who)?;
+
+			ClaimTags::<T>::try_mutate(namespace, &claim, |tags| {
+				ensure!(!tags.contains(&tag), Error::<T>::DuplicateTag);
+				tags.try_insert(tag.clone()).map_err(|_| Error::<T>::TooManyTags)?;
+				Ok::<_, Error<T>>(())
+			})?;
+
+			log::debug!(target: LOG_TARGET, "tagged claim {claim:?} in namespace {namespace} with {tag:?} by {who:?}");
+			Self::deposit_event(Event::ClaimTagAdded { namespace, claim, tag });
+			Ok(())
+		}
+
+		/// Remove `tag` from a claim owned by the signer.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NoSuchClaim`] if the hash has not been claimed in `namespace`.
+		/// - [`Error::NotClaimOwner`] if the claim is owned by another account.
+		/// - [`Error::NoSuchTag`] if the claim does not carry `tag`.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::remove_tag())]
+		pub fn remove_tag(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+			tag: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_owner(namespace, &claim, &who)?;
+			let tag = ClaimTagOf::<T>::try_from(tag).map_err(|_| Error::<T>::NoSuchTag)?;
+
+			ClaimTags::<T>::try_mutate_exists(namespace, &claim, |maybe_tags| {
+				let tags = maybe_tags.as_mut().ok_or(Error::<T>::NoSuchTag)?;
+				ensure!(tags.remove(&tag), Error::<T>::NoSuchTag);
+				if tags.is_empty() {
+					*maybe_tags = None;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			log::debug!(target: LOG_TARGET, "untagged claim {claim:?} in namespace {namespace} from {tag:?} by {who:?}");
+			Self::deposit_event(Event::ClaimTagRemoved { namespace, claim, tag });
+			Ok(())
+		}
+
+		/// Give a claim owned by the signer `alias`, a name by which it can be looked up with
+		/// [`Pallet::resolve_alias`] instead of its hash.
+		///
+		/// An alias names a single claim in `namespace`, and a claim has at most one alias, so
+		/// any alias the claim had before is freed. The alias stays with the claim when it changes
+		/// owner, and is cleared when the claim is removed.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::AliasTooLong`] if `alias` is longer than [`Config::MaxAliasLen`].
+		/// - [`Error::NoSuchClaim`] if the hash has not been claimed in `namespace`.
+		/// - [`Error::NotClaimOwner`] if the claim is owned by another account.
+		/// - [`Error::AliasTaken`] if `alias` already names a claim in `namespace`, including this
+		///   one.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::set_alias())]
+		pub fn set_alias(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+			alias: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let alias = ClaimAliasOf::<T>::try_from(alias).map_err(|_| Error::<T>::AliasTooLong)?;
+			Self::ensure_owner(namespace, &claim, &who)?;
+			ensure!(!Aliases::<T>::contains_key(namespace, &alias), Error::<T>::AliasTaken);
+
+			if let Some(previous) = ClaimAliases::<T>::get(namespace, &claim) {
+				Aliases::<T>::remove(namespace, previous);
+			}
+			Aliases::<T>::insert(namespace, &alias, &claim);
+			ClaimAliases::<T>::insert(namespace, &claim, &alias);
+
+			log::debug!(target: LOG_TARGET, "aliased claim {claim:?} in namespace {namespace} as {alias:?} by {who:?}");
+			Self::deposit_event(Event::ClaimAliasSet { namespace, claim, alias });
+			Ok(())
+		}
+
+		/// Clear the alias of a claim owned by the signer, freeing it for other claims.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NoSuchClaim`] if the hash has not been claimed in `namespace`.
+		/// - [`Error::NotClaimOwner`] if the claim is owned by another account.
+		/// - [`Error::AliasNotFound`] if the claim has no alias.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::clear_alias())]
+		pub fn clear_alias(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_owner(namespace, &claim, &who)?;
+			let alias =
+				ClaimAliases::<T>::take(namespace, &claim).ok_or(Error::<T>::AliasNotFound)?;
+			Aliases::<T>::remove(namespace, &alias);
+
+			log::debug!(target: LOG_TARGET, "cleared the alias {alias:?} of claim {claim:?} in namespace {namespace} by {who:?}");
+			Self::deposit_event(Event::ClaimAliasCleared { namespace, claim, alias });
+			Ok(())
+		}
+
+		/// Freeze a claim, so that its owner can no longer revoke or transfer it, or change its
+		/// metadata, for instance while it is disputed.
+		///
+		/// The origin must be [`Config::ClaimAdminOrigin`]. The claim can still be removed with
+		/// [`Pallet::force_revoke_claim`], and still expires. Freezing a claim that is already
+		/// frozen has no effect beyond the event.
+		///
+		/// ## Errors
+		///
+		/// - [`BadOrigin`](sp_runtime::DispatchError::BadOrigin) if the origin is not
+		///   [`Config::ClaimAdminOrigin`].
+		/// - [`Error::NoSuchClaim`] if the hash has not been claimed in `namespace`.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::freeze_claim())]
+		pub fn freeze_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+		) -> DispatchResult {
+			T::ClaimAdminOrigin::ensure_origin(origin)?;
+
+			ensure!(Claims::<T>::contains_key(namespace, &claim), Error::<T>::NoSuchClaim);
+			FrozenClaims::<T>::insert(namespace, &claim, ());
+			log::debug!(target: LOG_TARGET, "froze claim {claim:?} in namespace {namespace}");
+			Self::deposit_event(Event::ClaimFrozen { namespace, claim });
+			Ok(())
+		}
+
+		/// Thaw a claim frozen with [`Pallet::freeze_claim`], giving its owner control of it
+		/// again.
+		///
+		/// The origin must be [`Config::ClaimAdminOrigin`]. Thawing a claim that is not frozen has
+		/// no effect beyond the event.
+		///
+		/// ## Errors
+		///
+		/// - [`BadOrigin`](sp_runtime::DispatchError::BadOrigin) if the origin is not
+		///   [`Config::ClaimAdminOrigin`].
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::thaw_claim())]
+		pub fn thaw_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+		) -> DispatchResult {
+			T::ClaimAdminOrigin::ensure_origin(origin)?;
+
+			FrozenClaims::<T>::remove(namespace, &claim);
+			log::debug!(target: LOG_TARGET, "thawed claim {claim:?} in namespace {namespace}");
+			Self::deposit_event(Event::ClaimThawed { namespace, claim });
+			Ok(())
+		}
+
+		/// Restore a claim revoked while [`Config::TombstoneRevoked`] was set to its former owner.
+		///
+		/// The origin must be [`Config::ClaimAdminOrigin`]. The tombstone is removed, and the claim
+		/// is created again for its former owner as if by [`Pallet::create_claim`], with its former
+		/// kind and algorithm but without metadata or expiry, emitting [`Event::ClaimCreated`]
+		/// followed by [`Event::ClaimRestored`]. [`Config::ClaimDeposit`] is held from the owner
+		/// again. This works while claim creation is halted with [`Pallet::set_paused`].
+		///
+		/// ## Errors
+		///
+		/// - [`BadOrigin`](sp_runtime::DispatchError::BadOrigin) if the origin is not
+		///   [`Config::ClaimAdminOrigin`].
+		/// - [`Error::NotTombstoned`] if the hash has no tombstone in `namespace`.
+		/// - Any error of [`Pallet::create_claim`] that applies to the former owner, such as
+		///   [`Error::TooManyClaims`] or [`Error::InsufficientBalance`].
+		#[pallet::call_index(30)]
+		#[pallet::weight(
+			Pallet::<T>::create_claim_weight()
+				.saturating_add(Pallet::<T>::history_weight(1))
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		)]
+		pub fn restore_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+		) -> DispatchResult {
+			T::ClaimAdminOrigin::ensure_origin(origin)?;
+
+			let revoked =
+				RevokedClaims::<T>::take(namespace, &claim).ok_or(Error::<T>::NotTombstoned)?;
+			Self::do_create_claim(
+				&revoked.owner,
+				namespace,
+				claim.clone(),
+				revoked.kind,
+				revoked.algo,
+				None,
+				None,
+				None,
+				None,
+			)?;
+			log::debug!(target: LOG_TARGET, "restored claim {claim:?} in namespace {namespace} to {:?}", revoked.owner);
+			Self::deposit_event(Event::ClaimRestored { who: revoked.owner, namespace, claim });
+			Ok(())
+		}
+
+		/// Commit to a claim that the signer will reveal with [`Pallet::reveal_claim`].
+		///
+		/// `commitment` is computed with [`Pallet::commitment_of`] from the signer, the namespace
+		/// and hash to be claimed and a secret salt, so that the hash is not disclosed before it is
+		/// claimed. The commitment replaces any previous one of the signer, and is kept until it is
+		/// revealed.
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn commit_claim(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Commitments::<T>::insert(
+				&who,
+				(commitment, T::BlockNumberProvider::current_block_number()),
+			);
+
+			log::debug!(target: LOG_TARGET, "{who:?} committed to claim {commitment:?}");
+			Self::deposit_event(Event::ClaimCommitted { who, commitment });
+			Ok(())
+		}
+
+		/// Claim ownership of a hash in `namespace` that the signer committed to with
+		/// [`Pallet::commit_claim`].
+		///
+		/// The claim is created for the signer as if by [`Pallet::create_claim`], without
+		/// metadata or expiry, emitting [`Event::ClaimCreated`] followed by
+		/// [`Event::ClaimRevealed`]. The commitment is removed.
+		///
+		/// The claim counts as made in the block of the commitment. If the hash was claimed in
+		/// `namespace` after that, such as by a front-runner who saw this reveal in the pool, that
+		/// claim is removed first, its deposit is released to its owner and
+		/// [`Event::ClaimSuperseded`] is emitted. A claim that has expired but has not been swept
+		/// yet is removed as [`Pallet::create_claim`] would.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::CommitmentNotFound`] if the signer has no commitment.
+		/// - [`Error::RevealTooEarly`] if the commitment was made less than [`Config::RevealDelay`]
+		///   blocks ago.
+		/// - [`Error::BadReveal`] if the signer, `namespace`, `claim` and `salt` do not match the
+		///   commitment.
+		/// - [`Error::TooSoon`] if the signer made a claim less than [`Config::MinClaimInterval`]
+		///   blocks ago.
+		/// - Any error of [`Pallet::create_claim`] that prevents the signer from making the claim,
+		///   such as [`Error::AlreadyClaimed`].
+		#[pallet::call_index(32)]
+		#[pallet::weight(
+			Pallet::<T>::create_claim_weight()
+				.saturating_add(Pallet::<T>::history_weight(1))
+				.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+				.saturating_add(Pallet::<T>::expired_claim_weight())
+		)]
+		pub fn reveal_claim(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+			kind: ClaimKind,
+			algo: HashAlgo,
+			salt: [u8; 32],
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_not_paused()?;
+			let (commitment, committed_at) =
+				Commitments::<T>::get(&who).ok_or(Error::<T>::CommitmentNotFound)?;
+			ensure!(
+				T::BlockNumberProvider::current_block_number() >=
+					committed_at.saturating_add(T::RevealDelay::get()),
+				Error::<T>::RevealTooEarly
+			);
+			ensure!(
+				Self::commitment_of(&who, namespace, &claim, &salt) == commitment,
+				Error::<T>::BadReveal
+			);
+			Self::note_claim_interval(&who)?;
+
+			Commitments::<T>::remove(&who);
+			let made_way = Self::make_way_for_reveal(namespace, &claim, committed_at);
+			Self::do_create_claim(
+				&who,
+				namespace,
+				claim.clone(),
+				kind,
+				algo,
+				None,
+				None,
+				None,
+				None,
+			)?;
+
+			ClaimCommittedAt::<T>::insert(namespace, &claim, committed_at);
+
+			log::debug!(target: LOG_TARGET, "revealed claim {claim:?} in namespace {namespace} for {who:?}");
+			Self::deposit_event(Event::ClaimRevealed { who, namespace, claim });
+			if !made_way {
+				let weight = Self::create_claim_weight()
+					.saturating_add(Self::history_weight(1))
+					.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+				return Ok(Some(weight).into());
+			}
+			Ok(().into())
+		}
+
+		/// Attach `note` to a claim owned by the signer, such as a contact or payment pointer for
+		/// marketplaces, or clear it with `None`.
+		///
+		/// The note replaces any previous one. It is not part of the claim, and is cleared when
+		/// the claim changes owner or is removed.
+		///
+		/// ## Errors
+		///
+		/// - [`Error::NoteTooLong`] if `note` is longer than [`Config::MaxNoteLen`].
+		/// - [`Error::NoSuchClaim`] if the hash has not been claimed in `namespace`.
+		/// - [`Error::NotClaimOwner`] if the claim is owned by another account.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::set_owner_note())]
+		pub fn set_owner_note(
+			origin: OriginFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+			note: Option<Vec<u8>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let note = note
+				.map(ClaimNoteOf::<T>::try_from)
+				.transpose()
+				.map_err(|_| Error::<T>::NoteTooLong)?;
+			Self::ensure_owner(namespace, &claim, &who)?;
+			ClaimNotes::<T>::set(namespace, &claim, note.clone());
+
+			log::debug!(target: LOG_TARGET, "set the note of claim {claim:?} in namespace {namespace} by {who:?}");
+			Self::deposit_event(Event::OwnerNoteSet { namespace, claim, note });
+			Ok(())
+		}
+
+		/// Revoke the claim on the document `data` in [`DEFAULT_NAMESPACE`], owned by the signer.
+		///
+		/// The claim is found under [`Pallet::claim_of_preimage`], the same hash
+		/// `verify_preimage` of the runtime API looks it up under, so clients do not have to hash
+		/// the document themselves. Otherwise this is [`Pallet::revoke_claim`].
+		///
+		/// ## Errors
+		///
+		/// - [`Error::PreimageTooLong`] if `data` is longer than [`Config::MaxPreimageLen`].
+		/// - [`Error::NoSuchClaim`] if the hash of `data` has not been claimed.
+		/// - [`Error::NotClaimOwner`] if the claim is owned by another account.
+		/// - [`Error::ClaimLocked`] if the claim is locked.
+		/// - [`Error::ClaimFrozen`] if the claim is frozen with [`Pallet::freeze_claim`].
+		#[pallet::call_index(34)]
+		#[pallet::weight(
+			T::WeightInfo::revoke_claim_by_preimage(data.len() as u32)
+				.saturating_add(Pallet::<T>::history_weight(1))
+		)]
+		pub fn revoke_claim_by_preimage(origin: OriginFor<T>, data: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(data.len() <= T::MaxPreimageLen::get() as usize, Error::<T>::PreimageTooLong);
+			let claim = Self::claim_of_preimage(&data).ok_or(Error::<T>::NoSuchClaim)?;
+			Self::do_revoke_claim(who, DEFAULT_NAMESPACE, claim)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The owner of `claim` in `namespace` and the block number at which it was claimed, if it
+		/// exists.
+		pub fn claim_info(
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+		) -> Option<(T::AccountId, BlockNumberFor<T>)> {
+			Claims::<T>::get(namespace, &claim).map(|info| (info.owner, info.created_at))
+		}
+
+		/// The owner of `claim` in `namespace`, the block number at which it was claimed and its
+		/// age in blocks at the current block, if it exists.
+		pub fn resolve_claim(
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+		) -> Option<(T::AccountId, BlockNumberFor<T>, BlockNumberFor<T>)> {
+			let now = T::BlockNumberProvider::current_block_number();
+			Claims::<T>::get(namespace, &claim)
+				.map(|info| (info.owner, info.created_at, now.saturating_sub(info.created_at)))
+		}
+
+		/// The account that owns `claim` in `namespace`, if it exists.
+		///
+		/// This is also available through [`ClaimInspect`] and [`ClaimRegistry`], which other
+		/// pallets should prefer.
+		pub fn owner_of(namespace: NamespaceId, claim: &T::ClaimId) -> Option<T::AccountId> {
+			Claims::<T>::get(namespace, claim).map(|info| info.owner)
+		}
+
+		/// The tags attached to `claim` in `namespace`, in lexicographic order.
+		pub fn tags_of(namespace: NamespaceId, claim: &T::ClaimId) -> Vec<ClaimTagOf<T>> {
+			ClaimTags::<T>::get(namespace, claim).into_iter().collect()
+		}
+
+		/// The claim named `alias` in `namespace`, if any.
+		pub fn resolve_alias(namespace: NamespaceId, alias: &[u8]) -> Option<T::ClaimId> {
+			let alias = ClaimAliasOf::<T>::try_from(alias.to_vec()).ok()?;
+			Aliases::<T>::get(namespace, alias)
+		}
+
+		/// The alias of `claim` in `namespace`, if it has one.
+		pub fn alias_of(namespace: NamespaceId, claim: &T::ClaimId) -> Option<ClaimAliasOf<T>> {
+			ClaimAliases::<T>::get(namespace, claim)
+		}
+
+		/// Whether `claim` has been claimed in `namespace`.
+		///
+		/// This and [`Pallet::is_owner`] are plain functions, not view functions: they can only be
+		/// called from within the runtime, and no query is generated for them.
+		/// `#[pallet::view_functions]` first shipped in polkadot-sdk stable2503, while the pallet
+		/// is built against stable2407.
+		pub fn claim_exists(namespace: NamespaceId, claim: T::ClaimId) -> bool {
+			Claims::<T>::contains_key(namespace, &claim)
+		}
+
+		/// Whether `who` owns `claim` in `namespace`.
+		pub fn is_owner(namespace: NamespaceId, claim: T::ClaimId, who: T::AccountId) -> bool {
+			Self::owner_of(namespace, &claim).is_some_and(|owner| owner == who)
+		}
+
+		/// Check that `who` owns `claim` in `namespace`, returning the claim's info if so.
+		///
+		/// Fails with [`Error::NoSuchClaim`] if the claim does not exist, or with
+		/// [`Error::NotClaimOwner`] if it belongs to someone else. Pallets building on the registry
+		/// can use this to reject a call with the same errors as this pallet's own calls.
+		pub fn ensure_owner(
+			namespace: NamespaceId,
+			claim: &T::ClaimId,
+			who: &T::AccountId,
+		) -> Result<ClaimInfoOf<T>, Error<T>> {
+			let info = Claims::<T>::get(namespace, claim).ok_or(Error::<T>::NoSuchClaim)?;
+			ensure!(info.owner == *who, Error::<T>::NotClaimOwner);
+			Ok(info)
+		}
+
+		/// The information of `claim` in `namespace`, if it exists and `who` is either its owner or
+		/// its manager.
+		///
+		/// Fails with [`Error::NoSuchClaim`] if the claim does not exist, or with
+		/// [`Error::NotClaimOwner`] if `who` may not manage it.
+		pub fn ensure_owner_or_manager(
+			namespace: NamespaceId,
+			claim: &T::ClaimId,
+			who: &T::AccountId,
+		) -> Result<ClaimInfoOf<T>, Error<T>> {
+			let info = Claims::<T>::get(namespace, claim).ok_or(Error::<T>::NoSuchClaim)?;
+			ensure!(
+				info.owner == *who || info.manager.as_ref() == Some(who),
+				Error::<T>::NotClaimOwner
+			);
+			Ok(info)
+		}
+
+		/// Check that the claim described by `info` is not locked in the current block.
+		///
+		/// Fails with [`Error::ClaimLocked`] until the current block is past
+		/// [`ClaimInfo::lock_until`].
+		pub fn ensure_unlocked(info: &ClaimInfoOf<T>) -> Result<(), Error<T>> {
+			let now = T::BlockNumberProvider::current_block_number();
+			ensure!(info.lock_until.is_none_or(|until| now > until), Error::<T>::ClaimLocked);
+			Ok(())
+		}
+
+		/// Ensure that `claim` in `namespace` is not frozen with [`Pallet::freeze_claim`].
+		pub fn ensure_not_frozen(
+			namespace: NamespaceId,
+			claim: &T::ClaimId,
+		) -> Result<(), Error<T>> {
+			ensure!(!FrozenClaims::<T>::contains_key(namespace, claim), Error::<T>::ClaimFrozen);
+			Ok(())
+		}
+
+		/// Whether `claim` in `namespace` is frozen with [`Pallet::freeze_claim`].
+		pub fn is_frozen(namespace: NamespaceId, claim: &T::ClaimId) -> bool {
+			FrozenClaims::<T>::contains_key(namespace, claim)
+		}
+
+		/// The tombstone of `claim` in `namespace`, if it was revoked while
+		/// [`Config::TombstoneRevoked`] was set.
+		pub fn revoked_claim(
+			namespace: NamespaceId,
+			claim: &T::ClaimId,
+		) -> Option<RevokedClaimOf<T>> {
+			RevokedClaims::<T>::get(namespace, claim)
+		}
+
+		/// The note attached to `claim` in `namespace` by its owner, if any.
+		pub fn note_of(namespace: NamespaceId, claim: &T::ClaimId) -> Option<ClaimNoteOf<T>> {
+			ClaimNotes::<T>::get(namespace, claim)
+		}
+
+		/// The commitment of `who` to `claim` in `namespace` with `salt`, to be made with
+		/// [`Pallet::commit_claim`].
+		///
+		/// This is [`frame_system::Config::Hashing`] of the SCALE encoding of `who`, `namespace`,
+		/// `claim` and `salt`, in that order. Binding the committer and the namespace keeps anyone
+		/// else from revealing a copied commitment, and the commitment from being revealed in
+		/// another namespace.
+		pub fn commitment_of(
+			who: &T::AccountId,
+			namespace: NamespaceId,
+			claim: &T::ClaimId,
+			salt: &[u8; 32],
+		) -> T::Hash {
+			T::Hashing::hash_of(&(who, namespace, claim, salt))
+		}
+
+		/// The claim on the document `data`: [`frame_system::Config::Hashing`] of `data`, read as
+		/// a [`Config::ClaimId`], or `None` if the hash is not one.
+		pub fn claim_of_preimage(data: &[u8]) -> Option<T::ClaimId> {
+			T::ClaimId::decode(&mut T::Hashing::hash(data).as_ref()).ok()
+		}
+
+		/// The commitment made by `who` with [`Pallet::commit_claim`] and the block it was made
+		/// in, if any.
+		pub fn commitment(who: &T::AccountId) -> Option<(T::Hash, BlockNumberFor<T>)> {
+			Commitments::<T>::get(who)
+		}
+
+		/// The IPFS identifier attached to `claim` in `namespace`, if any.
+		pub fn cid_of(namespace: NamespaceId, claim: &T::ClaimId) -> Option<Cid> {
+			ClaimCids::<T>::get(namespace, claim)
+		}
+
+		/// The block number at which `claim` was originally made in `namespace`, if it exists.
+		pub fn created_at(namespace: NamespaceId, claim: &T::ClaimId) -> Option<BlockNumberFor<T>> {
+			Claims::<T>::get(namespace, claim).map(|info| info.created_at)
+		}
+
+		/// The hashing algorithm recorded for `claim` in `namespace`, if it exists.
+		pub fn algo_of(namespace: NamespaceId, claim: &T::ClaimId) -> Option<HashAlgo> {
+			Claims::<T>::get(namespace, claim).map(|info| info.algo)
+		}
+
+		/// The total number of claims, across all namespaces.
+		pub fn claim_count() -> u32 {
+			ClaimCount::<T>::get()
+		}
+
+		/// All the claims owned by `owner`, read from the [`ClaimsByOwner`] index in the order of
+		/// the map.
+		///
+		/// This is not capped, so it is meant to be called from a runtime API. Use
+		/// [`Pallet::try_claims_of`] from within a block.
+		pub fn claims_of(owner: &T::AccountId) -> Vec<(NamespaceId, T::ClaimId)> {
+			ClaimsByOwner::<T>::iter_key_prefix(owner).collect()
+		}
+
+		/// All the claims owned by `owner`, as with [`Pallet::claims_of`], but failing with
+		/// [`Error::QueryTooLarge`] rather than reading more than [`Config::MaxIterationItems`]
+		/// entries of the index.
+		///
+		/// This is also available through [`ClaimInspect`], which other pallets should prefer.
+		pub fn try_claims_of(
+			owner: &T::AccountId,
+		) -> Result<Vec<(NamespaceId, T::ClaimId)>, Error<T>> {
+			Self::bounded_collect(ClaimsByOwner::<T>::iter_key_prefix(owner))
+		}
+
+		/// The number of claims owned by `owner`, read from [`ClaimCountByOwner`] without
+		/// iterating the [`ClaimsByOwner`] index.
+		pub fn claim_count_of(owner: &T::AccountId) -> u32 {
+			ClaimCountByOwner::<T>::get(owner)
+		}
+
+		/// A page of the claims `owner` holds in `namespace`, in the order of the
+		/// [`ClaimsByOwner`] index.
+		///
+		/// The page starts at `start_key`, or at the first claim if it is `None`, and holds at most
+		/// `limit` claims, capped to [`Config::MaxQueryPageSize`]. The returned cursor is the
+		/// `start_key` of the next page, and is `None` once the last claim has been returned.
+		/// A `start_key` that is not among the claims of `owner` yields an empty page.
+		///
+		/// The index is read from `start_key` on, and only as far as the end of the page, so the
+		/// cost of a page does not grow with the number of claims before it.
+		pub fn claims_of_paged(
+			owner: &T::AccountId,
+			namespace: NamespaceId,
+			start_key: Option<T::ClaimId>,
+			limit: u32,
+		) -> (Vec<T::ClaimId>, Option<T::ClaimId>) {
+			let limit = limit.min(T::MaxQueryPageSize::get()) as usize;
+			// Read one claim past the page, which is the cursor of the next page.
+			let mut claims: Vec<_> = match start_key {
+				Some(start_key) => {
+					let key = (namespace, start_key);
+					if !ClaimsByOwner::<T>::contains_key(owner, &key) {
+						return (Vec::new(), None);
+					}
+					let raw_start_key = ClaimsByOwner::<T>::hashed_key_for(owner, &key);
+					// The iteration starts after `raw_start_key`, so put the start back in front.
+					core::iter::once(key.1)
+						.chain(Self::owner_claims_in(
+							namespace,
+							ClaimsByOwner::<T>::iter_key_prefix_from(owner, raw_start_key),
+						))
+						.take(limit.saturating_add(1))
+						.collect()
+				},
+				None =>
+					Self::owner_claims_in(namespace, ClaimsByOwner::<T>::iter_key_prefix(owner))
+						.take(limit.saturating_add(1))
+						.collect(),
+			};
+			let next = if claims.len() > limit { claims.pop() } else { None };
+			(claims, next)
+		}
+
+		/// The hashes of the entries of `keys`, keys of [`ClaimsByOwner`], that are in `namespace`.
+		fn owner_claims_in(
+			namespace: NamespaceId,
+			keys: impl Iterator<Item = (NamespaceId, T::ClaimId)>,
+		) -> impl Iterator<Item = T::ClaimId> {
+			keys.filter_map(move |(ns, claim)| (ns == namespace).then_some(claim))
+		}
+
+		/// Every claim in every namespace, with its owner and the block number at which it was
+		/// claimed, in the order of the [`Claims`] map.
+		///
+		/// This reads the whole map, so it is left out of production builds and only compiled for
+		/// tests and benchmarks.
+		#[cfg(any(test, feature = "test-utils", feature = "runtime-benchmarks"))]
+		pub fn iter_claims(
+		) -> impl Iterator<Item = (NamespaceId, T::ClaimId, (T::AccountId, BlockNumberFor<T>))> {
+			Claims::<T>::iter()
+				.map(|(namespace, claim, info)| (namespace, claim, (info.owner, info.created_at)))
+		}
+
+		/// A page of the claims in `namespace`, with the owner of each and the block number at
+		/// which it was claimed, in the order of the [`Claims`] map.
+		///
+		/// Paging works as with [`Pallet::claims_of_paged`]: the page starts at `start_key`, or at
+		/// the first claim if it is `None`, holds at most `limit` claims, capped to
+		/// [`Config::MaxQueryPageSize`], and comes with the `start_key` of the next page, if any. A
+		/// `start_key` that has not been claimed in `namespace` yields an empty page.
+		pub fn claims_paged(
+			namespace: NamespaceId,
+			start_key: Option<T::ClaimId>,
+			limit: u32,
+		) -> (Vec<ClaimRecordOf<T>>, Option<T::ClaimId>) {
+			let limit = limit.min(T::MaxQueryPageSize::get()) as usize;
+			// Read one claim past the page to find the start of the next one.
+			let mut claims: Vec<_> = match start_key {
+				Some(start_key) => {
+					let Some(first) = Claims::<T>::get(namespace, &start_key) else {
+						return (Vec::new(), None);
+					};
+					let rest = Claims::<T>::iter_prefix_from(
+						namespace,
+						Claims::<T>::hashed_key_for(namespace, &start_key),
+					);
+					core::iter::once((start_key, first)).chain(rest).take(limit + 1).collect()
+				},
+				None => Claims::<T>::iter_prefix(namespace).take(limit + 1).collect(),
+			};
+			let next =
+				(claims.len() > limit).then(|| claims.pop()).flatten().map(|(claim, _)| claim);
+			let claims = claims
+				.into_iter()
+				.map(|(claim, info)| (claim, info.owner, info.created_at))
+				.collect();
+			(claims, next)
+		}
+
+		/// A page of the claims in every namespace, with the namespace and owner of each and the
+		/// block number at which it was claimed, in the order of the [`Claims`] map.
+		///
+		/// Paging works as with [`Pallet::claims_paged`], except that the keys name a namespace as
+		/// well as a hash.
+		pub fn all_claims_paged(
+			start_key: Option<NamespacedClaimKeyOf<T>>,
+			limit: u32,
+		) -> (Vec<NamespacedClaimRecordOf<T>>, Option<NamespacedClaimKeyOf<T>>) {
+			let limit = limit.min(T::MaxQueryPageSize::get()) as usize;
+			// Read one claim past the page to find the start of the next one.
+			let mut claims: Vec<_> = match start_key {
+				Some((namespace, start_key)) => {
+					let Some(first) = Claims::<T>::get(namespace, &start_key) else {
+						return (Vec::new(), None);
+					};
+					let rest =
+						Claims::<T>::iter_from(Claims::<T>::hashed_key_for(namespace, &start_key));
+					core::iter::once((namespace, start_key, first))
+						.chain(rest)
+						.take(limit + 1)
+						.collect()
+				},
+				None => Claims::<T>::iter().take(limit + 1).collect(),
+			};
+			let next = (claims.len() > limit)
+				.then(|| claims.pop())
+				.flatten()
+				.map(|(namespace, claim, _)| (namespace, claim));
+			let claims = claims
+				.into_iter()
+				.map(|(namespace, claim, info)| (namespace, claim, info.owner, info.created_at))
+				.collect();
+			(claims, next)
+		}
+
+		/// All the claims of the given `kind`, across all namespaces.
+		///
+		/// This iterates over every claim, so it is meant to be called from a runtime API rather
+		/// than from within a block. Use [`Pallet::try_claims_of_kind`] there.
+		pub fn claims_of_kind(kind: ClaimKind) -> Vec<(NamespaceId, T::ClaimId)> {
+			Claims::<T>::iter()
+				.filter_map(|(namespace, claim, info)| {
+					(info.kind == kind).then_some((namespace, claim))
+				})
+				.collect()
+		}
+
+		/// All the claims of the given `kind`, as with [`Pallet::claims_of_kind`], but failing
+		/// with [`Error::QueryTooLarge`] rather than visiting more than
+		/// [`Config::MaxIterationItems`] claims, whatever their kind.
+		pub fn try_claims_of_kind(
+			kind: ClaimKind,
+		) -> Result<Vec<(NamespaceId, T::ClaimId)>, Error<T>> {
+			let claims = Self::bounded_collect(Claims::<T>::iter())?;
+			Ok(claims
+				.into_iter()
+				.filter_map(|(namespace, claim, info)| {
+					(info.kind == kind).then_some((namespace, claim))
+				})
+				.collect())
+		}
+
+		/// Collect `items`, failing with [`Error::QueryTooLarge`] as soon as there are more than
+		/// [`Config::MaxIterationItems`] of them.
+		fn bounded_collect<I: Iterator>(items: I) -> Result<Vec<I::Item>, Error<T>> {
+			let max = T::MaxIterationItems::get() as usize;
+			let items: Vec<_> = items.take(max.saturating_add(1)).collect();
+			ensure!(items.len() <= max, Error::<T>::QueryTooLarge);
+			Ok(items)
+		}
+
+		/// All the claims created in block `n` that still exist, read from the [`ClaimsByBlock`]
+		/// index.
+		pub fn claims_created_in_block(n: BlockNumberFor<T>) -> Vec<(NamespaceId, T::ClaimId)> {
+			ClaimsByBlock::<T>::get(n).into_inner()
+		}
+
+		/// All the claims created from block `from` to block `to`, both included, that still
+		/// exist, across all namespaces and in the order they were created, with the owner of
+		/// each.
+		///
+		/// This reads the [`ClaimsByBlock`] bucket of every block in the range. An empty range,
+		/// where `from` is after `to`, has no claims. Returns `None` if the range spans more than
+		/// [`Config::MaxQueryRange`] blocks.
+		pub fn claims_between(
+			from: BlockNumberFor<T>,
+			to: BlockNumberFor<T>,
+		) -> Option<Vec<NamespacedClaimOf<T>>> {
+			if from > to {
+				return Some(Vec::new());
+			}
+			if to - from >= T::MaxQueryRange::get() {
+				return None;
+			}
+			let mut claims = Vec::new();
+			let mut block = from;
+			loop {
+				claims.extend(ClaimsByBlock::<T>::get(block).into_iter().filter_map(
+					|(namespace, claim)| {
+						Claims::<T>::get(namespace, &claim)
+							.map(|info| (namespace, claim, info.owner))
+					},
+				));
+				if block == to {
+					break;
+				}
+				block.saturating_inc();
+			}
+			Some(claims)
+		}
+
+		/// The number of claims, of those scheduled to expire in the next block, and whether claim
+		/// creation is halted, in a single [`ClaimStats`].
+		///
+		/// Only counters and flags are read, so this is cheap whatever the number of claims.
+		pub fn stats() -> ClaimStats {
+			let next_block =
+				T::BlockNumberProvider::current_block_number().saturating_add(One::one());
+			ClaimStats {
+				total: ClaimCount::<T>::get(),
+				expiring_next_block: ExpiringAt::<T>::decode_len(next_block).unwrap_or(0) as u32,
+				paused: Paused::<T>::get(),
+			}
+		}
+
+		/// The [`ClaimsRoot`], committing to every claim created or removed so far.
+		pub fn claims_root() -> T::Hash {
+			ClaimsRoot::<T>::get()
+		}
+
+		/// The number of blocks after which claims created with [`Pallet::create_claims`] or
+		/// renewed with [`Pallet::renew_claim`] expire: the lifetime set with
+		/// [`Pallet::set_claim_lifetime`], if any, or else [`Config::ClaimLifetime`].
+		pub fn claim_lifetime() -> BlockNumberFor<T> {
+			ClaimLifetimeOverride::<T>::get().unwrap_or_else(T::ClaimLifetime::get)
+		}
+
+		/// The namespace and hash of the claim stored under `raw`, a full storage key of
+		/// [`Claims`] such as those returned by the `state_getKeys` RPC.
+		///
+		/// Both parts of the key are hashed with [`Blake2_128Concat`], which appends the encoded
+		/// key to its hash, so they can be recovered. Returns `None` if `raw` is not a key of
+		/// [`Claims`], is truncated or too long, or if a hash does not match the key it precedes.
+		pub fn claim_from_storage_key(raw: &[u8]) -> Option<(NamespaceId, T::ClaimId)> {
+			let mut rest = raw.strip_prefix(&Claims::<T>::final_prefix()[..])?;
+			let namespace = Self::decode_blake2_128_concat(&mut rest)?;
+			let claim = Self::decode_blake2_128_concat(&mut rest)?;
+			rest.is_empty().then_some((namespace, claim))
+		}
+
+		/// All the hashes claimed in `namespace`.
+		///
+		/// This iterates over every claim of the namespace, so it is meant to be called from a
+		/// runtime API rather than from within a block.
+		pub fn claims_in_namespace(namespace: NamespaceId) -> Vec<T::ClaimId> {
+			Claims::<T>::iter_key_prefix(namespace).collect()
+		}
+
+		/// Register `claim` in `namespace` as owned by `who`, holding the deposit, scheduling it
+		/// to expire at `expires_at` and locking it until `lock_until`, if any.
+		#[allow(clippy::too_many_arguments)]
+		pub(crate) fn do_create_claim(
+			who: &T::AccountId,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+			kind: ClaimKind,
+			algo: HashAlgo,
+			metadata: Option<ClaimMetadataOf<T>>,
+			expires_at: Option<BlockNumberFor<T>>,
+			lock_until: Option<BlockNumberFor<T>>,
+			cid: Option<Cid>,
+		) -> DispatchResult {
+			Self::ensure_allowlisted(who)?;
+			Self::ensure_valid_hash(&claim)?;
+			ensure!(!Claims::<T>::contains_key(namespace, &claim), Error::<T>::AlreadyClaimed);
+			ensure!(
+				!PendingClaims::<T>::contains_key(namespace, &claim),
+				Error::<T>::AlreadyClaimed
+			);
+			ensure!(
+				!RevokedClaims::<T>::contains_key(namespace, &claim),
+				Error::<T>::ClaimTombstoned
+			);
+			ensure!(
+				ClaimCount::<T>::get() < T::MaxTotalClaims::get(),
+				Error::<T>::ClaimCapacityReached
+			);
+
+			let current_block = T::BlockNumberProvider::current_block_number();
+			if let Some(expires_at) = expires_at {
+				ExpiringAt::<T>::try_append(expires_at, (namespace, claim.clone()))
+					.map_err(|_| Error::<T>::TooManyExpiringInBlock)?;
+			}
+
+			Self::index_claim(who, namespace, claim.clone())?;
+			Self::index_block(current_block, namespace, claim.clone())?;
+			let metadata_len = metadata.as_ref().map_or(0, |metadata| metadata.len());
+			let deposit =
+				T::ClaimDeposit::get().saturating_add(Self::metadata_deposit(metadata_len));
+			T::Fungible::hold(&HoldReason::ClaimDeposit.into(), who, deposit)
+				.map_err(|_| Error::<T>::InsufficientBalance)?;
+			let id = Self::next_claim_id()?;
+			let info = ClaimInfo {
+				id,
+				owner: who.clone(),
+				created_at: current_block,
+				deposit,
+				expires_at,
+				kind,
+				algo,
+				lock_until,
+				manager: None,
+			};
+			Claims::<T>::insert(namespace, &claim, info);
+			ClaimCount::<T>::mutate(|count| count.saturating_inc());
+			if let Some(metadata) = metadata {
+				ClaimMetadata::<T>::insert(namespace, &claim, metadata);
+			}
+			if let Some(cid) = &cid {
+				ClaimCids::<T>::insert(namespace, &claim, cid);
+			}
+
+			Self::record_history(namespace, &claim, HistoryAction::Created);
+			Self::accumulate_root(namespace, &claim, HistoryAction::Created);
+			T::OnClaimChanged::on_created(namespace, who, &claim);
+			log::debug!(target: LOG_TARGET, "created claim {claim:?} in namespace {namespace} for {who:?}");
+			Self::deposit_event(Event::ClaimCreated {
+				who: who.clone(),
+				namespace,
+				claim,
+				kind,
+				algo,
+				id,
+				cid,
+			});
+			Ok(())
+		}
+
+		/// Take the next id from [`NextClaimId`].
+		fn next_claim_id() -> Result<u64, Error<T>> {
+			NextClaimId::<T>::try_mutate(|next| {
+				let id = *next;
+				*next = id.checked_add(1).ok_or(Error::<T>::ClaimIdsExhausted)?;
+				Ok(id)
+			})
+		}
+
+		/// Check that claim creation is not halted with [`Pallet::set_paused`].
+		pub(crate) fn ensure_not_paused() -> Result<(), Error<T>> {
+			ensure!(!Paused::<T>::get(), Error::<T>::Paused);
+			Ok(())
+		}
+
+		/// Check that `who` may create claims, as far as the [`Allowlist`] is concerned.
+		fn ensure_allowlisted(who: &T::AccountId) -> Result<(), Error<T>> {
+			ensure!(
+				!T::EnforceAllowlist::get() || Allowlist::<T>::contains_key(who),
+				Error::<T>::NotAllowlisted
+			);
+			Ok(())
+		}
+
+		/// Check that `who` waited [`Config::MinClaimInterval`] blocks since their last claim, and
+		/// record that they claim in the current block.
+		fn note_claim_interval(who: &T::AccountId) -> Result<(), Error<T>> {
+			let interval = T::MinClaimInterval::get();
+			if interval.is_zero() {
+				return Ok(());
+			}
+			let now = T::BlockNumberProvider::current_block_number();
+			if let Some(last) = LastClaimAt::<T>::get(who) {
+				ensure!(now.saturating_sub(last) >= interval, Error::<T>::TooSoon);
+			}
+			LastClaimAt::<T>::insert(who, now);
+			Ok(())
+		}
+
+		/// Check that `claim` is a hash that may be claimed at all.
+		fn ensure_valid_hash(claim: &T::ClaimId) -> Result<(), Error<T>> {
+			ensure!(
+				!(T::RejectDefaultHash::get() && *claim == T::ClaimId::default()),
+				Error::<T>::InvalidClaimHash
+			);
+			Ok(())
+		}
+
+		/// The deposit covering `len` bytes of metadata.
+		fn metadata_deposit(len: usize) -> BalanceOf<T> {
+			T::MetadataDepositPerByte::get().saturating_mul((len as u32).into())
+		}
+
+		/// Add `claim` in `namespace` to the claims owned by `owner`, and count it in
+		/// [`ClaimCountByOwner`].
+		fn index_claim(
+			owner: &T::AccountId,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+		) -> DispatchResult {
+			let count = ClaimCountByOwner::<T>::get(owner);
+			ensure!(count < T::MaxClaimsPerAccount::get(), Error::<T>::TooManyClaims);
+			ClaimsByOwner::<T>::insert(owner, (namespace, claim), ());
+			ClaimCountByOwner::<T>::insert(owner, count.saturating_add(1));
+			Ok(())
+		}
+
+		/// Add `claim` in `namespace` to the claims created in `block`.
+		fn index_block(
+			block: BlockNumberFor<T>,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+		) -> DispatchResult {
+			ClaimsByBlock::<T>::try_append(block, (namespace, claim))
+				.map_err(|_| Error::<T>::BlockClaimLimitReached.into())
+		}
+
+		/// Deposit `event` with the topic of the claim it is about, if any.
+		///
+		/// See the topics of [`Event`].
+		pub(super) fn deposit_event(event: Event<T>) {
+			let topic = event.claim().map(T::Hashing::hash_of);
+			let event = <T as Config>::RuntimeEvent::from(event);
+			frame_system::Pallet::<T>::deposit_event_indexed(topic.as_slice(), event.into());
+		}
+
+		/// Revoke `claim` in `namespace` for its owner `who`, as [`Pallet::revoke_claim`].
+		fn do_revoke_claim(
+			who: T::AccountId,
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+		) -> DispatchResult {
+			let info = Self::ensure_owner(namespace, &claim, &who)?;
+			Self::ensure_unlocked(&info)?;
+			Self::ensure_not_frozen(namespace, &claim)?;
+
+			Self::remove_claim(namespace, &claim, &who, info.deposit);
+			Self::tombstone(namespace, &claim, &info);
+
+			log::debug!(target: LOG_TARGET, "revoked claim {claim:?} in namespace {namespace} by {who:?}");
+			Self::deposit_event(Event::ClaimRevoked {
+				who,
+				namespace,
+				claim,
+				created_at: info.created_at,
+				deposit_returned: info.deposit,
+				id: info.id,
+			});
+			Ok(())
+		}
+
+		/// Move `claim` in `namespace` from its current owner to `dest`, together with its
+		/// deposit, and clear any pending approval.
+		fn do_transfer_claim(
+			namespace: NamespaceId,
+			claim: T::ClaimId,
+			mut info: ClaimInfoOf<T>,
+			dest: T::AccountId,
+		) -> DispatchResult {
+			let from = info.owner;
+			Self::unindex_claim(&from, namespace, &claim);
+			Self::index_claim(&dest, namespace, claim.clone())?;
+			T::Fungible::transfer_on_hold(
+				&HoldReason::ClaimDeposit.into(),
+				&from,
+				&dest,
+				info.deposit,
+				Precision::Exact,
+				Restriction::OnHold,
+				Fortitude::Polite,
+			)?;
+			info.owner = dest.clone();
+			info.manager = None;
+			let id = info.id;
+			Claims::<T>::insert(namespace, &claim, info);
+			Approvals::<T>::remove(namespace, &claim);
+			ClaimNotes::<T>::remove(namespace, &claim);
+			Self::record_history(namespace, &claim, HistoryAction::Transferred);
+
+			log::debug!(target: LOG_TARGET, "transferred claim {claim:?} in namespace {namespace} from {from:?} to {dest:?}");
+			Self::deposit_event(Event::ClaimTransferred { from, to: dest, namespace, claim, id });
+			Ok(())
+		}
+
+		/// Remove `claim` in `namespace`, its metadata and any pending approval, and return its
+		/// `deposit` to `owner`.
+		fn remove_claim(
+			namespace: NamespaceId,
+			claim: &T::ClaimId,
+			owner: &T::AccountId,
+			deposit: BalanceOf<T>,
+		) {
+			Self::clear_claim(namespace, claim, owner);
+			Self::release_deposit(namespace, claim, owner, deposit);
+		}
+
+		/// Leave a tombstone for `claim` in `namespace`, just revoked by its owner, if
+		/// [`Config::TombstoneRevoked`] is set.
+		fn tombstone(namespace: NamespaceId, claim: &T::ClaimId, info: &ClaimInfoOf<T>) {
+			if !T::TombstoneRevoked::get() {
+				return;
+			}
+			let revoked = RevokedClaim {
+				owner: info.owner.clone(),
+				created_at: info.created_at,
+				revoked_at: T::BlockNumberProvider::current_block_number(),
+				kind: info.kind,
+				algo: info.algo,
+			};
+			RevokedClaims::<T>::insert(namespace, claim, revoked);
+		}
+
+		/// Remove `claim` in `namespace` from storage, leaving its deposit on hold.
+		fn clear_claim(namespace: NamespaceId, claim: &T::ClaimId, owner: &T::AccountId) {
+			Self::unindex_claim(owner, namespace, claim);
+			if let Some(info) = Claims::<T>::take(namespace, claim) {
+				Self::unindex_block(info.created_at, namespace, claim);
+			}
+			ClaimCount::<T>::mutate(|count| count.saturating_dec());
+			ClaimMetadata::<T>::remove(namespace, claim);
+			ClaimCids::<T>::remove(namespace, claim);
+			ClaimNotes::<T>::remove(namespace, claim);
+			ClaimCommittedAt::<T>::remove(namespace, claim);
+			ClaimTags::<T>::remove(namespace, claim);
+			if let Some(alias) = ClaimAliases::<T>::take(namespace, claim) {
+				Aliases::<T>::remove(namespace, alias);
+			}
+			Approvals::<T>::remove(namespace, claim);
+			FrozenClaims::<T>::remove(namespace, claim);
+			Self::record_history(namespace, claim, HistoryAction::Revoked);
+			Self::accumulate_root(namespace, claim, HistoryAction::Revoked);
+			T::OnClaimChanged::on_revoked(namespace, owner, claim);
+		}
+
+		/// Append `action` to the history of `claim` in `namespace`, dropping the oldest entry if
+		/// the history is full. Does nothing unless [`Config::TrackHistory`] is set.
+		fn record_history(namespace: NamespaceId, claim: &T::ClaimId, action: HistoryAction) {
+			if !T::TrackHistory::get() {
+				return;
+			}
+			let now = T::BlockNumberProvider::current_block_number();
+			ClaimHistory::<T>::mutate(namespace, claim, |history| {
+				if history.is_full() && !history.is_empty() {
+					history.remove(0);
+				}
+				// Only fails if `MaxHistoryLen` is zero, in which case nothing is kept.
+				let _ = history.try_push((action, now));
+			});
+		}
+
+		/// Fold the creation or removal of `claim` in `namespace` into the [`ClaimsRoot`].
+		fn accumulate_root(namespace: NamespaceId, claim: &T::ClaimId, action: HistoryAction) {
+			ClaimsRoot::<T>::mutate(|root| {
+				*root = T::Hashing::hash_of(&(*root, action, namespace, claim));
+			});
+		}
+
+		/// The weight of creating a claim: [`WeightInfo::create_claim_allowlisted`] while
+		/// [`Config::EnforceAllowlist`] is set, and [`WeightInfo::create_claim`] otherwise.
+		fn create_claim_weight() -> Weight {
+			if T::EnforceAllowlist::get() {
+				T::WeightInfo::create_claim_allowlisted()
+			} else {
+				T::WeightInfo::create_claim()
+			}
+		}
+
+		/// The weight of recording `n` changes with [`Pallet::record_history`].
+		fn history_weight(n: u32) -> Weight {
+			if T::TrackHistory::get() {
+				T::DbWeight::get().reads_writes(n.into(), n.into())
+			} else {
+				Weight::zero()
+			}
+		}
+
+		/// Decode the key hashed with [`Blake2_128Concat`] at the start of `input`, and advance
+		/// `input` past it.
+		fn decode_blake2_128_concat<K: Encode + Decode>(input: &mut &[u8]) -> Option<K> {
+			let mut rest = input.get(16..)?;
+			let key = K::decode(&mut rest).ok()?;
+			let hashed = &input[..input.len() - rest.len()];
+			if Blake2_128Concat::hash(&key.encode()) != hashed {
+				return None;
+			}
+			*input = rest;
+			Some(key)
+		}
+
+		/// The block at which a claim made or renewed now expires, `lifetime` blocks from now.
+		///
+		/// Fails rather than wrapping around or saturating when the block number would overflow.
+		fn expiry_after(lifetime: BlockNumberFor<T>) -> Result<BlockNumberFor<T>, Error<T>> {
+			T::BlockNumberProvider::current_block_number()
+				.checked_add(&lifetime)
+				.ok_or(Error::<T>::ExpiryOverflow)
+		}
+
+		/// Release `amount` of the claim deposits held from `who` for `claim` in `namespace`.
+		///
+		/// If `amount` would leave the free balance of `who` below the existential deposit, it
+		/// could not be credited and would be lost, so it goes to [`Config::DustDestination`]
+		/// instead.
+		fn release_deposit(
+			namespace: NamespaceId,
+			claim: &T::ClaimId,
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+		) {
+			let reason = HoldReason::ClaimDeposit.into();
+			if !amount.is_zero() &&
+				T::Fungible::balance(who).saturating_add(amount) < T::Fungible::minimum_balance()
+			{
+				let (credit, missing) =
+					<T::Fungible as BalancedHold<_>>::slash(&reason, who, amount);
+				debug_assert!(missing.is_zero());
+				let amount = credit.peek();
+				T::DustDestination::on_unbalanced(credit);
+				Self::deposit_event(Event::ClaimDepositDusted {
+					namespace,
+					claim: claim.clone(),
+					amount,
+				});
+				return;
+			}
+			let released = T::Fungible::release(&reason, who, amount, Precision::BestEffort);
+			debug_assert!(released == Ok(amount));
+		}
+
+		/// Slash `amount` of the claim deposits held from `who` to [`Config::SlashDestination`],
+		/// returning the amount actually slashed.
+		fn slash_deposit(who: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+			let (credit, missing) = <T::Fungible as BalancedHold<_>>::slash(
+				&HoldReason::ClaimDeposit.into(),
+				who,
+				amount,
+			);
+			debug_assert!(missing.is_zero());
+			let slashed = credit.peek();
+			T::SlashDestination::on_unbalanced(credit);
+			slashed
+		}
+
+		/// Remove the claims that expired at or before `now`, oldest first, visiting at most `max`
+		/// scheduled claims with at most `limit` weight. Returns the weight used and the number of
+		/// claims removed.
+		///
+		/// The sweep resumes from [`CleanupCursor`], so the claims that do not fit are removed in
+		/// the next blocks.
+		pub(crate) fn remove_expired_claims(
+			now: BlockNumberFor<T>,
+			limit: Weight,
+			max: u32,
+		) -> (Weight, u32) {
+			let mut used = T::WeightInfo::on_initialize_expire(0);
+			if used.any_gt(limit) {
+				return (Weight::zero(), 0);
+			}
+			let bucket_weight = Self::expiry_bucket_weight();
+			let claim_weight = Self::expired_claim_weight();
+
+			let (mut visited, mut removed) = (0u32, 0u32);
+			let mut cursor = CleanupCursor::<T>::get().unwrap_or(now);
+			while cursor <= now && !used.saturating_add(bucket_weight).any_gt(limit) {
+				used.saturating_accrue(bucket_weight);
+				let mut claims = ExpiringAt::<T>::get(cursor).into_inner();
+				let mut done = 0;
+				for (namespace, claim) in claims.iter() {
+					if visited == max || used.saturating_add(claim_weight).any_gt(limit) {
+						break;
+					}
+					used.saturating_accrue(claim_weight);
+					visited += 1;
+					done += 1;
+					// The claim may have been revoked, or revoked and claimed again, since it was
+					// scheduled. Only remove it if it is really due.
+					let Some(info) = Claims::<T>::get(namespace, claim) else { continue };
+					if info.expires_at != Some(cursor) {
+						continue;
+					}
+					Self::expire_claim(*namespace, claim, &info);
+					removed += 1;
+				}
+				if done < claims.len() {
+					// Keep the rest of the bucket for the next block.
+					ExpiringAt::<T>::insert(
+						cursor,
+						BoundedVec::truncate_from(claims.split_off(done)),
+					);
+					break;
+				}
+				ExpiringAt::<T>::remove(cursor);
+				cursor.saturating_inc();
+			}
+			CleanupCursor::<T>::put(cursor);
+			(used, removed)
+		}
+
+		/// Remove the expired `claim` in `namespace`, described by `info`, and release its deposit
+		/// to its owner.
+		fn expire_claim(namespace: NamespaceId, claim: &T::ClaimId, info: &ClaimInfoOf<T>) {
+			Self::remove_claim(namespace, claim, &info.owner, info.deposit);
+			log::debug!(target: LOG_TARGET, "expired claim {claim:?} in namespace {namespace} of {:?}", info.owner);
+			Self::deposit_event(Event::ClaimExpired {
+				namespace,
+				claim: claim.clone(),
+				id: info.id,
+			});
+		}
+
+		/// Remove the claim of `claim` in `namespace` if it has expired, or if it counts as made
+		/// after `committed_at`, for a commitment made then to be revealed. Returns whether a
+		/// claim was removed.
+		fn make_way_for_reveal(
+			namespace: NamespaceId,
+			claim: &T::ClaimId,
+			committed_at: BlockNumberFor<T>,
+		) -> bool {
+			let Some(info) = Claims::<T>::get(namespace, claim) else { return false };
+			if info.has_expired(T::BlockNumberProvider::current_block_number()) {
+				Self::take_over_expired(namespace, claim, &info);
+				return true;
+			}
+			let made_at = ClaimCommittedAt::<T>::get(namespace, claim).unwrap_or(info.created_at);
+			if made_at <= committed_at {
+				return false;
+			}
+			if let Some(at) = info.expires_at {
+				Self::unschedule_expiry(at, namespace, claim);
+			}
+			Self::remove_claim(namespace, claim, &info.owner, info.deposit);
+			log::debug!(target: LOG_TARGET, "superseded claim {claim:?} in namespace {namespace} of {:?}", info.owner);
+			Self::deposit_event(Event::ClaimSuperseded {
+				namespace,
+				claim: claim.clone(),
+				by_owner: info.owner,
+				id: info.id,
+			});
+			true
+		}
+
+		/// Remove the expired `claim` in `namespace`, described by `info`, that the sweep has not
+		/// reached yet, so that the hash can be claimed again.
+		fn take_over_expired(namespace: NamespaceId, claim: &T::ClaimId, info: &ClaimInfoOf<T>) {
+			if let Some(at) = info.expires_at {
+				Self::unschedule_expiry(at, namespace, claim);
+			}
+			Self::expire_claim(namespace, claim, info);
+		}
+
+		/// The weight of visiting one block of [`ExpiringAt`] in the expiry sweep, measured by
+		/// [`WeightInfo::on_initialize_expire`].
+		fn expiry_bucket_weight() -> Weight {
+			T::WeightInfo::on_initialize_expire(1)
+				.saturating_sub(T::WeightInfo::on_initialize_expire(0))
+		}
+
+		/// The weight of removing one expired claim in the expiry sweep, measured by
+		/// [`WeightInfo::expire_claims`].
+		pub(crate) fn expired_claim_weight() -> Weight {
+			T::WeightInfo::expire_claims(1)
+				.saturating_sub(T::WeightInfo::expire_claims(0))
+				.saturating_add(Self::history_weight(1))
+		}
+
+		/// The most weight [`Pallet::purge_expired`] can use when asked to visit `max` claims.
+		///
+		/// Every claim may be alone in its block of [`ExpiringAt`], so a block is charged for each
+		/// of them.
+		pub(crate) fn purge_expired_weight(max: u32) -> Weight {
+			let max = max.min(T::MaxBatchSize::get());
+			T::WeightInfo::on_initialize_expire(0).saturating_add(
+				Self::expiry_bucket_weight()
+					.saturating_add(Self::expired_claim_weight())
+					.saturating_mul(max.into()),
+			)
+		}
+
+		/// Remove `claim` in `namespace` from the claims scheduled to expire at `block`, if it is
+		/// there.
+		fn unschedule_expiry(block: BlockNumberFor<T>, namespace: NamespaceId, claim: &T::ClaimId) {
+			ExpiringAt::<T>::mutate_exists(block, |maybe_claims| {
+				let Some(claims) = maybe_claims else { return };
+				if let Some(index) =
+					claims.iter().position(|(ns, c)| *ns == namespace && c == claim)
+				{
+					claims.swap_remove(index);
+				}
+				if claims.is_empty() {
+					*maybe_claims = None;
+				}
+			});
+		}
+
+		/// Remove `claim` in `namespace` from the claims owned by `owner`.
+		fn unindex_claim(owner: &T::AccountId, namespace: NamespaceId, claim: &T::ClaimId) {
+			if ClaimsByOwner::<T>::take(owner, (namespace, claim)).is_some() {
+				ClaimCountByOwner::<T>::mutate_exists(owner, |count| {
+					*count = count.and_then(|count| count.checked_sub(1)).filter(|n| *n > 0);
+				});
+			}
+		}
+
+		/// Remove `claim` in `namespace` from the claims created in `block`, if it is there.
+		fn unindex_block(block: BlockNumberFor<T>, namespace: NamespaceId, claim: &T::ClaimId) {
+			ClaimsByBlock::<T>::mutate_exists(block, |maybe_claims| {
+				let Some(claims) = maybe_claims else { return };
+				if let Some(index) =
+					claims.iter().position(|(ns, c)| *ns == namespace && c == claim)
+				{
+					claims.swap_remove(index);
+				}
+				if claims.is_empty() {
+					*maybe_claims = None;
+				}
+			});
+		}
+
+		/// Check the pallet's storage invariants at block `now` of [`Config::BlockNumberProvider`]:
+		///
+		/// - no claim was made after `now`;
+		/// - every claim has its own id, below [`NextClaimId`];
+		/// - every claim with an expiry is scheduled in [`ExpiringAt`], at or after
+		///   [`CleanupCursor`];
+		/// - the [`ClaimsByOwner`] index agrees exactly with [`Claims`], and [`ClaimCountByOwner`]
+		///   with the index;
+		/// - every entry of the [`ClaimsByBlock`] index is a claim created in that block;
+		/// - every owner has at least the deposits of their claims on hold;
+		/// - [`ClaimCount`] is the number of claims;
+		/// - every pending claim is scheduled to be dropped after the current block of this chain,
+		///   and is not also claimed;
+		/// - [`Aliases`] and [`ClaimAliases`] agree, and only name existing claims.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub(crate) fn do_try_state(
+			now: BlockNumberFor<T>,
+		) -> Result<(), sp_runtime::TryRuntimeError> {
+			use frame_support::traits::fungible::InspectHold;
+
+			let mut ids = alloc::collections::BTreeSet::new();
+			for (namespace, claim, info) in Claims::<T>::iter() {
+				ensure!(info.created_at <= now, "a claim was made in a future block");
+				ensure!(
+					info.id < NextClaimId::<T>::get(),
+					"a claim has an id that was never assigned"
+				);
+				ensure!(ids.insert(info.id), "two claims have the same id");
+				if let Some(expires_at) = info.expires_at {
+					ensure!(
+						ExpiringAt::<T>::get(expires_at).contains(&(namespace, claim)),
+						"a claim is not scheduled to expire when it should"
+					);
+				}
+			}
+
+			if let Some(cursor) = CleanupCursor::<T>::get() {
+				ensure!(
+					ExpiringAt::<T>::iter_keys().all(|block| block >= cursor),
+					"some claims are scheduled before the cleanup cursor"
+				);
+			}
+
+			// The number of claims indexed under each owner, and the sum of their deposits.
+			let mut owners = alloc::collections::BTreeMap::new();
+			for (owner, (namespace, claim)) in ClaimsByOwner::<T>::iter_keys() {
+				let info =
+					Claims::<T>::get(namespace, &claim).ok_or("an indexed claim does not exist")?;
+				ensure!(info.owner == owner, "a claim is indexed under the wrong owner");
+				let (count, deposits) =
+					owners.entry(owner).or_insert((0u32, BalanceOf::<T>::zero()));
+				count.saturating_inc();
+				deposits.saturating_accrue(info.deposit);
+			}
+			let mut indexed = 0usize;
+			for (owner, (count, deposits)) in owners.iter() {
+				ensure!(
+					ClaimCountByOwner::<T>::get(owner) == *count,
+					"ClaimCountByOwner is out of sync with the owner index"
+				);
+				ensure!(
+					T::Fungible::balance_on_hold(&HoldReason::ClaimDeposit.into(), owner) >=
+						*deposits,
+					"an owner has less on hold than the deposits of their claims"
+				);
+				indexed += *count as usize;
+			}
+			let claims = Claims::<T>::iter_keys().count();
+			ensure!(indexed == claims, "some claims are not indexed");
+			ensure!(
+				ClaimCountByOwner::<T>::iter_keys().all(|owner| owners.contains_key(&owner)),
+				"an account with no claims still has a claim count"
+			);
+			ensure!(ClaimCount::<T>::get() as usize == claims, "ClaimCount is out of sync");
+
+			for (block, claims) in ClaimsByBlock::<T>::iter() {
+				ensure!(!claims.is_empty(), "a block with no claims is still indexed");
+				for (namespace, claim) in claims {
+					let info = Claims::<T>::get(namespace, &claim)
+						.ok_or("a claim indexed by block does not exist")?;
+					ensure!(info.created_at == block, "a claim is indexed under the wrong block");
+				}
+			}
+
+			for (namespace, claim, pending) in PendingClaims::<T>::iter() {
+				ensure!(
+					pending.expires_at > frame_system::Pallet::<T>::block_number(),
+					"a pending claim outlived its timeout"
+				);
+				ensure!(
+					PendingExpiringAt::<T>::get(pending.expires_at)
+						.contains(&(namespace, claim.clone())),
+					"a pending claim is not scheduled to be dropped when it should"
+				);
+				ensure!(
+					!Claims::<T>::contains_key(namespace, &claim),
+					"a hash is both claimed and pending"
+				);
+			}
+
+			for (namespace, claim, approved) in Approvals::<T>::iter() {
+				let info =
+					Claims::<T>::get(namespace, &claim).ok_or("an approval outlived its claim")?;
+				ensure!(info.owner != approved, "a claim is approved for its own owner");
+			}
+
+			for (namespace, alias, claim) in Aliases::<T>::iter() {
+				ensure!(
+					Claims::<T>::contains_key(namespace, &claim),
+					"an alias outlived its claim"
+				);
+				ensure!(
+					ClaimAliases::<T>::get(namespace, &claim).as_ref() == Some(&alias),
+					"an alias is not the alias of the claim it names"
+				);
+			}
+			ensure!(
+				Aliases::<T>::iter_keys().count() == ClaimAliases::<T>::iter_keys().count(),
+				"a claim has an alias that names no claim"
+			);
+			for (namespace, claim) in FrozenClaims::<T>::iter_keys() {
+				ensure!(
+					Claims::<T>::contains_key(namespace, &claim),
+					"a frozen claim does not exist"
+				);
+			}
+			for (namespace, claim) in ClaimCids::<T>::iter_keys() {
+				ensure!(
+					Claims::<T>::contains_key(namespace, &claim),
+					"an IPFS identifier outlived its claim"
+				);
+			}
+			for (namespace, claim) in ClaimNotes::<T>::iter_keys() {
+				ensure!(Claims::<T>::contains_key(namespace, &claim), "a note outlived its claim");
+			}
+			for (namespace, claim) in ClaimCommittedAt::<T>::iter_keys() {
+				ensure!(
+					Claims::<T>::contains_key(namespace, &claim),
+					"a commitment block outlived its claim"
+				);
+			}
+			for (namespace, claim) in RevokedClaims::<T>::iter_keys() {
+				ensure!(!Claims::<T>::contains_key(namespace, &claim), "a tombstoned claim exists");
+			}
+			Ok(())
+		}
 	}
 }
@@ -0,0 +1,1149 @@
+//! Storage migrations for the template pallet.
+//!
+//! Every change to the layout of the pallet's storage bumps [`crate::STORAGE_VERSION`] and adds a
+//! module here with a migration from the previous version. The migrations are wrapped in
+//! [`VersionedMigration`](frame_support::migrations::VersionedMigration), so they only run when
+//! the on-chain storage version matches, and must be added to the `Migrations` of the runtime.
+//!
+//! Claims were keyed by the runtime's `Hash` before [`Config::ClaimId`](crate::Config::ClaimId)
+//! existed, so a runtime that sets `ClaimId = Hash` keeps its storage as it is. The migrations up
+//! to version 8 decode that old layout and are only available to such runtimes. A runtime that
+//! switches to another `ClaimId` must bring its own migration of every claim key.
+
+/// Migrate the pallet storage from version 0 to version 1.
+///
+/// Version 0 stored each claim as an `(owner, block number)` tuple. Version 1 also records the
+/// deposit reserved for the claim. Nothing was reserved for claims made before deposits existed,
+/// so they are migrated with a deposit of zero.
+pub mod v1 {
+	use crate::{BalanceOf, Config, Pallet};
+	use frame_support::{
+		migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use sp_runtime::traits::{Saturating, Zero};
+
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+
+	/// The storage items of version 0, as they were declared then.
+	pub(crate) mod v0 {
+		use super::*;
+
+		#[frame_support::storage_alias]
+		pub(crate) type Claims<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as frame_system::Config>::Hash,
+			(<T as frame_system::Config>::AccountId, BlockNumberFor<T>),
+		>;
+	}
+
+	/// The claims as stored in version 1, as `(owner, block number, deposit)` tuples.
+	#[frame_support::storage_alias]
+	pub(crate) type Claims<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::Hash,
+		(<T as frame_system::Config>::AccountId, BlockNumberFor<T>, BalanceOf<T>),
+	>;
+
+	/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the state of this pallet from V0 to V1.
+	///
+	/// Use [`MigrateV0ToV1`] instead, which only runs when the on-chain version is 0.
+	pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			Claims::<T>::translate::<(T::AccountId, BlockNumberFor<T>), _>(
+				|_claim, (owner, created_at)| {
+					translated.saturating_inc();
+					Some((owner, created_at, BalanceOf::<T>::zero()))
+				},
+			);
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let count = v0::Claims::<T>::iter().count() as u32;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let before = u32::decode(&mut &state[..])
+				.map_err(|_| "the state from `pre_upgrade` could not be decoded")?;
+			// Iterating decodes every value, so this also proves none were left in the old layout.
+			let after = Claims::<T>::iter().count() as u32;
+			ensure!(before == after, "some claims were lost in the migration");
+			Ok(())
+		}
+	}
+
+	/// Migrate the pallet storage from version 0 to version 1, if the on-chain version is 0.
+	pub type MigrateV0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		InnerMigrateV0ToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migrate the pallet storage from version 1 to version 2.
+///
+/// Version 2 stores each claim as a [`ClaimInfo`](v2::ClaimInfo) struct, which also records the
+/// block at which the claim expires. A claim is assumed to expire `ClaimLifetime` blocks after it
+/// was made if it is scheduled in that block of `ExpiringAt`. Otherwise, for instance if it was
+/// registered at genesis or made while expiry was disabled, it is migrated without an expiry.
+pub mod v2 {
+	use super::v4::ExpiringAt;
+	use crate::{BalanceOf, Config, Pallet};
+	use frame_support::{
+		migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use sp_runtime::traits::{Saturating, Zero};
+
+	#[cfg(feature = "try-runtime")]
+	use {super::v1, alloc::vec::Vec};
+
+	/// A claim as stored in versions 2 and 3.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+	pub struct ClaimInfo<AccountId, BlockNumber, Balance> {
+		/// The account that owns the claim.
+		pub owner: AccountId,
+		/// The block number at which the claim was originally made.
+		pub created_at: BlockNumber,
+		/// The deposit reserved from the owner.
+		pub deposit: Balance,
+		/// The block number at which the claim is scheduled to expire, if any.
+		pub expires_at: Option<BlockNumber>,
+	}
+
+	/// The claims as stored in versions 2 and 3.
+	#[frame_support::storage_alias]
+	pub(crate) type Claims<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::Hash,
+		ClaimInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+	>;
+
+	/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the state of this pallet from V1 to V2.
+	///
+	/// Use [`MigrateV1ToV2`] instead, which only runs when the on-chain version is 1.
+	pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let lifetime = T::ClaimLifetime::get();
+			let mut translated = 0u64;
+			Claims::<T>::translate::<(T::AccountId, BlockNumberFor<T>, BalanceOf<T>), _>(
+				|claim, (owner, created_at, deposit)| {
+					translated.saturating_inc();
+					let expires_at = Some(created_at.saturating_add(lifetime)).filter(|block| {
+						!lifetime.is_zero() && ExpiringAt::<T>::get(block).contains(&claim)
+					});
+					Some(ClaimInfo { owner, created_at, deposit, expires_at })
+				},
+			);
+			T::DbWeight::get().reads_writes(translated.saturating_mul(2), translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let count = v1::Claims::<T>::iter().count() as u32;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let before = u32::decode(&mut &state[..])
+				.map_err(|_| "the state from `pre_upgrade` could not be decoded")?;
+			// Iterating decodes every value, so this also proves none were left in the old layout.
+			let after = Claims::<T>::iter().count() as u32;
+			ensure!(before == after, "some claims were lost in the migration");
+			Ok(())
+		}
+	}
+
+	/// Migrate the pallet storage from version 1 to version 2, if the on-chain version is 1.
+	pub type MigrateV1ToV2<T> = VersionedMigration<
+		1,
+		2,
+		InnerMigrateV1ToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migrate the pallet storage from version 2 to version 3.
+///
+/// Version 3 keeps track of the number of claims in `ClaimCount`, which is initialised by counting
+/// the existing claims.
+pub mod v3 {
+	use super::v2::Claims;
+	use crate::{ClaimCount, Config, Pallet};
+	use frame_support::{
+		migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+	};
+
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+
+	/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the state of this pallet from V2 to V3.
+	///
+	/// Use [`MigrateV2ToV3`] instead, which only runs when the on-chain version is 2.
+	pub struct InnerMigrateV2ToV3<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let count = Claims::<T>::iter_keys().count() as u32;
+			ClaimCount::<T>::put(count);
+			T::DbWeight::get().reads_writes(count.into(), 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(
+				ClaimCount::<T>::get() as usize == Claims::<T>::iter_keys().count(),
+				"ClaimCount does not match the number of claims"
+			);
+			Ok(())
+		}
+	}
+
+	/// Migrate the pallet storage from version 2 to version 3, if the on-chain version is 2.
+	pub type MigrateV2ToV3<T> = VersionedMigration<
+		2,
+		3,
+		InnerMigrateV2ToV3<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migrate the pallet storage from version 3 to version 4.
+///
+/// Version 4 records the [`ClaimKind`] of each claim. The kind of the existing claims is unknown,
+/// so they are migrated as `ClaimKind::Other(0)`.
+pub mod v4 {
+	use super::v2;
+	use crate::{BalanceOf, ClaimKind, ClaimMetadataOf, Config, Pallet};
+	use frame_support::{
+		migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use sp_runtime::traits::Saturating;
+
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+
+	/// A claim as stored in versions 4 to 7.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+	pub struct ClaimInfo<AccountId, BlockNumber, Balance> {
+		/// The account that owns the claim.
+		pub owner: AccountId,
+		/// The block number at which the claim was originally made.
+		pub created_at: BlockNumber,
+		/// The deposit held from the owner.
+		pub deposit: Balance,
+		/// The block number at which the claim is scheduled to expire, if any.
+		pub expires_at: Option<BlockNumber>,
+		/// The kind of artifact whose hash is claimed.
+		pub kind: ClaimKind,
+	}
+
+	/// The [`ClaimInfo`] type used by a runtime.
+	pub type ClaimInfoOf<T> =
+		ClaimInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, BalanceOf<T>>;
+
+	/// The claims as stored in version 4, keyed by their hash alone.
+	#[frame_support::storage_alias]
+	pub(crate) type Claims<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, <T as frame_system::Config>::Hash, ClaimInfoOf<T>>;
+
+	/// The claims owned by each account, as stored up to version 4.
+	#[frame_support::storage_alias]
+	pub(crate) type ClaimsByOwner<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<<T as frame_system::Config>::Hash, <T as Config>::MaxClaimsPerAccount>,
+		ValueQuery,
+	>;
+
+	/// The metadata of the claims, as stored up to version 4.
+	#[frame_support::storage_alias]
+	pub(crate) type ClaimMetadata<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::Hash,
+		ClaimMetadataOf<T>,
+	>;
+
+	/// The claims scheduled to expire in each block, as stored up to version 4.
+	#[frame_support::storage_alias]
+	pub(crate) type ExpiringAt<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<<T as frame_system::Config>::Hash, <T as Config>::MaxExpiringPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The pending transfer approvals, as stored up to version 4.
+	#[frame_support::storage_alias]
+	pub(crate) type Approvals<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::Hash,
+		<T as frame_system::Config>::AccountId,
+	>;
+
+	/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the state of this pallet from V3 to V4.
+	///
+	/// Use [`MigrateV3ToV4`] instead, which only runs when the on-chain version is 3.
+	pub struct InnerMigrateV3ToV4<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV3ToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			Claims::<T>::translate::<v2::ClaimInfo<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>, _>(
+				|_claim, old| {
+					translated.saturating_inc();
+					Some(ClaimInfo {
+						owner: old.owner,
+						created_at: old.created_at,
+						deposit: old.deposit,
+						expires_at: old.expires_at,
+						kind: ClaimKind::Other(0),
+					})
+				},
+			);
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let count = v2::Claims::<T>::iter().count() as u32;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let before = u32::decode(&mut &state[..])
+				.map_err(|_| "the state from `pre_upgrade` could not be decoded")?;
+			// Iterating decodes every value, so this also proves none were left in the old layout.
+			let after = Claims::<T>::iter().count() as u32;
+			ensure!(before == after, "some claims were lost in the migration");
+			Ok(())
+		}
+	}
+
+	/// Migrate the pallet storage from version 3 to version 4, if the on-chain version is 3.
+	pub type MigrateV3ToV4<T> = VersionedMigration<
+		3,
+		4,
+		InnerMigrateV3ToV4<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migrate the pallet storage from version 4 to version 5.
+///
+/// Version 5 keys every claim by a [`NamespaceId`] as well as its hash. All the existing claims,
+/// together with their metadata, approvals and entries in the owner and expiry indices, are moved
+/// to [`DEFAULT_NAMESPACE`].
+pub mod v5 {
+	use super::v4;
+	use crate::{
+		Approvals, ClaimMetadata, Config, ExpiringAt, NamespaceId, Pallet, DEFAULT_NAMESPACE,
+	};
+	use alloc::vec::Vec;
+	use frame_support::{
+		migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+	};
+	use sp_runtime::traits::Saturating;
+
+	/// The claims as stored in versions 5 to 7.
+	#[frame_support::storage_alias]
+	pub(crate) type Claims<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		<T as frame_system::Config>::Hash,
+		v4::ClaimInfoOf<T>,
+	>;
+
+	/// The claims owned by each account, as stored in versions 5 to 11.
+	#[frame_support::storage_alias]
+	pub(crate) type ClaimsByOwner<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<(NamespaceId, <T as Config>::ClaimId), <T as Config>::MaxClaimsPerAccount>,
+		ValueQuery,
+	>;
+
+	/// Put every hash of `claims` in [`DEFAULT_NAMESPACE`].
+	fn namespaced<H, S: Get<u32>>(claims: BoundedVec<H, S>) -> BoundedVec<(NamespaceId, H), S> {
+		// Both vectors have the same bound, so nothing is truncated.
+		BoundedVec::truncate_from(
+			claims.into_iter().map(|claim| (DEFAULT_NAMESPACE, claim)).collect(),
+		)
+	}
+
+	/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the state of this pallet from V4 to V5.
+	///
+	/// Use [`MigrateV4ToV5`] instead, which only runs when the on-chain version is 4.
+	pub struct InnerMigrateV4ToV5<T>(core::marker::PhantomData<T>);
+
+	impl<T> UncheckedOnRuntimeUpgrade for InnerMigrateV4ToV5<T>
+	where
+		T: Config<ClaimId = <T as frame_system::Config>::Hash>,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let mut moved = 0u64;
+			// The old and new maps share their storage prefix, so each old map is drained
+			// completely before anything is written in the new layout.
+			let claims: Vec<_> = v4::Claims::<T>::drain().collect();
+			for (claim, info) in claims {
+				moved.saturating_inc();
+				Claims::<T>::insert(DEFAULT_NAMESPACE, claim, info);
+			}
+			let metadata: Vec<_> = v4::ClaimMetadata::<T>::drain().collect();
+			for (claim, metadata) in metadata {
+				moved.saturating_inc();
+				ClaimMetadata::<T>::insert(DEFAULT_NAMESPACE, claim, metadata);
+			}
+			let approvals: Vec<_> = v4::Approvals::<T>::drain().collect();
+			for (claim, approved) in approvals {
+				moved.saturating_inc();
+				Approvals::<T>::insert(DEFAULT_NAMESPACE, claim, approved);
+			}
+
+			ClaimsByOwner::<T>::translate_values::<BoundedVec<T::Hash, T::MaxClaimsPerAccount>, _>(
+				|claims| {
+					moved.saturating_inc();
+					Some(namespaced(claims))
+				},
+			);
+			ExpiringAt::<T>::translate_values::<BoundedVec<T::Hash, T::MaxExpiringPerBlock>, _>(
+				|claims| {
+					moved.saturating_inc();
+					Some(namespaced(claims))
+				},
+			);
+
+			// Each moved entry is read once, and written once in the new layout. Drained entries
+			// are written once more to remove them.
+			T::DbWeight::get().reads_writes(moved, moved.saturating_mul(2))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let count = v4::Claims::<T>::iter().count() as u32;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let before = u32::decode(&mut &state[..])
+				.map_err(|_| "the state from `pre_upgrade` could not be decoded")?;
+			// Iterating decodes every key and value, so this also proves none were left in the old
+			// layout.
+			let after = Claims::<T>::iter().count() as u32;
+			ensure!(before == after, "some claims were lost in the migration");
+			let namespaced = Claims::<T>::iter_prefix(DEFAULT_NAMESPACE).count() as u32;
+			ensure!(namespaced == after, "some claims were not moved to the default namespace");
+			Ok(())
+		}
+	}
+
+	/// Migrate the pallet storage from version 4 to version 5, if the on-chain version is 4.
+	pub type MigrateV4ToV5<T> = VersionedMigration<
+		4,
+		5,
+		InnerMigrateV4ToV5<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migrate the pallet state from version 5 to version 6.
+///
+/// Version 6 holds claim deposits with the `fungible` traits, under
+/// [`HoldReason::ClaimDeposit`], instead of reserving them with `ReservableCurrency`. The layout
+/// of the storage does not change, but the deposit of every claim is unreserved with the
+/// `OldCurrency` the pallet used before and put on hold again.
+pub mod v6 {
+	use super::v5::Claims;
+	use crate::{BalanceOf, Config, HoldReason, Pallet};
+	use alloc::vec::Vec;
+	use frame_support::{
+		migrations::VersionedMigration,
+		pallet_prelude::*,
+		traits::{fungible::MutateHold, ReservableCurrency, UncheckedOnRuntimeUpgrade},
+	};
+	use sp_runtime::traits::{Saturating, Zero};
+
+	/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the state of this pallet from V5 to V6.
+	///
+	/// Use [`MigrateV5ToV6`] instead, which only runs when the on-chain version is 5.
+	pub struct InnerMigrateV5ToV6<T, OldCurrency>(core::marker::PhantomData<(T, OldCurrency)>);
+
+	impl<T, OldCurrency> UncheckedOnRuntimeUpgrade for InnerMigrateV5ToV6<T, OldCurrency>
+	where
+		T: Config<ClaimId = <T as frame_system::Config>::Hash>,
+		OldCurrency: ReservableCurrency<T::AccountId, Balance = BalanceOf<T>>,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let reason = HoldReason::ClaimDeposit.into();
+			let mut read = 0u64;
+			let mut moved = 0u64;
+			// Collect the claims first, as some of them are rewritten below.
+			let claims: Vec<_> = Claims::<T>::iter().collect();
+			for (namespace, claim, mut info) in claims {
+				read.saturating_inc();
+				if info.deposit.is_zero() {
+					continue;
+				}
+				moved.saturating_inc();
+				let missing = OldCurrency::unreserve(&info.owner, info.deposit);
+				let mut held = info.deposit.saturating_sub(missing);
+				if T::Fungible::hold(&reason, &info.owner, held).is_err() {
+					held = Zero::zero();
+				}
+				// Only record what is actually held, so releasing the deposit later cannot fail.
+				if held != info.deposit {
+					info.deposit = held;
+					Claims::<T>::insert(namespace, claim, info);
+				}
+			}
+
+			// Each claim is read once. Moving a deposit reads and writes the owner's account to
+			// unreserve it, and again, together with the owner's holds, to hold it.
+			T::DbWeight::get()
+				.reads_writes(read.saturating_add(moved.saturating_mul(3)), moved.saturating_mul(3))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let deposits = Claims::<T>::iter_values()
+				.fold(BalanceOf::<T>::zero(), |total, info| total.saturating_add(info.deposit));
+			Ok(deposits.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			use frame_support::traits::fungible::InspectHold;
+
+			let before = BalanceOf::<T>::decode(&mut &state[..])
+				.map_err(|_| "the state from `pre_upgrade` could not be decoded")?;
+			let mut after = BalanceOf::<T>::zero();
+			for info in Claims::<T>::iter_values() {
+				after.saturating_accrue(info.deposit);
+			}
+			ensure!(before == after, "some deposits could not be moved to a hold");
+			for (owner, claims) in super::v5::ClaimsByOwner::<T>::iter() {
+				let mut deposits = BalanceOf::<T>::zero();
+				for (namespace, claim) in claims {
+					let info = Claims::<T>::get(namespace, claim).ok_or("a claim is missing")?;
+					deposits.saturating_accrue(info.deposit);
+				}
+				ensure!(
+					T::Fungible::balance_on_hold(&HoldReason::ClaimDeposit.into(), &owner) >=
+						deposits,
+					"a deposit was not put on hold"
+				);
+			}
+			Ok(())
+		}
+	}
+
+	/// Migrate the pallet state from version 5 to version 6, if the on-chain version is 5.
+	///
+	/// `OldCurrency` must be the `ReservableCurrency` the pallet used before version 6.
+	pub type MigrateV5ToV6<T, OldCurrency> = VersionedMigration<
+		5,
+		6,
+		InnerMigrateV5ToV6<T, OldCurrency>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migrate the pallet storage from version 6 to version 7.
+///
+/// Version 7 indexes the claims by the block they were created in, in [`ClaimsByBlock`]. The
+/// index is built from the existing claims. Blocks used to have no limit on the number of claims
+/// created in them, so claims that do not fit in [`Config::MaxClaimsPerBlock`] are left out.
+pub mod v7 {
+	use super::v5::Claims;
+	use crate::{ClaimsByBlock, Config, Pallet};
+	use frame_support::{
+		migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+	};
+	use sp_runtime::traits::Saturating;
+
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+
+	/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the state of this pallet from V6 to V7.
+	///
+	/// Use [`MigrateV6ToV7`] instead, which only runs when the on-chain version is 6.
+	pub struct InnerMigrateV6ToV7<T>(core::marker::PhantomData<T>);
+
+	impl<T> UncheckedOnRuntimeUpgrade for InnerMigrateV6ToV7<T>
+	where
+		T: Config<ClaimId = <T as frame_system::Config>::Hash>,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let mut read = 0u64;
+			for (namespace, claim, info) in Claims::<T>::iter() {
+				read.saturating_inc();
+				// A full block keeps the claims indexed first.
+				let _ = ClaimsByBlock::<T>::try_append(info.created_at, (namespace, claim));
+			}
+			T::DbWeight::get().reads_writes(read.saturating_mul(2), read)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			for (block, claims) in ClaimsByBlock::<T>::iter() {
+				for (namespace, claim) in claims {
+					let info = Claims::<T>::get(namespace, claim).ok_or("a claim is missing")?;
+					ensure!(info.created_at == block, "a claim is indexed under the wrong block");
+				}
+			}
+			Ok(())
+		}
+	}
+
+	/// Migrate the pallet storage from version 6 to version 7, if the on-chain version is 6.
+	pub type MigrateV6ToV7<T> = VersionedMigration<
+		6,
+		7,
+		InnerMigrateV6ToV7<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migrate the pallet storage from version 7 to version 8.
+///
+/// Version 8 assigns every claim a sequence number, its [`ClaimInfo::id`]. The existing claims
+/// are numbered from zero in the order they were created, and [`NextClaimId`] is set past them.
+pub mod v8 {
+	use super::v5;
+	use crate::{BalanceOf, ClaimKind, Config, NamespaceId, NextClaimId, Pallet};
+	use alloc::vec::Vec;
+	use frame_support::{
+		migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// A claim as stored in version 8.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+	pub struct ClaimInfo<AccountId, BlockNumber, Balance> {
+		/// The sequence number assigned to the claim when it was created.
+		pub id: u64,
+		/// The account that owns the claim.
+		pub owner: AccountId,
+		/// The block number at which the claim was originally made.
+		pub created_at: BlockNumber,
+		/// The deposit held from the owner.
+		pub deposit: Balance,
+		/// The block number at which the claim is scheduled to expire, if any.
+		pub expires_at: Option<BlockNumber>,
+		/// The kind of artifact whose hash is claimed.
+		pub kind: ClaimKind,
+	}
+
+	/// The [`ClaimInfo`] type used by a runtime.
+	pub type ClaimInfoOf<T> =
+		ClaimInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, BalanceOf<T>>;
+
+	/// The claims as stored in version 8.
+	#[frame_support::storage_alias]
+	pub(crate) type Claims<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		<T as frame_system::Config>::Hash,
+		ClaimInfoOf<T>,
+	>;
+
+	/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the state of this pallet from V7 to V8.
+	///
+	/// Use [`MigrateV7ToV8`] instead, which only runs when the on-chain version is 7.
+	pub struct InnerMigrateV7ToV8<T>(core::marker::PhantomData<T>);
+
+	impl<T> UncheckedOnRuntimeUpgrade for InnerMigrateV7ToV8<T>
+	where
+		T: Config<ClaimId = <T as frame_system::Config>::Hash>,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			// The old and new claims share their storage, so they are all read before any of them
+			// is rewritten. The order of the map says nothing about when a claim was made, so the
+			// claims are numbered by creation block, with ties broken by the map order.
+			let mut claims: Vec<_> = v5::Claims::<T>::iter().collect();
+			claims.sort_by_key(|(_, _, old)| old.created_at);
+			let count = claims.len() as u64;
+			for (id, (namespace, claim, old)) in (0..).zip(claims) {
+				let info = ClaimInfo {
+					id,
+					owner: old.owner,
+					created_at: old.created_at,
+					deposit: old.deposit,
+					expires_at: old.expires_at,
+					kind: old.kind,
+				};
+				Claims::<T>::insert(namespace, claim, info);
+			}
+			NextClaimId::<T>::put(count);
+			T::DbWeight::get().reads_writes(count, count.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let count = v5::Claims::<T>::iter().count() as u64;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let before = u64::decode(&mut &state[..])
+				.map_err(|_| "the state from `pre_upgrade` could not be decoded")?;
+			// Iterating decodes every value, so this also proves none were left in the old layout.
+			let mut ids: Vec<_> = Claims::<T>::iter_values().map(|info| info.id).collect();
+			ids.sort();
+			ensure!(
+				ids == (0..before).collect::<Vec<_>>(),
+				"the claims were not numbered in sequence"
+			);
+			ensure!(NextClaimId::<T>::get() == before, "NextClaimId does not follow the claims");
+			Ok(())
+		}
+	}
+
+	/// Migrate the pallet storage from version 7 to version 8, if the on-chain version is 7.
+	pub type MigrateV7ToV8<T> = VersionedMigration<
+		7,
+		8,
+		InnerMigrateV7ToV8<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migrate the pallet storage from version 8 to version 9.
+///
+/// Version 9 records the hashing algorithm of every claim, its [`ClaimInfo::algo`]. The algorithm
+/// of the existing claims is not known, so they are marked as
+/// [`HashAlgo::Other(0)`](crate::HashAlgo::Other).
+///
+/// The keys of the claims are left as they are, so this migration works with any
+/// [`Config::ClaimId`](crate::Config::ClaimId).
+pub mod v9 {
+	use super::v8;
+	use crate::{BalanceOf, ClaimKind, Config, HashAlgo, NamespaceId, Pallet};
+	use frame_support::{
+		migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use sp_runtime::traits::Saturating;
+
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+
+	/// A claim as stored in versions 9 and 10.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+	pub struct ClaimInfo<AccountId, BlockNumber, Balance> {
+		/// The sequence number assigned to the claim when it was created.
+		pub id: u64,
+		/// The account that owns the claim.
+		pub owner: AccountId,
+		/// The block number at which the claim was originally made.
+		pub created_at: BlockNumber,
+		/// The deposit held from the owner.
+		pub deposit: Balance,
+		/// The block number at which the claim is scheduled to expire, if any.
+		pub expires_at: Option<BlockNumber>,
+		/// The kind of artifact whose hash is claimed.
+		pub kind: ClaimKind,
+		/// The hashing algorithm that produced the claimed hash.
+		pub algo: HashAlgo,
+	}
+
+	/// The [`ClaimInfo`] type used by a runtime.
+	pub type ClaimInfoOf<T> =
+		ClaimInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, BalanceOf<T>>;
+
+	/// The claims as stored in versions 9 and 10.
+	#[frame_support::storage_alias]
+	pub(crate) type Claims<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		<T as Config>::ClaimId,
+		ClaimInfoOf<T>,
+	>;
+
+	/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the state of this pallet from V8 to V9.
+	///
+	/// Use [`MigrateV8ToV9`] instead, which only runs when the on-chain version is 8.
+	pub struct InnerMigrateV8ToV9<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV8ToV9<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			Claims::<T>::translate_values::<v8::ClaimInfoOf<T>, _>(|old| {
+				translated.saturating_inc();
+				Some(ClaimInfo {
+					id: old.id,
+					owner: old.owner,
+					created_at: old.created_at,
+					deposit: old.deposit,
+					expires_at: old.expires_at,
+					kind: old.kind,
+					algo: HashAlgo::Other(0),
+				})
+			});
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let count = Claims::<T>::iter_keys().count() as u64;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let before = u64::decode(&mut &state[..])
+				.map_err(|_| "the state from `pre_upgrade` could not be decoded")?;
+			// Iterating decodes every value, so this also proves none were left in the old layout.
+			let after = Claims::<T>::iter_values().count() as u64;
+			ensure!(before == after, "some claims were lost in the migration");
+			Ok(())
+		}
+	}
+
+	/// Migrate the pallet storage from version 8 to version 9, if the on-chain version is 8.
+	pub type MigrateV8ToV9<T> = VersionedMigration<
+		8,
+		9,
+		InnerMigrateV8ToV9<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migrate the pallet storage from version 9 to version 10.
+///
+/// Version 10 counts the claims of every account in [`ClaimCountByOwner`]. The counts are taken
+/// from the owner index, so this migration works with any
+/// [`Config::ClaimId`](crate::Config::ClaimId).
+pub mod v10 {
+	use super::v5::ClaimsByOwner;
+	use crate::{ClaimCountByOwner, Config, Pallet};
+	use frame_support::{
+		migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+	};
+	use sp_runtime::traits::Saturating;
+
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+
+	/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the state of this pallet from V9 to
+	/// V10.
+	///
+	/// Use [`MigrateV9ToV10`] instead, which only runs when the on-chain version is 9.
+	pub struct InnerMigrateV9ToV10<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV9ToV10<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut owners = 0u64;
+			for (owner, claims) in ClaimsByOwner::<T>::iter() {
+				owners.saturating_inc();
+				ClaimCountByOwner::<T>::insert(owner, claims.len() as u32);
+			}
+			T::DbWeight::get().reads_writes(owners, owners)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			for (owner, claims) in ClaimsByOwner::<T>::iter() {
+				ensure!(
+					ClaimCountByOwner::<T>::get(owner) as usize == claims.len(),
+					"ClaimCountByOwner does not match the owner index"
+				);
+			}
+			Ok(())
+		}
+	}
+
+	/// Migrate the pallet storage from version 9 to version 10, if the on-chain version is 9.
+	pub type MigrateV9ToV10<T> = VersionedMigration<
+		9,
+		10,
+		InnerMigrateV9ToV10<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migrate the pallet storage from version 10 to version 11.
+///
+/// Version 11 lets a claim be locked against revocation and transfer, with its
+/// [`ClaimInfo::lock_until`]. None of the existing claims are locked.
+///
+/// The keys of the claims are left as they are, so this migration works with any
+/// [`Config::ClaimId`](crate::Config::ClaimId).
+pub mod v11 {
+	use super::v9;
+	use crate::{BalanceOf, ClaimKind, Config, HashAlgo, NamespaceId, Pallet};
+	use frame_support::{
+		migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use sp_runtime::traits::Saturating;
+
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+
+	/// A claim as stored in versions 11 and 12.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+	pub struct ClaimInfo<AccountId, BlockNumber, Balance> {
+		/// The sequence number assigned to the claim when it was created.
+		pub id: u64,
+		/// The account that owns the claim.
+		pub owner: AccountId,
+		/// The block number at which the claim was originally made.
+		pub created_at: BlockNumber,
+		/// The deposit held from the owner.
+		pub deposit: Balance,
+		/// The block number at which the claim is scheduled to expire, if any.
+		pub expires_at: Option<BlockNumber>,
+		/// The kind of artifact whose hash is claimed.
+		pub kind: ClaimKind,
+		/// The hashing algorithm that produced the claimed hash.
+		pub algo: HashAlgo,
+		/// The last block in which the claim cannot be revoked or transferred, if it is locked.
+		pub lock_until: Option<BlockNumber>,
+	}
+
+	/// The [`ClaimInfo`] type used by a runtime.
+	pub type ClaimInfoOf<T> =
+		ClaimInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, BalanceOf<T>>;
+
+	/// The claims as stored in versions 11 and 12.
+	#[frame_support::storage_alias]
+	pub(crate) type Claims<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		NamespaceId,
+		Blake2_128Concat,
+		<T as Config>::ClaimId,
+		ClaimInfoOf<T>,
+	>;
+
+	/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the state of this pallet from V10 to
+	/// V11.
+	///
+	/// Use [`MigrateV10ToV11`] instead, which only runs when the on-chain version is 10.
+	pub struct InnerMigrateV10ToV11<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV10ToV11<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			Claims::<T>::translate_values::<v9::ClaimInfoOf<T>, _>(|old| {
+				translated.saturating_inc();
+				Some(ClaimInfo {
+					id: old.id,
+					owner: old.owner,
+					created_at: old.created_at,
+					deposit: old.deposit,
+					expires_at: old.expires_at,
+					kind: old.kind,
+					algo: old.algo,
+					lock_until: None,
+				})
+			});
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let count = Claims::<T>::iter_keys().count() as u64;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let before = u64::decode(&mut &state[..])
+				.map_err(|_| "the state from `pre_upgrade` could not be decoded")?;
+			// Iterating decodes every value, so this also proves none were left in the old layout.
+			let after = Claims::<T>::iter_values().count() as u64;
+			ensure!(before == after, "some claims were lost in the migration");
+			Ok(())
+		}
+	}
+
+	/// Migrate the pallet storage from version 10 to version 11, if the on-chain version is 10.
+	pub type MigrateV10ToV11<T> = VersionedMigration<
+		10,
+		11,
+		InnerMigrateV10ToV11<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migrate the pallet storage from version 11 to version 12.
+///
+/// Version 12 stores the [`ClaimsByOwner`] index as a double map with an entry for every claim,
+/// instead of a bounded list of claims for every account.
+/// [`ClaimCountByOwner`](crate::ClaimCountByOwner) already holds the
+/// length of each list, so it is left as it is.
+///
+/// The keys of the claims are left as they are, so this migration works with any
+/// [`Config::ClaimId`](crate::Config::ClaimId).
+pub mod v12 {
+	use super::v5;
+	use crate::{ClaimsByOwner, Config, Pallet};
+	use alloc::vec::Vec;
+	use frame_support::{
+		migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+	};
+	use sp_runtime::traits::Saturating;
+
+	/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the state of this pallet from V11 to
+	/// V12.
+	///
+	/// Use [`MigrateV11ToV12`] instead, which only runs when the on-chain version is 11.
+	pub struct InnerMigrateV11ToV12<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV11ToV12<T> {
+		fn on_runtime_upgrade() -> Weight {
+			// The old and new indices share their storage prefix, so the old one is read
+			// completely before anything is written in the new layout.
+			let lists: Vec<_> = v5::ClaimsByOwner::<T>::iter().collect();
+			let (mut owners, mut claims) = (0u64, 0u64);
+			for (owner, list) in lists {
+				owners.saturating_inc();
+				v5::ClaimsByOwner::<T>::remove(&owner);
+				for key in list {
+					claims.saturating_inc();
+					ClaimsByOwner::<T>::insert(&owner, key, ());
+				}
+			}
+			T::DbWeight::get().reads_writes(owners, owners.saturating_add(claims))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let count: u64 =
+				v5::ClaimsByOwner::<T>::iter_values().map(|list| list.len() as u64).sum();
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let before = u64::decode(&mut &state[..])
+				.map_err(|_| "the state from `pre_upgrade` could not be decoded")?;
+			let after = ClaimsByOwner::<T>::iter_keys().count() as u64;
+			ensure!(before == after, "some claims were lost from the owner index");
+			Ok(())
+		}
+	}
+
+	/// Migrate the pallet storage from version 11 to version 12, if the on-chain version is 11.
+	pub type MigrateV11ToV12<T> = VersionedMigration<
+		11,
+		12,
+		InnerMigrateV11ToV12<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migrate the pallet storage from version 12 to version 13.
+///
+/// Version 13 lets the owner of a claim appoint a manager, its [`ClaimInfo::manager`]. None of the
+/// existing claims have one.
+///
+/// The keys of the claims are left as they are, so this migration works with any
+/// [`Config::ClaimId`](crate::Config::ClaimId).
+pub mod v13 {
+	use super::v11;
+	use crate::{ClaimInfo, Claims, Config, Pallet};
+	use frame_support::{
+		migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+	};
+	use sp_runtime::traits::Saturating;
+
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+
+	/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the state of this pallet from V12 to
+	/// V13.
+	///
+	/// Use [`MigrateV12ToV13`] instead, which only runs when the on-chain version is 12.
+	pub struct InnerMigrateV12ToV13<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV12ToV13<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			Claims::<T>::translate_values::<v11::ClaimInfoOf<T>, _>(|old| {
+				translated.saturating_inc();
+				Some(ClaimInfo {
+					id: old.id,
+					owner: old.owner,
+					created_at: old.created_at,
+					deposit: old.deposit,
+					expires_at: old.expires_at,
+					kind: old.kind,
+					algo: old.algo,
+					lock_until: old.lock_until,
+					manager: None,
+				})
+			});
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let count = Claims::<T>::iter_keys().count() as u64;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let before = u64::decode(&mut &state[..])
+				.map_err(|_| "the state from `pre_upgrade` could not be decoded")?;
+			// Iterating decodes every value, so this also proves none were left in the old layout.
+			let after = Claims::<T>::iter_values().count() as u64;
+			ensure!(before == after, "some claims were lost in the migration");
+			Ok(())
+		}
+	}
+
+	/// Migrate the pallet storage from version 12 to version 13, if the on-chain version is 12.
+	pub type MigrateV12ToV13<T> = VersionedMigration<
+		12,
+		13,
+		InnerMigrateV12ToV13<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
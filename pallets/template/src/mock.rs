@@ -1,6 +1,20 @@
+//! A mock runtime with the template pallet, its balances and the system pallet.
+//!
+//! Besides this pallet's own tests, it is available to other crates with the `test-utils`
+//! feature.
+
 use crate as pallet_template;
-use frame_support::derive_impl;
-use sp_runtime::BuildStorage;
+use crate::{NamespaceId, OnClaimChanged};
+use frame_support::{
+	derive_impl, ord_parameter_types,
+	pallet_prelude::inject_runtime_type,
+	parameter_types,
+	traits::{tokens::imbalance::ResolveTo, ConstU32, ConstU64, Hooks},
+	weights::{RuntimeDbWeight, Weight},
+};
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use sp_core::H256;
+use sp_runtime::{traits::BlockNumberProvider, BuildStorage};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -9,6 +23,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		TemplateModule: pallet_template,
 	}
 );
@@ -16,14 +31,176 @@ frame_support::construct_runtime!(
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
 	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type DbWeight = DbWeight;
 }
 
-impl pallet_template::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+	type ExistentialDeposit = ExistentialDeposit;
+}
+
+parameter_types! {
+	// Storage accesses are not free, so that the weight-bounded hooks can be tested.
+	pub const DbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 10, write: 100 };
+}
+
+parameter_types! {
+	pub static RejectDefaultHash: bool = true;
+	pub static EnforceAllowlist: bool = false;
+	pub static TombstoneRevoked: bool = false;
+	pub static MinClaimInterval: u64 = 0;
+	pub static ProvidedBlockNumber: Option<u64> = None;
+	pub static TrackHistory: bool = true;
+	pub static MaxClaimsPerAccount: u32 = 3;
+	pub static MaxTotalClaims: u32 = u32::MAX;
+	pub static MaxClaimsPerBlock: u32 = 10;
+	pub static ExistentialDeposit: u64 = 1;
+	pub static ClaimChanges: Vec<ClaimChange> = vec![];
+}
+
+ord_parameter_types! {
+	pub const FeeExempt: u64 = FEE_EXEMPT;
+}
+
+/// The block number provider of the pallet: [`ProvidedBlockNumber`] if set, as a relay chain's
+/// block number would be, or else the block number of [`System`].
+pub struct MockBlockNumberProvider;
+
+impl BlockNumberProvider for MockBlockNumberProvider {
+	type BlockNumber = u64;
+
+	fn current_block_number() -> u64 {
+		ProvidedBlockNumber::get().unwrap_or_else(System::block_number)
+	}
+}
+
+/// A change reported to [`RecordClaimChanges`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClaimChange {
+	Created(NamespaceId, u64, H256),
+	Revoked(NamespaceId, u64, H256),
+}
+
+/// Records every change it is notified of in [`ClaimChanges`].
+pub struct RecordClaimChanges;
+
+impl OnClaimChanged<u64, H256> for RecordClaimChanges {
+	fn on_created(namespace: NamespaceId, owner: &u64, claim: &H256) {
+		ClaimChanges::mutate(|changes| {
+			changes.push(ClaimChange::Created(namespace, *owner, *claim))
+		});
+	}
+
+	fn on_revoked(namespace: NamespaceId, owner: &u64, claim: &H256) {
+		ClaimChanges::mutate(|changes| {
+			changes.push(ClaimChange::Revoked(namespace, *owner, *claim))
+		});
+	}
+}
+
+/// The defaults of the template pallet in the mock runtime, small enough for tests to reach
+/// every limit.
+pub struct TestDefaultConfig;
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig, no_aggregated_types)]
+impl frame_system::DefaultConfig for TestDefaultConfig {}
+
+#[frame_support::register_default_impl(TestDefaultConfig)]
+impl pallet_template::DefaultConfig for TestDefaultConfig {
+	#[inject_runtime_type]
+	type RuntimeEvent = ();
+	#[inject_runtime_type]
+	type RuntimeHoldReason = ();
 	type WeightInfo = ();
+	type MaxClaimMetadataLen = ConstU32<32>;
+	type MaxExpiringPerBlock = ConstU32<2>;
+	type MaxClaimsPerAccount = MaxClaimsPerAccount;
+	type MaxTotalClaims = MaxTotalClaims;
+	type MaxClaimsPerBlock = MaxClaimsPerBlock;
+	type SlashDestination = ResolveTo<ConstU64<TREASURY>, Balances>;
+	type DustDestination = ResolveTo<ConstU64<TREASURY>, Balances>;
+	type MaxBatchSize = ConstU32<2>;
+	type RejectDefaultHash = RejectDefaultHash;
+	type EnforceAllowlist = EnforceAllowlist;
+	type TombstoneRevoked = TombstoneRevoked;
+	type MaxQueryPageSize = ConstU32<2>;
+	// Composed with the no-op handler to exercise the tuple implementation.
+	type OnClaimChanged = (RecordClaimChanges, ());
+	type TrackHistory = TrackHistory;
+	type MaxHistoryLen = ConstU32<3>;
+	type MaxTagLen = ConstU32<8>;
+	type MaxTagsPerClaim = ConstU32<2>;
+	type MaxAliasLen = ConstU32<8>;
+	type MaxNoteLen = ConstU32<16>;
+	type MaxPreimageLen = ConstU32<64>;
+	type MaxIterationItems = ConstU32<3>;
 }
 
-// Build genesis storage according to the mock runtime.
+#[derive_impl(TestDefaultConfig as pallet_template::DefaultConfig)]
+impl pallet_template::Config for Test {
+	type ClaimId = H256;
+	type ClaimLifetime = ConstU64<10>;
+	type Fungible = Balances;
+	type ClaimDeposit = ConstU64<CLAIM_DEPOSIT>;
+	type MetadataDepositPerByte = ConstU64<METADATA_DEPOSIT_PER_BYTE>;
+	type ClaimAdminOrigin = EnsureRoot<u64>;
+	type FeeExemptOrigin = EnsureSignedBy<FeeExempt, u64>;
+	type MaxQueryRange = ConstU64<3>;
+	type PendingTimeout = ConstU64<5>;
+	type MinClaimInterval = MinClaimInterval;
+	type RevealDelay = ConstU64<3>;
+	type BlockNumberProvider = MockBlockNumberProvider;
+}
+
+/// The deposit reserved for each claim in tests.
+pub const CLAIM_DEPOSIT: u64 = 10;
+
+/// The deposit reserved for each byte of claim metadata in tests.
+pub const METADATA_DEPOSIT_PER_BYTE: u64 = 1;
+
+/// The account slashed claim deposits are sent to in tests.
+pub const TREASURY: u64 = 99;
+
+/// The account allowed to claim without fees in tests.
+pub const FEE_EXEMPT: u64 = 3;
+
+/// The free balance every test account starts with.
+pub const INITIAL_BALANCE: u64 = 100;
+
+/// Build genesis storage according to the mock runtime, with accounts 1 to 3 endowed with
+/// [`INITIAL_BALANCE`].
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+	new_test_ext_with_claims(Vec::new())
+}
+
+/// Build genesis storage as [`new_test_ext`] does, with `claims` registered at genesis in the
+/// default namespace as `(claim, owner)` pairs.
+///
+/// Like all genesis claims, they are made at block 0 without a deposit.
+pub fn new_test_ext_with_claims(claims: Vec<(H256, u64)>) -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: (1..=3).map(|who| (who, INITIAL_BALANCE)).collect(),
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	pallet_template::GenesisConfig::<Test> {
+		initial_claims: claims.into_iter().map(|(claim, owner)| (claim, owner, 0)).collect(),
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	t.into()
+}
+
+/// Advance the chain to block `n`, running the pallet's `on_initialize` hook for each new block,
+/// and its `on_idle` hook as if nothing else was in the block.
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		TemplateModule::on_initialize(next);
+		TemplateModule::on_idle(next, Weight::MAX);
+	}
 }
@@ -0,0 +1,66 @@
+//! An off-chain mirror of the claims registry.
+//!
+//! On every block the pallet's off-chain worker looks at the claim events of that block and
+//! copies the resulting state of each affected claim into the node's persistent off-chain local
+//! storage, so indexers can keep track of all claims without re-scanning the chain state.
+//!
+//! ## Key schema
+//!
+//! All values are SCALE encoded.
+//!
+//! - [`CLAIM_PREFIX`] `++ (namespace, claim).encode()` holds `(owner, created_at)` for every claim
+//!   that exists. The key is cleared when the claim is revoked or expires.
+//! - [`LAST_INDEXED_KEY`] holds the number of the last block the worker has processed.
+//! - [`LOCK_KEY`] is used by the worker to keep concurrent runs from interleaving their writes.
+//!
+//! The mirror reflects the blocks the worker has processed, in the order it processed them. After
+//! a re-org, claims touched on the retracted fork are only corrected once they change again.
+
+use crate::{Claims, Config, Event, NamespaceId};
+use alloc::vec::Vec;
+use codec::Encode;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::offchain::{
+	storage::StorageValueRef,
+	storage_lock::{StorageLock, Time},
+};
+
+/// The prefix of the keys holding the mirrored claims.
+pub const CLAIM_PREFIX: &[u8] = b"pallet-template::claim::";
+/// The key holding the number of the last processed block.
+pub const LAST_INDEXED_KEY: &[u8] = b"pallet-template::last-indexed";
+/// The key of the lock held while a block is being processed.
+pub const LOCK_KEY: &[u8] = b"pallet-template::lock";
+
+/// The off-chain storage key under which `claim` in `namespace` is mirrored.
+pub fn claim_key<T: Config>(namespace: NamespaceId, claim: &T::ClaimId) -> Vec<u8> {
+	[CLAIM_PREFIX, &(namespace, claim).encode()[..]].concat()
+}
+
+/// Mirror the claims changed in `block` into off-chain storage.
+pub(crate) fn index_block<T: Config>(block: BlockNumberFor<T>) {
+	let mut lock = StorageLock::<Time>::new(LOCK_KEY);
+	let _guard = lock.lock();
+
+	for record in frame_system::Pallet::<T>::read_events_no_consensus() {
+		let event = <T as Config>::RuntimeEvent::from(record.event);
+		let Ok(event) = event.try_into() else { continue };
+		let (namespace, claim) = match event {
+			Event::ClaimCreated { namespace, claim, .. } |
+			Event::ClaimRevoked { namespace, claim, .. } |
+			Event::ClaimTransferred { namespace, claim, .. } |
+			Event::ClaimForceRevoked { namespace, claim, .. } |
+			Event::ClaimExpired { namespace, claim, .. } => (namespace, claim),
+			_ => continue,
+		};
+
+		let key = claim_key::<T>(namespace, &claim);
+		let mut value = StorageValueRef::persistent(&key);
+		match Claims::<T>::get(namespace, claim) {
+			Some(info) => value.set(&(info.owner, info.created_at)),
+			None => value.clear(),
+		}
+	}
+
+	StorageValueRef::persistent(LAST_INDEXED_KEY).set(&block);
+}
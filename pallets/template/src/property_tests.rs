@@ -0,0 +1,168 @@
+//! Property tests of the claims registry.
+//!
+//! Each case runs a sequence of `create_claim`, `revoke_claim` and `transfer_claim` calls,
+//! generated by `proptest` from random signers and over a small pool of hashes so that calls
+//! often collide, against the mock runtime. After every call the registry is compared with a
+//! simple model of who owns what, and its invariants are checked.
+//!
+//! A failing sequence is shrunk to the shortest one that still fails, and saved under
+//! `proptest-regressions/` so that later runs try it first.
+
+use crate::{
+	mock::*, Call, ClaimCount, ClaimKind, Claims, ClaimsByOwner, Error, HashAlgo, NamespaceId,
+};
+use alloc::collections::BTreeMap;
+use frame_support::traits::Get;
+use proptest::prelude::*;
+use sp_core::H256;
+use sp_runtime::{traits::Dispatchable, DispatchError};
+
+/// The number of random call sequences to run.
+const CASES: u32 = 64;
+/// The most calls in each sequence.
+const STEPS: usize = 64;
+/// The accounts signing the calls, all of which are funded by [`new_test_ext`].
+const SIGNERS: [u64; 3] = [1, 2, 3];
+/// The namespaces the calls are spread over.
+const NAMESPACES: [NamespaceId; 2] = [0, 1];
+
+#[derive(Debug)]
+enum Action {
+	Create { who: u64, namespace: NamespaceId, claim: H256 },
+	Revoke { who: u64, namespace: NamespaceId, claim: H256 },
+	Transfer { who: u64, namespace: NamespaceId, claim: H256, dest: u64 },
+}
+
+/// The calls of a sequence, each from any signer, in any namespace and over any hash of the pool.
+fn action() -> impl Strategy<Value = Action> {
+	let signer = || prop::sample::select(&SIGNERS[..]);
+	let namespace = prop::sample::select(&NAMESPACES[..]);
+	// Never the default hash, so the calls are not rejected for it.
+	let claim = (1..=6u8).prop_map(H256::repeat_byte);
+	let target = (signer(), namespace, claim);
+	prop_oneof![
+		target
+			.clone()
+			.prop_map(|(who, namespace, claim)| Action::Create { who, namespace, claim }),
+		target
+			.clone()
+			.prop_map(|(who, namespace, claim)| Action::Revoke { who, namespace, claim }),
+		(target, signer()).prop_map(|((who, namespace, claim), dest)| Action::Transfer {
+			who,
+			namespace,
+			claim,
+			dest
+		}),
+	]
+}
+
+impl Action {
+	/// Dispatch the action the way a transaction would, so failed calls leave no changes behind.
+	fn dispatch(&self) -> Result<(), DispatchError> {
+		let (who, call) = match *self {
+			Action::Create { who, namespace, claim } => (
+				who,
+				Call::create_claim {
+					namespace,
+					claim,
+					kind: ClaimKind::Document,
+					algo: HashAlgo::Blake2_256,
+					metadata: None,
+					expiry: None,
+					lock_until: None,
+					cid: None,
+				},
+			),
+			Action::Revoke { who, namespace, claim } =>
+				(who, Call::revoke_claim { namespace, claim }),
+			Action::Transfer { who, namespace, claim, dest } =>
+				(who, Call::transfer_claim { namespace, claim, dest }),
+		};
+		RuntimeCall::TemplateModule(call)
+			.dispatch(RuntimeOrigin::signed(who))
+			.map(|_| ())
+			.map_err(|e| e.error)
+	}
+}
+
+/// Who owns each claim, according to the calls that succeeded so far.
+type Model = BTreeMap<(NamespaceId, H256), u64>;
+
+/// Check that `action` returned the `result` expected from `model`, and apply it there.
+fn apply(model: &mut Model, action: &Action, result: Result<(), DispatchError>) {
+	let owned_by = |model: &Model, who: u64| model.values().filter(|owner| **owner == who).count();
+	let max_claims = <<Test as crate::Config>::MaxClaimsPerAccount as Get<u32>>::get() as usize;
+	match *action {
+		Action::Create { who, namespace, claim } =>
+			if model.contains_key(&(namespace, claim)) {
+				assert_eq!(result, Err(Error::<Test>::AlreadyClaimed.into()));
+			} else if owned_by(model, who) == max_claims {
+				assert_eq!(result, Err(Error::<Test>::TooManyClaims.into()));
+			} else {
+				assert_eq!(result, Ok(()));
+				model.insert((namespace, claim), who);
+			},
+		Action::Revoke { who, namespace, claim } => match model.get(&(namespace, claim)) {
+			None => assert_eq!(result, Err(Error::<Test>::NoSuchClaim.into())),
+			Some(owner) if *owner != who =>
+				assert_eq!(result, Err(Error::<Test>::NotClaimOwner.into())),
+			Some(_) => {
+				assert_eq!(result, Ok(()));
+				model.remove(&(namespace, claim));
+			},
+		},
+		Action::Transfer { who, namespace, claim, dest } => match model.get(&(namespace, claim)) {
+			None => assert_eq!(result, Err(Error::<Test>::NoSuchClaim.into())),
+			Some(owner) if *owner != who =>
+				assert_eq!(result, Err(Error::<Test>::NotClaimOwner.into())),
+			Some(_) if dest == who =>
+				assert_eq!(result, Err(Error::<Test>::CannotTransferToSelf.into())),
+			Some(_) if owned_by(model, dest) == max_claims =>
+				assert_eq!(result, Err(Error::<Test>::TooManyClaims.into())),
+			Some(_) => {
+				assert_eq!(result, Ok(()));
+				model.insert((namespace, claim), dest);
+			},
+		},
+	}
+}
+
+/// Check that the registry holds exactly the claims of `model`, each indexed under its owner
+/// alone.
+fn check_invariants(model: &Model) {
+	let claims: Model = Claims::<Test>::iter()
+		.map(|(namespace, claim, info)| ((namespace, claim), info.owner))
+		.collect();
+	assert_eq!(&claims, model);
+	assert_eq!(ClaimCount::<Test>::get() as usize, claims.len());
+
+	let mut indexed = Model::new();
+	for (owner, key) in ClaimsByOwner::<Test>::iter_keys() {
+		assert!(indexed.insert(key, owner).is_none(), "a claim has two owners");
+	}
+	assert_eq!(&indexed, model);
+	assert_eq!(crate::Pallet::<Test>::do_try_state(System::block_number()), Ok(()));
+}
+
+fn run_case(actions: &[Action]) {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let mut model = Model::new();
+		for action in actions {
+			let result = action.dispatch();
+			apply(&mut model, action, result);
+			check_invariants(&model);
+		}
+	});
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(CASES))]
+
+	#[test]
+	fn random_calls_keep_the_registry_consistent(
+		actions in prop::collection::vec(action(), 1..=STEPS),
+	) {
+		run_case(&actions);
+	}
+}
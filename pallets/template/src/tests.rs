@@ -1,5 +1,140 @@
-use crate::{mock::*, Error, Event, Something};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	migrations, mock::*, Approvals, Cid, ClaimHistory, ClaimInfo, ClaimInfoOf, ClaimInspect,
+	ClaimKind, ClaimMetadata, ClaimMetadataOf, ClaimNoteOf, ClaimRegistry, ClaimStats, ClaimTags,
+	Claims, ClaimsByOwner, CleanupCursor, Error, Event, ExpiringAt, HashAlgo, HistoryAction,
+	HoldReason, PendingClaims, PendingExpiringAt, RevokedClaim, Something, DEFAULT_NAMESPACE,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::WithPostDispatchInfo,
+	storage::StoragePrefixedMap,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion, UncheckedOnRuntimeUpgrade},
+	weights::{RuntimeDbWeight, Weight},
+};
+use sp_core::H256;
+use sp_runtime::{traits::Hash as _, BuildStorage, DispatchError};
+
+// The weight of a single storage read, charged by `create_claim` when the hash is already taken.
+fn read_weight() -> Weight {
+	<<Test as frame_system::Config>::DbWeight as Get<RuntimeDbWeight>>::get().reads(1)
+}
+
+fn claim_info(
+	id: u64,
+	owner: u64,
+	created_at: u64,
+	deposit: u64,
+	expires_at: Option<u64>,
+) -> ClaimInfoOf<Test> {
+	ClaimInfo {
+		id,
+		owner,
+		created_at,
+		deposit,
+		expires_at,
+		kind: ClaimKind::Document,
+		algo: HashAlgo::Blake2_256,
+		lock_until: None,
+		manager: None,
+	}
+}
+
+fn cid(cid: &str) -> Cid {
+	Cid(cid.as_bytes().to_vec().try_into().unwrap())
+}
+
+// A subscriber that keeps the messages of the pallet's debug log lines.
+#[derive(Default)]
+struct CaptureLogs(std::sync::Mutex<Vec<String>>);
+
+impl tracing::Subscriber for CaptureLogs {
+	fn enabled(&self, metadata: &tracing::Metadata) -> bool {
+		metadata.target() == crate::LOG_TARGET && *metadata.level() <= tracing::Level::DEBUG
+	}
+
+	fn event(&self, event: &tracing::Event) {
+		struct Message<'a>(&'a mut Vec<String>);
+		impl tracing::field::Visit for Message<'_> {
+			fn record_debug(
+				&mut self,
+				field: &tracing::field::Field,
+				value: &dyn core::fmt::Debug,
+			) {
+				if field.name() == "message" {
+					self.0.push(format!("{value:?}"));
+				}
+			}
+		}
+		event.record(&mut Message(&mut self.0.lock().unwrap()));
+	}
+
+	fn new_span(&self, _: &tracing::span::Attributes) -> tracing::span::Id {
+		tracing::span::Id::from_u64(1)
+	}
+
+	fn record(&self, _: &tracing::span::Id, _: &tracing::span::Record) {}
+
+	fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+	fn enter(&self, _: &tracing::span::Id) {}
+
+	fn exit(&self, _: &tracing::span::Id) {}
+}
+
+// Run `f`, returning the messages of the pallet's log lines it emitted on this thread.
+fn capture_logs(f: impl FnOnce()) -> Vec<String> {
+	// Log lines reach `tracing` subscribers through the logger this installs, unless another test
+	// installed it first. Its filter is global, so let debug lines through.
+	sp_tracing::try_init_simple();
+	log::set_max_level(log::LevelFilter::Debug);
+	let capture = std::sync::Arc::new(CaptureLogs::default());
+	tracing::subscriber::with_default(capture.clone(), f);
+	let logs = capture.0.lock().unwrap().clone();
+	logs
+}
+
+// A claim in the layout of versions 4 to 7, for the migration tests.
+fn old_claim_info(
+	owner: u64,
+	created_at: u64,
+	deposit: u64,
+	expires_at: Option<u64>,
+) -> migrations::v4::ClaimInfoOf<Test> {
+	migrations::v4::ClaimInfo { owner, created_at, deposit, expires_at, kind: ClaimKind::Document }
+}
+
+// The claims indexed under `owner`, sorted, as the index is in the order of its map.
+fn indexed_claims(owner: u64) -> Vec<(crate::NamespaceId, H256)> {
+	let mut claims = TemplateModule::claims_of(&owner);
+	claims.sort();
+	claims
+}
+
+// Move the owner index to the layout it had before version 12, a list of claims per account.
+fn index_claims_as_before_v12() {
+	let keys: Vec<_> = ClaimsByOwner::<Test>::iter_keys().collect();
+	for (owner, key) in keys {
+		ClaimsByOwner::<Test>::remove(owner, key);
+		assert_ok!(migrations::v5::ClaimsByOwner::<Test>::try_append(owner, key));
+	}
+}
+
+// Have `who` claim each of `claims` in the default namespace.
+fn create_claims_for(who: u64, claims: &[H256]) {
+	for claim in claims {
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(who),
+			DEFAULT_NAMESPACE,
+			*claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+	}
+}
 
 #[test]
 fn it_works_for_default_value() {
@@ -25,3 +160,7127 @@ fn correct_error_for_none_value() {
 		);
 	});
 }
+
+#[test]
+fn create_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(11),
+			None,
+			None
+		));
+		assert_eq!(
+			Claims::<Test>::get(DEFAULT_NAMESPACE, claim),
+			Some(claim_info(0, 1, 1, CLAIM_DEPOSIT, Some(11)))
+		);
+		System::assert_last_event(
+			Event::ClaimCreated {
+				namespace: DEFAULT_NAMESPACE,
+				who: 1,
+				claim,
+				kind: ClaimKind::Document,
+				algo: HashAlgo::Blake2_256,
+				id: 0,
+				cid: None,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn create_claim_attaches_an_ipfs_cid() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let v0 = cid("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+		let v1 = cid("bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi");
+		for (i, cid) in [v0, v1].into_iter().enumerate() {
+			let claim = H256::repeat_byte(i as u8 + 1);
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				Some(cid.clone())
+			));
+			assert_eq!(TemplateModule::cid_of(DEFAULT_NAMESPACE, &claim), Some(cid.clone()));
+			System::assert_last_event(
+				Event::ClaimCreated {
+					namespace: DEFAULT_NAMESPACE,
+					who: 1,
+					claim,
+					kind: ClaimKind::Document,
+					algo: HashAlgo::Blake2_256,
+					id: i as u64,
+					cid: Some(cid),
+				}
+				.into(),
+			);
+		}
+
+		// The identifier goes with the claim.
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert_eq!(TemplateModule::cid_of(DEFAULT_NAMESPACE, &claim), None);
+	});
+}
+
+#[test]
+fn create_claim_rejects_malformed_cids() {
+	new_test_ext().execute_with(|| {
+		let malformed = [
+			// Too short for a CIDv0.
+			"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd",
+			// `0` is not a base58 character.
+			"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPb0G",
+			// Upper case is not the base32 alphabet of a CIDv1.
+			"bAFYBEIGDYRZT5SFP7UDM7HU76UH7Y26NF3EFUYLQABF3OCLGTQY55FBZDI",
+			// A bare multibase prefix.
+			"b",
+			// No known prefix at all.
+			"zdj7WWeQ43G6JJvLWQWZpyHuAMq6uYWRjkBXFad11vE2LHhQ7",
+			"",
+		];
+		for malformed in malformed {
+			assert_noop!(
+				TemplateModule::create_claim(
+					RuntimeOrigin::signed(1),
+					DEFAULT_NAMESPACE,
+					H256::repeat_byte(1),
+					ClaimKind::Document,
+					HashAlgo::Blake2_256,
+					None,
+					None,
+					None,
+					Some(cid(malformed))
+				),
+				Error::<Test>::InvalidCid.with_weight(Weight::zero())
+			);
+		}
+	});
+}
+
+#[test]
+fn create_claim_logs_under_the_pallet_target() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		let logs = capture_logs(|| create_claims_for(1, &[claim]));
+		assert_eq!(
+			logs,
+			vec![format!("created claim {claim:?} in namespace {DEFAULT_NAMESPACE} for 1")]
+		);
+	});
+}
+
+#[test]
+fn create_claim_fails_when_already_claimed() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::AlreadyClaimed.with_weight(read_weight())
+		);
+	});
+}
+
+#[test]
+fn revoke_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		System::set_block_number(4);
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert_eq!(Claims::<Test>::get(DEFAULT_NAMESPACE, claim), None);
+		System::assert_last_event(
+			Event::ClaimRevoked {
+				namespace: DEFAULT_NAMESPACE,
+				who: 1,
+				claim,
+				created_at: 1,
+				deposit_returned: CLAIM_DEPOSIT,
+				id: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn revoke_claim_fails_for_non_owner_or_missing_claim() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_noop!(
+			TemplateModule::revoke_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NoSuchClaim
+		);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			TemplateModule::revoke_claim(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NotClaimOwner
+		);
+	});
+}
+
+#[test]
+fn transfer_claim_keeps_original_block_number() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(11),
+			None,
+			None
+		));
+
+		System::set_block_number(5);
+		assert_ok!(TemplateModule::transfer_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			2
+		));
+		assert_eq!(
+			Claims::<Test>::get(DEFAULT_NAMESPACE, claim),
+			Some(claim_info(0, 2, 1, CLAIM_DEPOSIT, Some(11)))
+		);
+		System::assert_last_event(
+			Event::ClaimTransferred { namespace: DEFAULT_NAMESPACE, from: 1, to: 2, claim, id: 0 }
+				.into(),
+		);
+
+		// The previous owner has lost control over the claim.
+		assert_noop!(
+			TemplateModule::revoke_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NotClaimOwner
+		);
+
+		// Revoking reports when the claim was originally made, not when it was transferred.
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		System::assert_last_event(
+			Event::ClaimRevoked {
+				namespace: DEFAULT_NAMESPACE,
+				who: 2,
+				claim,
+				created_at: 1,
+				deposit_returned: CLAIM_DEPOSIT,
+				id: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn transfer_claim_fails_for_non_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			TemplateModule::transfer_claim(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, claim, 3),
+			Error::<Test>::NotClaimOwner
+		);
+	});
+}
+
+#[test]
+fn transfer_claim_fails_for_nonexistent_claim() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TemplateModule::transfer_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				H256::repeat_byte(1),
+				2
+			),
+			Error::<Test>::NoSuchClaim
+		);
+	});
+}
+
+#[test]
+fn transfer_claim_to_self_fails() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			TemplateModule::transfer_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, claim, 1),
+			Error::<Test>::CannotTransferToSelf
+		);
+	});
+}
+
+#[test]
+fn create_claim_stores_metadata() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			Some(b"ipfs://example".to_vec()),
+			None,
+			None,
+			None
+		));
+		assert_eq!(
+			ClaimMetadata::<Test>::get(DEFAULT_NAMESPACE, claim).unwrap().into_inner(),
+			b"ipfs://example"
+		);
+	});
+}
+
+#[test]
+fn create_claim_fails_with_metadata_too_long() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				H256::repeat_byte(1),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				Some(vec![0; 33]),
+				None,
+				None,
+				None
+			),
+			Error::<Test>::MetadataTooLong.with_weight(Weight::zero())
+		);
+	});
+}
+
+#[test]
+fn create_claim_rejects_the_default_hash_when_configured() {
+	new_test_ext().execute_with(|| {
+		assert!(RejectDefaultHash::get());
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				H256::default(),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::InvalidClaimHash.with_weight(Weight::zero())
+		);
+		assert_noop!(
+			TemplateModule::create_claims(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				vec![H256::repeat_byte(1), H256::default()].try_into().unwrap(),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				true
+			),
+			Error::<Test>::InvalidClaimHash
+		);
+	});
+}
+
+#[test]
+fn create_claim_accepts_the_default_hash_when_allowed() {
+	new_test_ext().execute_with(|| {
+		RejectDefaultHash::set(false);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			H256::default(),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &H256::default()), Some(1));
+	});
+}
+
+#[test]
+fn revoke_claim_clears_metadata() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			Some(vec![1]),
+			None,
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert_eq!(ClaimMetadata::<Test>::get(DEFAULT_NAMESPACE, claim), None);
+	});
+}
+
+#[test]
+fn create_claim_reserves_a_deposit_for_metadata() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			H256::repeat_byte(1),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			Some(vec![0; 8]),
+			None,
+			None,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(1), CLAIM_DEPOSIT + 8 * METADATA_DEPOSIT_PER_BYTE);
+	});
+}
+
+#[test]
+fn set_claim_metadata_replaces_and_clears_metadata() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			Some(vec![0; 4]),
+			None,
+			None,
+			None
+		));
+
+		// Growing the metadata holds the deposit of the extra bytes.
+		assert_ok!(TemplateModule::set_claim_metadata(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			Some(b"ipfs://example".to_vec())
+		));
+		assert_eq!(
+			ClaimMetadata::<Test>::get(DEFAULT_NAMESPACE, claim).unwrap().into_inner(),
+			b"ipfs://example"
+		);
+		let deposit = CLAIM_DEPOSIT + 14 * METADATA_DEPOSIT_PER_BYTE;
+		assert_eq!(Claims::<Test>::get(DEFAULT_NAMESPACE, claim).unwrap().deposit, deposit);
+		assert_eq!(Balances::reserved_balance(1), deposit);
+		System::assert_last_event(
+			Event::ClaimMetadataUpdated { namespace: DEFAULT_NAMESPACE, claim }.into(),
+		);
+
+		// Empty metadata clears it and returns its deposit.
+		assert_ok!(TemplateModule::set_claim_metadata(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			Some(vec![])
+		));
+		assert_eq!(ClaimMetadata::<Test>::get(DEFAULT_NAMESPACE, claim), None);
+		assert_eq!(Balances::reserved_balance(1), CLAIM_DEPOSIT);
+
+		// Clearing twice is harmless.
+		assert_ok!(TemplateModule::set_claim_metadata(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			None
+		));
+		assert_eq!(Claims::<Test>::get(DEFAULT_NAMESPACE, claim).unwrap().deposit, CLAIM_DEPOSIT);
+		assert_eq!(Balances::reserved_balance(1), CLAIM_DEPOSIT);
+
+		// Revoking releases whatever is held for the claim.
+		assert_ok!(TemplateModule::set_claim_metadata(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			Some(vec![0; 32])
+		));
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn the_metadata_deposit_follows_the_stored_bytes() {
+	use frame_support::traits::fungible::InspectHold;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			Some(vec![0; 8]),
+			None,
+			None,
+			None
+		));
+		let held = || Balances::balance_on_hold(&HoldReason::ClaimDeposit.into(), &1);
+		let recorded = || Claims::<Test>::get(DEFAULT_NAMESPACE, claim).unwrap().deposit;
+
+		for len in [20, 32, 3, 12, 1] {
+			let free = Balances::free_balance(1);
+			let before = held();
+			assert_ok!(TemplateModule::set_claim_metadata(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				Some(vec![0; len])
+			));
+			let deposit = CLAIM_DEPOSIT + len as u64 * METADATA_DEPOSIT_PER_BYTE;
+			assert_eq!((held(), recorded()), (deposit, deposit), "{len} bytes");
+			// Only the difference moves between the free and held balances.
+			assert_eq!(free + before, Balances::free_balance(1) + deposit, "{len} bytes");
+		}
+	});
+}
+
+#[test]
+fn set_claim_metadata_fails_for_bad_callers_and_input() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_noop!(
+			TemplateModule::set_claim_metadata(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				None
+			),
+			Error::<Test>::NoSuchClaim
+		);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			TemplateModule::set_claim_metadata(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				claim,
+				Some(vec![1])
+			),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			TemplateModule::set_claim_metadata(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				Some(vec![0; 33])
+			),
+			Error::<Test>::MetadataTooLong
+		);
+		assert_noop!(
+			TemplateModule::set_claim_metadata(RuntimeOrigin::signed(1), 7, claim, Some(vec![1])),
+			Error::<Test>::NoSuchClaim
+		);
+	});
+}
+
+#[test]
+fn set_claim_metadata_fails_when_the_deposit_is_unaffordable() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		// Leave too little free balance to hold the deposit of two more bytes.
+		let free = Balances::free_balance(1);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), 2, free - 2));
+		assert_noop!(
+			TemplateModule::set_claim_metadata(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				Some(vec![0; 2])
+			),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn genesis_config_registers_initial_claims() {
+	let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+	let storage = RuntimeGenesisConfig {
+		system: Default::default(),
+		balances: Default::default(),
+		template_module: crate::GenesisConfig {
+			initial_claims: vec![(first, 1, 0), (second, 2, 0)],
+		},
+	}
+	.build_storage()
+	.unwrap();
+
+	sp_io::TestExternalities::from(storage).execute_with(|| {
+		// The kind and algorithm of genesis claims are unknown.
+		let (kind, algo) = (ClaimKind::Other(0), HashAlgo::Other(0));
+		assert_eq!(
+			Claims::<Test>::get(DEFAULT_NAMESPACE, first),
+			Some(ClaimInfo { kind, algo, ..claim_info(0, 1, 0, 0, None) })
+		);
+		assert_eq!(
+			Claims::<Test>::get(DEFAULT_NAMESPACE, second),
+			Some(ClaimInfo { kind, algo, ..claim_info(1, 2, 0, 0, None) })
+		);
+		assert_eq!(
+			TemplateModule::claims_created_in_block(0),
+			vec![(DEFAULT_NAMESPACE, first), (DEFAULT_NAMESPACE, second)]
+		);
+	});
+}
+
+#[test]
+fn claims_between_reads_the_blocks_in_range() {
+	new_test_ext().execute_with(|| {
+		let claims: Vec<_> = (1..=4).map(H256::repeat_byte).collect();
+		System::set_block_number(1);
+		create_claims_for(1, &claims[..2]);
+		System::set_block_number(3);
+		create_claims_for(2, &claims[2..3]);
+		System::set_block_number(4);
+		create_claims_for(1, &claims[3..]);
+
+		let between = |from, to| {
+			TemplateModule::claims_between(from, to)
+				.map(|found| found.into_iter().map(|(_, claim, owner)| (claim, owner)).collect())
+		};
+		// Empty ranges, with no block or no claim in them.
+		assert_eq!(between(3, 2), Some(vec![]));
+		assert_eq!(between(2, 2), Some(vec![]));
+		// A single block.
+		assert_eq!(between(3, 3), Some(vec![(claims[2], 2)]));
+		// Several blocks, in the order the claims were created.
+		assert_eq!(between(1, 3), Some(vec![(claims[0], 1), (claims[1], 1), (claims[2], 2)]));
+		assert_eq!(between(2, 4), Some(vec![(claims[2], 2), (claims[3], 1)]));
+
+		// `MaxQueryRange` is 3 in the mock.
+		assert_eq!(between(1, 4), None);
+		assert_eq!(between(0, u64::MAX), None);
+
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claims[0]
+		));
+		assert_eq!(between(1, 1), Some(vec![(claims[1], 1)]));
+	});
+}
+
+#[test]
+fn the_claims_root_depends_on_the_order_of_changes() {
+	let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+	let root_after = |claims: [H256; 2], revoke: Option<H256>| {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_eq!(TemplateModule::claims_root(), H256::zero());
+			create_claims_for(1, &claims);
+			if let Some(claim) = revoke {
+				assert_ok!(TemplateModule::revoke_claim(
+					RuntimeOrigin::signed(1),
+					DEFAULT_NAMESPACE,
+					claim
+				));
+			}
+			TemplateModule::claims_root()
+		})
+	};
+
+	// The same changes in the same order always give the same root.
+	let root = root_after([first, second], None);
+	assert_ne!(root, H256::zero());
+	assert_eq!(root_after([first, second], None), root);
+	// Another order gives another root, although the same claims exist in the end.
+	assert_ne!(root_after([second, first], None), root);
+	// Removing a claim changes the root, rather than undoing its creation.
+	let revoked = root_after([first, second], Some(second));
+	assert_ne!(revoked, root);
+	assert_eq!(root_after([first, second], Some(second)), revoked);
+
+	// Genesis claims are folded in as well.
+	new_test_ext_with_claims(vec![(first, 1), (second, 1)]).execute_with(|| {
+		assert_eq!(TemplateModule::claims_root(), root);
+	});
+}
+
+#[test]
+fn the_mock_builder_registers_claims_at_genesis() {
+	let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+	new_test_ext_with_claims(vec![(first, 1), (second, 2)]).execute_with(|| {
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &first), Some(1));
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &second), Some(2));
+		assert_eq!(TemplateModule::claims_created_in_block(0).len(), 2);
+		// The accounts are endowed as with `new_test_ext`.
+		assert_eq!(Balances::free_balance(3), INITIAL_BALANCE);
+		assert_ok!(TemplateModule::do_try_state(0));
+	});
+}
+
+#[test]
+#[should_panic(expected = "duplicate claim in genesis config")]
+fn genesis_config_rejects_duplicate_claims() {
+	let claim = H256::repeat_byte(1);
+	let _ = RuntimeGenesisConfig {
+		system: Default::default(),
+		balances: Default::default(),
+		template_module: crate::GenesisConfig {
+			initial_claims: vec![(claim, 1, 0), (claim, 2, 0)],
+		},
+	}
+	.build_storage();
+}
+
+#[test]
+fn claims_expire_after_their_lifetime() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			Some(vec![1]),
+			Some(11),
+			None,
+			None
+		));
+		assert_eq!(ExpiringAt::<Test>::get(11).into_inner(), vec![(DEFAULT_NAMESPACE, claim)]);
+
+		run_to_block(10);
+		assert!(Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+
+		run_to_block(11);
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		assert!(!ClaimMetadata::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		assert!(!ExpiringAt::<Test>::contains_key(11));
+		System::assert_last_event(
+			Event::ClaimExpired { namespace: DEFAULT_NAMESPACE, claim, id: 0 }.into(),
+		);
+	});
+}
+
+#[test]
+fn expired_claims_can_be_claimed_again_before_the_sweep() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			Some(vec![1]),
+			Some(11),
+			None,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(1), CLAIM_DEPOSIT + METADATA_DEPOSIT_PER_BYTE);
+
+		// Skip past the expiry without running the sweep.
+		System::set_block_number(12);
+		assert!(Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		System::reset_events();
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_eq!(Claims::<Test>::get(DEFAULT_NAMESPACE, claim).unwrap().owner, 2);
+		assert!(!ClaimMetadata::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		assert!(!ExpiringAt::<Test>::contains_key(11));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), CLAIM_DEPOSIT);
+		assert_eq!(TemplateModule::claims_of(&1), vec![]);
+		assert_eq!(TemplateModule::claim_count(), 1);
+		let events: Vec<RuntimeEvent> =
+			System::events().into_iter().map(|record| record.event).collect();
+		assert!(events
+			.contains(&Event::ClaimExpired { namespace: DEFAULT_NAMESPACE, claim, id: 0 }.into()));
+		System::assert_last_event(
+			Event::ClaimCreated {
+				namespace: DEFAULT_NAMESPACE,
+				who: 2,
+				claim,
+				kind: ClaimKind::Document,
+				algo: HashAlgo::Blake2_256,
+				id: 1,
+				cid: None,
+			}
+			.into(),
+		);
+
+		// A claim that has not expired yet still stands in the way.
+		let other = H256::repeat_byte(2);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			other,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(13),
+			None,
+			None
+		));
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				other,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::AlreadyClaimed.with_weight(read_weight())
+		);
+	});
+}
+
+#[test]
+fn expiry_skips_claims_revoked_before_expiring() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(11),
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+
+		run_to_block(11);
+		System::assert_last_event(
+			Event::ClaimRevoked {
+				namespace: DEFAULT_NAMESPACE,
+				who: 1,
+				claim,
+				created_at: 1,
+				deposit_returned: CLAIM_DEPOSIT,
+				id: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn expiry_keeps_claims_made_again_after_revocation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(11),
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+
+		System::set_block_number(5);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(15),
+			None,
+			None
+		));
+
+		// The stale schedule from the first claim must not remove the second one.
+		run_to_block(11);
+		assert_eq!(
+			Claims::<Test>::get(DEFAULT_NAMESPACE, claim),
+			Some(claim_info(1, 2, 5, CLAIM_DEPOSIT, Some(15)))
+		);
+
+		run_to_block(15);
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+	});
+}
+
+#[test]
+fn create_claim_fails_when_expiry_block_is_full() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			H256::repeat_byte(1),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(11),
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			H256::repeat_byte(2),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(11),
+			None,
+			None
+		));
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				H256::repeat_byte(3),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				Some(11),
+				None,
+				None
+			),
+			Error::<Test>::TooManyExpiringInBlock
+		);
+	});
+}
+
+#[test]
+fn full_expiry_buckets_reject_more_claims_from_every_call() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let max: u32 = <Test as crate::Config>::MaxExpiringPerBlock::get();
+		// Claims made now with the default lifetime all expire in block 11.
+		let batch: Vec<_> = (1..=max as u8).map(H256::repeat_byte).collect();
+		assert_ok!(TemplateModule::create_claims(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			batch.clone().try_into().unwrap(),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			false
+		));
+		assert_eq!(ExpiringAt::<Test>::decode_len(11), Some(max as usize));
+
+		let late = H256::repeat_byte(0xff);
+		assert_noop!(
+			TemplateModule::create_claims(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				vec![late].try_into().unwrap(),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				false
+			),
+			Error::<Test>::TooManyExpiringInBlock
+		);
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				late,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				Some(11),
+				None,
+				None
+			),
+			Error::<Test>::TooManyExpiringInBlock
+		);
+
+		// Renewing a claim of the full bucket into it again only moves it.
+		assert_ok!(TemplateModule::renew_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			batch[0]
+		));
+		assert_eq!(ExpiringAt::<Test>::decode_len(11), Some(max as usize));
+
+		// Renewing any other claim into it fails, and leaves that claim as it was.
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			late,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(12),
+			None,
+			None
+		));
+		assert_noop!(
+			TemplateModule::renew_claim(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, late),
+			Error::<Test>::TooManyExpiringInBlock
+		);
+
+		// The bucket is drained in full once due.
+		run_to_block(11);
+		assert!(!ExpiringAt::<Test>::contains_key(11));
+		assert!(batch
+			.iter()
+			.all(|claim| !Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim)));
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn create_claim_rejects_expiry_in_the_past() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		for expiry in [4, 5] {
+			assert_noop!(
+				TemplateModule::create_claim(
+					RuntimeOrigin::signed(1),
+					DEFAULT_NAMESPACE,
+					H256::repeat_byte(1),
+					ClaimKind::Document,
+					HashAlgo::Blake2_256,
+					None,
+					Some(expiry),
+					None,
+					None
+				),
+				Error::<Test>::ExpiryInPast.with_weight(Weight::zero())
+			);
+		}
+	});
+}
+
+#[test]
+fn create_claim_schedules_the_requested_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (early, late, forever) =
+			(H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3));
+		for (claim, expiry) in [(early, Some(3)), (late, Some(20)), (forever, None)] {
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				expiry,
+				None,
+				None
+			));
+		}
+		assert_eq!(ExpiringAt::<Test>::get(3).into_inner(), vec![(DEFAULT_NAMESPACE, early)]);
+		assert_eq!(ExpiringAt::<Test>::get(20).into_inner(), vec![(DEFAULT_NAMESPACE, late)]);
+		assert_eq!(
+			Claims::<Test>::get(DEFAULT_NAMESPACE, forever),
+			Some(claim_info(2, 1, 1, CLAIM_DEPOSIT, None))
+		);
+		// Nothing is scheduled after the global lifetime.
+		assert!(!ExpiringAt::<Test>::contains_key(11));
+
+		run_to_block(3);
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, early));
+		assert!(Claims::<Test>::contains_key(DEFAULT_NAMESPACE, late));
+
+		run_to_block(20);
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, late));
+		assert!(Claims::<Test>::contains_key(DEFAULT_NAMESPACE, forever));
+		assert_ok!(TemplateModule::do_try_state(20));
+	});
+}
+
+#[test]
+fn revoking_reports_the_deposit_returned() {
+	let (genesis, claim) = (H256::repeat_byte(1), H256::repeat_byte(2));
+	let storage = RuntimeGenesisConfig {
+		system: Default::default(),
+		balances: pallet_balances::GenesisConfig { balances: vec![(1, INITIAL_BALANCE)] },
+		template_module: crate::GenesisConfig { initial_claims: vec![(genesis, 1, 0)] },
+	}
+	.build_storage()
+	.unwrap();
+
+	sp_io::TestExternalities::from(storage).execute_with(|| {
+		System::set_block_number(1);
+		// Genesis claims are made without a deposit.
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			genesis
+		));
+		System::assert_last_event(
+			Event::ClaimRevoked {
+				who: 1,
+				namespace: DEFAULT_NAMESPACE,
+				claim: genesis,
+				created_at: 0,
+				deposit_returned: 0,
+				id: 0,
+			}
+			.into(),
+		);
+
+		// The metadata deposit is returned along with the claim deposit.
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			Some(b"abcd".to_vec()),
+			None,
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		System::assert_last_event(
+			Event::ClaimRevoked {
+				who: 1,
+				namespace: DEFAULT_NAMESPACE,
+				claim,
+				created_at: 1,
+				deposit_returned: CLAIM_DEPOSIT + 4 * METADATA_DEPOSIT_PER_BYTE,
+				id: 1,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn claim_ids_are_assigned_in_sequence_and_never_reused() {
+	let (genesis, claim) = (H256::repeat_byte(1), H256::repeat_byte(2));
+	let storage = RuntimeGenesisConfig {
+		system: Default::default(),
+		balances: pallet_balances::GenesisConfig { balances: vec![(1, INITIAL_BALANCE)] },
+		template_module: crate::GenesisConfig { initial_claims: vec![(genesis, 1, 0)] },
+	}
+	.build_storage()
+	.unwrap();
+
+	sp_io::TestExternalities::from(storage).execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(Claims::<Test>::get(DEFAULT_NAMESPACE, genesis).unwrap().id, 0);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		System::assert_last_event(
+			Event::ClaimCreated {
+				who: 1,
+				namespace: DEFAULT_NAMESPACE,
+				claim,
+				kind: ClaimKind::Document,
+				algo: HashAlgo::Blake2_256,
+				id: 1,
+				cid: None,
+			}
+			.into(),
+		);
+
+		// Claiming the same hash again after revoking it gives it a new id.
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(Claims::<Test>::get(DEFAULT_NAMESPACE, claim).unwrap().id, 2);
+		assert_eq!(crate::NextClaimId::<Test>::get(), 3);
+
+		crate::NextClaimId::<Test>::put(u64::MAX);
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				H256::repeat_byte(3),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::ClaimIdsExhausted
+		);
+	});
+}
+
+#[test]
+fn claims_are_indexed_by_creation_block() {
+	new_test_ext().execute_with(|| {
+		let (first, second, third) =
+			(H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3));
+		System::set_block_number(1);
+		for (who, claim) in [(1, first), (2, second)] {
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(who),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				Some(5),
+				None,
+				None
+			));
+		}
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			third,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(
+			TemplateModule::claims_created_in_block(1),
+			vec![(DEFAULT_NAMESPACE, first), (DEFAULT_NAMESPACE, second)]
+		);
+		assert_eq!(TemplateModule::claims_created_in_block(2), vec![(DEFAULT_NAMESPACE, third)]);
+
+		// Changing owner does not move a claim out of the block it was created in.
+		assert_ok!(TemplateModule::transfer_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			first,
+			3
+		));
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			second
+		));
+		assert_eq!(TemplateModule::claims_created_in_block(1), vec![(DEFAULT_NAMESPACE, first)]);
+
+		run_to_block(5);
+		assert!(TemplateModule::claims_created_in_block(1).is_empty());
+		assert!(!crate::ClaimsByBlock::<Test>::contains_key(1));
+		assert_eq!(TemplateModule::claims_created_in_block(2), vec![(DEFAULT_NAMESPACE, third)]);
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn create_claim_fails_when_the_block_claim_limit_is_reached() {
+	new_test_ext().execute_with(|| {
+		MaxClaimsPerBlock::set(2);
+		System::set_block_number(1);
+		for (who, byte) in [(1, 1), (2, 2)] {
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(who),
+				DEFAULT_NAMESPACE,
+				H256::repeat_byte(byte),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		let claim = H256::repeat_byte(3);
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(3),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::BlockClaimLimitReached
+		);
+
+		// Revoking a claim frees its slot, as does moving to the next block.
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			H256::repeat_byte(1)
+		));
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(3),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(3),
+			DEFAULT_NAMESPACE,
+			H256::repeat_byte(4),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+	});
+}
+
+#[test]
+fn expiry_overflow_is_rejected() {
+	new_test_ext().execute_with(|| {
+		// `ClaimLifetime` is 10 in the mock, so claims made now would expire past `u64::MAX`.
+		System::set_block_number(u64::MAX - 5);
+		let claim = H256::repeat_byte(1);
+		assert_noop!(
+			TemplateModule::create_claims(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				vec![claim].try_into().unwrap(),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				false
+			),
+			Error::<Test>::ExpiryOverflow
+		);
+
+		// Claims that pick their own expiry are unaffected, but cannot be renewed.
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(u64::MAX),
+			None,
+			None
+		));
+		assert_noop!(
+			TemplateModule::renew_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::ExpiryOverflow
+		);
+		assert_eq!(
+			ExpiringAt::<Test>::get(u64::MAX).into_inner(),
+			vec![(DEFAULT_NAMESPACE, claim)]
+		);
+	});
+}
+
+#[test]
+fn claim_info_and_claims_of_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (first, second, other) =
+			(H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3));
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			first,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			second,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			other,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_eq!(TemplateModule::claim_info(DEFAULT_NAMESPACE, first), Some((1, 1)));
+		assert_eq!(TemplateModule::claim_info(DEFAULT_NAMESPACE, other), Some((2, 2)));
+		assert_eq!(TemplateModule::claim_info(DEFAULT_NAMESPACE, H256::repeat_byte(4)), None);
+
+		let mut claims = TemplateModule::claims_of(&1);
+		claims.sort();
+		assert_eq!(claims, vec![(DEFAULT_NAMESPACE, first), (DEFAULT_NAMESPACE, second)]);
+		assert_eq!(TemplateModule::claims_of(&3), vec![]);
+	});
+}
+
+#[test]
+fn claim_count_of_follows_creations_transfers_and_revocations() {
+	new_test_ext().execute_with(|| {
+		let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		assert_eq!(TemplateModule::claim_count_of(&1), 0);
+
+		create_claims_for(1, &[first, second]);
+		assert_eq!(TemplateModule::claim_count_of(&1), 2);
+
+		assert_ok!(TemplateModule::transfer_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			first,
+			2
+		));
+		assert_eq!(TemplateModule::claim_count_of(&1), 1);
+		assert_eq!(TemplateModule::claim_count_of(&2), 1);
+
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			second
+		));
+		assert_eq!(TemplateModule::claim_count_of(&1), 0);
+		// Accounts without claims are not left with a stored count of zero.
+		assert!(!crate::ClaimCountByOwner::<Test>::contains_key(1));
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn claims_of_paged_walks_the_owner_index() {
+	new_test_ext().execute_with(|| {
+		let claims: Vec<_> = (1..=3).map(H256::repeat_byte).collect();
+		for (block, claim) in claims.iter().enumerate() {
+			System::set_block_number(block as u64 + 1);
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				*claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		// The pages follow the order of the owner index.
+		let claims: Vec<_> =
+			TemplateModule::claims_of(&1).into_iter().map(|(_, claim)| claim).collect();
+
+		let (page, cursor) = TemplateModule::claims_of_paged(&1, DEFAULT_NAMESPACE, None, 2);
+		assert_eq!(page, claims[..2]);
+		assert_eq!(cursor, Some(claims[2]));
+		let (page, cursor) = TemplateModule::claims_of_paged(&1, DEFAULT_NAMESPACE, cursor, 2);
+		assert_eq!(page, claims[2..]);
+		assert_eq!(cursor, None);
+
+		// An empty page still points at the next claim.
+		assert_eq!(
+			TemplateModule::claims_of_paged(&1, DEFAULT_NAMESPACE, Some(claims[1]), 0),
+			(vec![], Some(claims[1]))
+		);
+		// Unknown cursors, other owners and other namespaces yield nothing.
+		assert_eq!(
+			TemplateModule::claims_of_paged(&1, DEFAULT_NAMESPACE, Some(H256::repeat_byte(9)), 2),
+			(vec![], None)
+		);
+		assert_eq!(TemplateModule::claims_of_paged(&2, DEFAULT_NAMESPACE, None, 2), (vec![], None));
+		assert_eq!(TemplateModule::claims_of_paged(&1, 7, None, 2), (vec![], None));
+	});
+}
+
+#[test]
+fn the_owner_index_holds_hundreds_of_claims() {
+	new_test_ext().execute_with(|| {
+		MaxClaimsPerAccount::set(1_000);
+		MaxClaimsPerBlock::set(1_000);
+		System::set_block_number(1);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 1, 10_000));
+		let mut claims: Vec<_> = (0..300u32)
+			.map(|i| {
+				let mut hash = [0u8; 32];
+				hash[..4].copy_from_slice(&(i + 1).to_le_bytes());
+				(DEFAULT_NAMESPACE, H256(hash))
+			})
+			.collect();
+		for (_, claim) in claims.iter() {
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				*claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		claims.sort();
+		assert_eq!(indexed_claims(1), claims);
+		assert_eq!(TemplateModule::claim_count_of(&1), 300);
+
+		// Walking the index page by page visits every claim once.
+		let mut paged = Vec::new();
+		let mut cursor = None;
+		loop {
+			let (page, next) = TemplateModule::claims_of_paged(&1, DEFAULT_NAMESPACE, cursor, 2);
+			paged.extend(page.into_iter().map(|claim| (DEFAULT_NAMESPACE, claim)));
+			match next {
+				Some(next) => cursor = Some(next),
+				None => break,
+			}
+		}
+		paged.sort();
+		assert_eq!(paged, claims);
+
+		// Moving half of the claims away keeps both indices exact.
+		for (_, claim) in claims.iter().step_by(2) {
+			assert_ok!(TemplateModule::transfer_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				*claim,
+				2
+			));
+		}
+		assert_eq!(TemplateModule::claim_count_of(&1), 150);
+		assert_eq!(TemplateModule::claim_count_of(&2), 150);
+		assert_eq!(indexed_claims(2), claims.iter().step_by(2).cloned().collect::<Vec<_>>());
+		for (_, claim) in claims.iter().skip(1).step_by(2) {
+			assert_ok!(TemplateModule::revoke_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				*claim
+			));
+		}
+		assert!(indexed_claims(1).is_empty());
+		assert_eq!(TemplateModule::claim_count_of(&1), 0);
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn claims_of_paged_caps_the_page_size() {
+	new_test_ext().execute_with(|| {
+		let claims: Vec<_> = (1..=3).map(H256::repeat_byte).collect();
+		for (block, claim) in claims.iter().enumerate() {
+			System::set_block_number(block as u64 + 1);
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				7,
+				*claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		let claims: Vec<_> =
+			TemplateModule::claims_of(&1).into_iter().map(|(_, claim)| claim).collect();
+
+		// `MaxQueryPageSize` is 2 in the mock.
+		let (page, cursor) = TemplateModule::claims_of_paged(&1, 7, None, u32::MAX);
+		assert_eq!(page, claims[..2]);
+		assert_eq!(cursor, Some(claims[2]));
+	});
+}
+
+#[test]
+fn iter_claims_lists_the_claims_of_every_namespace() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_claims_for(1, &[H256::repeat_byte(1)]);
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(2),
+			7,
+			H256::repeat_byte(2),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+
+		let mut claims: Vec<_> = TemplateModule::iter_claims().collect();
+		claims.sort();
+		assert_eq!(
+			claims,
+			vec![
+				(DEFAULT_NAMESPACE, H256::repeat_byte(1), (1, 1)),
+				(7, H256::repeat_byte(2), (2, 2))
+			]
+		);
+	});
+}
+
+#[test]
+fn all_claims_paged_walks_the_claims_of_every_namespace() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let mut expected = Vec::new();
+		for (who, namespace) in [(1u64, DEFAULT_NAMESPACE), (2, 7), (3, 9)] {
+			let claim = H256::repeat_byte(who as u8);
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(who),
+				namespace,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			));
+			expected.push((namespace, claim, who, 1));
+		}
+
+		// `MaxQueryPageSize` is 2 in the mock, so this takes two pages.
+		let (mut claims, cursor) = TemplateModule::all_claims_paged(None, u32::MAX);
+		assert_eq!(claims.len(), 2);
+		let (rest, next) = TemplateModule::all_claims_paged(cursor, u32::MAX);
+		assert_eq!(next, None);
+		claims.extend(rest);
+		claims.sort();
+		assert_eq!(claims, expected);
+
+		// A cursor names the namespace as well as the hash.
+		let (_, cursor) = TemplateModule::all_claims_paged(None, 1);
+		let (namespace, claim) = cursor.unwrap();
+		assert_eq!(
+			TemplateModule::all_claims_paged(Some((namespace + 1, claim)), 1),
+			(vec![], None)
+		);
+	});
+}
+
+#[test]
+fn claims_paged_walks_every_claim_of_the_namespace() {
+	new_test_ext().execute_with(|| {
+		let mut expected = Vec::new();
+		for who in 1..=3u64 {
+			System::set_block_number(who);
+			let claim = H256::repeat_byte(who as u8);
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(who),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			));
+			expected.push((claim, who, who));
+		}
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			7,
+			H256::repeat_byte(9),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// `MaxQueryPageSize` is 2 in the mock, so this takes two pages.
+		let (mut claims, cursor) = TemplateModule::claims_paged(DEFAULT_NAMESPACE, None, u32::MAX);
+		assert_eq!(claims.len(), 2);
+		let (rest, cursor) = TemplateModule::claims_paged(DEFAULT_NAMESPACE, cursor, u32::MAX);
+		assert_eq!(cursor, None);
+		claims.extend(rest);
+		claims.sort();
+		assert_eq!(claims, expected);
+
+		assert_eq!(
+			TemplateModule::claims_paged(DEFAULT_NAMESPACE, Some(H256::repeat_byte(9)), 2),
+			(vec![], None)
+		);
+		assert_eq!(
+			TemplateModule::claims_paged(7, None, 2),
+			(vec![(H256::repeat_byte(9), 1, 3)], None)
+		);
+	});
+}
+
+#[test]
+fn claim_exists_and_is_owner_work() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert!(!TemplateModule::claim_exists(DEFAULT_NAMESPACE, claim));
+		assert!(!TemplateModule::is_owner(DEFAULT_NAMESPACE, claim, 1));
+
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert!(TemplateModule::claim_exists(DEFAULT_NAMESPACE, claim));
+		assert!(TemplateModule::is_owner(DEFAULT_NAMESPACE, claim, 1));
+		assert!(!TemplateModule::is_owner(DEFAULT_NAMESPACE, claim, 2));
+		assert!(!TemplateModule::claim_exists(7, claim));
+		assert!(!TemplateModule::is_owner(7, claim, 1));
+	});
+}
+
+#[test]
+fn create_claim_records_the_hash_algorithm() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_eq!(TemplateModule::algo_of(DEFAULT_NAMESPACE, &claim), None);
+
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Keccak256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(TemplateModule::algo_of(DEFAULT_NAMESPACE, &claim), Some(HashAlgo::Keccak256));
+		System::assert_last_event(
+			Event::ClaimCreated {
+				who: 1,
+				namespace: DEFAULT_NAMESPACE,
+				claim,
+				kind: ClaimKind::Document,
+				algo: HashAlgo::Keccak256,
+				id: 0,
+				cid: None,
+			}
+			.into(),
+		);
+
+		// The algorithm is not part of the key, so the same bytes from another algorithm are
+		// still taken.
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Sha2_256,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::AlreadyClaimed.with_weight(read_weight())
+		);
+	});
+}
+
+#[test]
+fn ensure_owner_returns_the_claim_info_or_a_typed_error() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert!(matches!(
+			TemplateModule::ensure_owner(DEFAULT_NAMESPACE, &claim, &1),
+			Err(Error::<Test>::NoSuchClaim)
+		));
+
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(
+			TemplateModule::ensure_owner(DEFAULT_NAMESPACE, &claim, &1).ok(),
+			Claims::<Test>::get(DEFAULT_NAMESPACE, claim)
+		);
+		assert!(matches!(
+			TemplateModule::ensure_owner(DEFAULT_NAMESPACE, &claim, &2),
+			Err(Error::<Test>::NotClaimOwner)
+		));
+		assert!(matches!(
+			TemplateModule::ensure_owner(7, &claim, &1),
+			Err(Error::<Test>::NoSuchClaim)
+		));
+	});
+}
+
+// Have account 1 claim `claim` in the default namespace, locked until block `until`.
+fn create_locked_claim(claim: H256, until: u64) {
+	assert_ok!(TemplateModule::create_claim(
+		RuntimeOrigin::signed(1),
+		DEFAULT_NAMESPACE,
+		claim,
+		ClaimKind::Document,
+		HashAlgo::Blake2_256,
+		None,
+		None,
+		Some(until),
+		None,
+	));
+}
+
+#[test]
+fn revoke_claim_fails_until_the_lock_has_passed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		create_locked_claim(claim, 5);
+		assert_eq!(Claims::<Test>::get(DEFAULT_NAMESPACE, claim).unwrap().lock_until, Some(5));
+
+		for block in [4, 5] {
+			System::set_block_number(block);
+			assert_noop!(
+				TemplateModule::revoke_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, claim),
+				Error::<Test>::ClaimLocked
+			);
+		}
+
+		System::set_block_number(6);
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+	});
+}
+
+#[test]
+fn transfer_claim_fails_until_the_lock_has_passed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		create_locked_claim(claim, 5);
+
+		for block in [4, 5] {
+			System::set_block_number(block);
+			assert_noop!(
+				TemplateModule::transfer_claim(
+					RuntimeOrigin::signed(1),
+					DEFAULT_NAMESPACE,
+					claim,
+					2
+				),
+				Error::<Test>::ClaimLocked
+			);
+			assert_noop!(
+				TemplateModule::transfer_claim_bulk(
+					RuntimeOrigin::signed(1),
+					DEFAULT_NAMESPACE,
+					vec![claim].try_into().unwrap(),
+					2
+				),
+				Error::<Test>::ClaimLocked
+			);
+		}
+
+		System::set_block_number(6);
+		assert_ok!(TemplateModule::transfer_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			2
+		));
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &claim), Some(2));
+	});
+}
+
+#[test]
+fn revoke_all_skips_locked_claims() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (locked, unlocked) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		create_locked_claim(locked, 5);
+		create_claims_for(1, &[unlocked]);
+
+		assert_ok!(TemplateModule::revoke_all(RuntimeOrigin::signed(1)));
+		assert!(Claims::<Test>::contains_key(DEFAULT_NAMESPACE, locked));
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, unlocked));
+		System::assert_last_event(
+			Event::AllClaimsRevoked { who: 1, count: 1, remaining: 1 }.into(),
+		);
+	});
+}
+
+#[test]
+fn force_revoke_claim_ignores_the_lock() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		create_locked_claim(claim, 5);
+
+		assert_ok!(TemplateModule::force_revoke_claim(
+			RuntimeOrigin::root(),
+			DEFAULT_NAMESPACE,
+			claim,
+			false
+		));
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+	});
+}
+
+#[test]
+fn frozen_claims_reject_owner_actions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (frozen, free) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		create_claims_for(1, &[frozen, free]);
+
+		assert_noop!(
+			TemplateModule::freeze_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, frozen),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::freeze_claim(
+				RuntimeOrigin::root(),
+				DEFAULT_NAMESPACE,
+				H256::repeat_byte(3)
+			),
+			Error::<Test>::NoSuchClaim
+		);
+		assert_ok!(TemplateModule::freeze_claim(RuntimeOrigin::root(), DEFAULT_NAMESPACE, frozen));
+		System::assert_last_event(
+			Event::ClaimFrozen { namespace: DEFAULT_NAMESPACE, claim: frozen }.into(),
+		);
+		assert!(TemplateModule::is_frozen(DEFAULT_NAMESPACE, &frozen));
+
+		assert_noop!(
+			TemplateModule::revoke_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, frozen),
+			Error::<Test>::ClaimFrozen
+		);
+		assert_noop!(
+			TemplateModule::transfer_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, frozen, 2),
+			Error::<Test>::ClaimFrozen
+		);
+		assert_noop!(
+			TemplateModule::transfer_claim_bulk(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				vec![free, frozen].try_into().unwrap(),
+				2
+			),
+			Error::<Test>::ClaimFrozen
+		);
+		assert_noop!(
+			TemplateModule::set_claim_metadata(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				frozen,
+				Some(vec![1])
+			),
+			Error::<Test>::ClaimFrozen
+		);
+		assert_ok!(TemplateModule::approve_transfer(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			frozen,
+			2
+		));
+		assert_noop!(
+			TemplateModule::accept_transfer(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, frozen),
+			Error::<Test>::ClaimFrozen
+		);
+
+		// Other hashes are unaffected, and revoking everything leaves the frozen claim behind.
+		create_claims_for(2, &[H256::repeat_byte(3)]);
+		assert_ok!(TemplateModule::revoke_all(RuntimeOrigin::signed(1)));
+		System::assert_last_event(
+			Event::AllClaimsRevoked { who: 1, count: 1, remaining: 1 }.into(),
+		);
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &frozen), Some(1));
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &free), None);
+
+		assert_ok!(TemplateModule::thaw_claim(RuntimeOrigin::root(), DEFAULT_NAMESPACE, frozen));
+		System::assert_last_event(
+			Event::ClaimThawed { namespace: DEFAULT_NAMESPACE, claim: frozen }.into(),
+		);
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			frozen
+		));
+	});
+}
+
+#[test]
+fn frozen_claims_can_still_be_force_revoked() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		create_claims_for(1, &[claim]);
+		assert_ok!(TemplateModule::freeze_claim(RuntimeOrigin::root(), DEFAULT_NAMESPACE, claim));
+
+		assert_ok!(TemplateModule::force_revoke_claim(
+			RuntimeOrigin::root(),
+			DEFAULT_NAMESPACE,
+			claim,
+			false
+		));
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		assert!(!TemplateModule::is_frozen(DEFAULT_NAMESPACE, &claim));
+
+		// The freeze does not carry over to whoever claims the hash next.
+		create_claims_for(2, &[claim]);
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn revoked_claims_leave_a_tombstone_until_restored() {
+	use frame_support::traits::fungible::InspectHold;
+
+	new_test_ext().execute_with(|| {
+		TombstoneRevoked::set(true);
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		create_claims_for(1, &[claim]);
+		System::set_block_number(3);
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		assert_eq!(Balances::total_balance_on_hold(&1), 0);
+		assert_eq!(
+			TemplateModule::revoked_claim(DEFAULT_NAMESPACE, &claim),
+			Some(RevokedClaim {
+				owner: 1,
+				created_at: 1,
+				revoked_at: 3,
+				kind: ClaimKind::Document,
+				algo: HashAlgo::Blake2_256,
+			})
+		);
+
+		// Nobody can claim the hash again, its former owner included.
+		for who in [1, 2] {
+			assert_noop!(
+				TemplateModule::create_claim(
+					RuntimeOrigin::signed(who),
+					DEFAULT_NAMESPACE,
+					claim,
+					ClaimKind::Document,
+					HashAlgo::Blake2_256,
+					None,
+					None,
+					None,
+					None
+				),
+				Error::<Test>::ClaimTombstoned
+			);
+		}
+		assert_noop!(
+			TemplateModule::create_pending_claim(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				claim,
+				3
+			),
+			Error::<Test>::ClaimTombstoned
+		);
+		// The tombstone is per namespace.
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(2),
+			7,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_noop!(
+			TemplateModule::restore_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, claim),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::restore_claim(RuntimeOrigin::root(), DEFAULT_NAMESPACE, claim));
+		System::assert_last_event(
+			Event::ClaimRestored { who: 1, namespace: DEFAULT_NAMESPACE, claim }.into(),
+		);
+		assert_eq!(TemplateModule::claim_info(DEFAULT_NAMESPACE, claim), Some((1, 3)));
+		assert_eq!(Balances::total_balance_on_hold(&1), CLAIM_DEPOSIT);
+		assert_eq!(TemplateModule::revoked_claim(DEFAULT_NAMESPACE, &claim), None);
+		assert_noop!(
+			TemplateModule::restore_claim(RuntimeOrigin::root(), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NotTombstoned
+		);
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn owners_can_attach_a_note_to_their_claims() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		create_claims_for(1, &[claim]);
+		let note: ClaimNoteOf<Test> = b"pay:alice".to_vec().try_into().unwrap();
+		let info = Claims::<Test>::get(DEFAULT_NAMESPACE, claim);
+
+		assert_noop!(
+			TemplateModule::set_owner_note(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				claim,
+				Some(note.to_vec())
+			),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(TemplateModule::set_owner_note(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			Some(note.to_vec())
+		));
+		System::assert_last_event(
+			Event::OwnerNoteSet { namespace: DEFAULT_NAMESPACE, claim, note: Some(note.clone()) }
+				.into(),
+		);
+		assert_eq!(TemplateModule::note_of(DEFAULT_NAMESPACE, &claim), Some(note.clone()));
+		// The claim itself is left as it was.
+		assert_eq!(Claims::<Test>::get(DEFAULT_NAMESPACE, claim), info);
+
+		assert_ok!(TemplateModule::set_owner_note(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			None
+		));
+		System::assert_last_event(
+			Event::OwnerNoteSet { namespace: DEFAULT_NAMESPACE, claim, note: None }.into(),
+		);
+		assert_eq!(TemplateModule::note_of(DEFAULT_NAMESPACE, &claim), None);
+
+		// The note goes with the claim, and with its owner.
+		assert_ok!(TemplateModule::set_owner_note(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			Some(note.to_vec())
+		));
+		assert_ok!(TemplateModule::transfer_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			2
+		));
+		assert_eq!(TemplateModule::note_of(DEFAULT_NAMESPACE, &claim), None);
+		assert_ok!(TemplateModule::set_owner_note(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			claim,
+			Some(note.to_vec())
+		));
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert_eq!(TemplateModule::note_of(DEFAULT_NAMESPACE, &claim), None);
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn set_owner_note_rejects_notes_that_are_too_long() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		create_claims_for(1, &[claim]);
+
+		assert_noop!(
+			TemplateModule::set_owner_note(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				Some(vec![0; 17])
+			),
+			Error::<Test>::NoteTooLong
+		);
+		assert_ok!(TemplateModule::set_owner_note(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			Some(vec![0; 16])
+		));
+	});
+}
+
+#[test]
+fn revoke_claim_by_preimage_revokes_the_claim_on_the_data() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let data = b"the document".to_vec();
+		let claim = <Test as frame_system::Config>::Hashing::hash(&data);
+		create_claims_for(1, &[claim]);
+		assert_eq!(TemplateModule::claim_of_preimage(&data), Some(claim));
+
+		assert_ok!(TemplateModule::revoke_claim_by_preimage(RuntimeOrigin::signed(1), data));
+		assert_eq!(Claims::<Test>::get(DEFAULT_NAMESPACE, claim), None);
+		System::assert_last_event(
+			Event::ClaimRevoked {
+				namespace: DEFAULT_NAMESPACE,
+				who: 1,
+				claim,
+				created_at: 1,
+				deposit_returned: CLAIM_DEPOSIT,
+				id: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn revoke_claim_by_preimage_fails_for_other_data_or_owners() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let data = b"the document".to_vec();
+		create_claims_for(1, &[<Test as frame_system::Config>::Hashing::hash(&data)]);
+
+		assert_noop!(
+			TemplateModule::revoke_claim_by_preimage(
+				RuntimeOrigin::signed(1),
+				b"another document".to_vec()
+			),
+			Error::<Test>::NoSuchClaim
+		);
+		assert_noop!(
+			TemplateModule::revoke_claim_by_preimage(RuntimeOrigin::signed(2), data),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			TemplateModule::revoke_claim_by_preimage(RuntimeOrigin::signed(1), vec![0; 65]),
+			Error::<Test>::PreimageTooLong
+		);
+	});
+}
+
+#[test]
+fn committed_claims_can_be_revealed_after_the_delay() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		let salt = [7; 32];
+		let commitment = TemplateModule::commitment_of(&1, DEFAULT_NAMESPACE, &claim, &salt);
+		assert_ok!(TemplateModule::commit_claim(RuntimeOrigin::signed(1), commitment));
+		System::assert_last_event(Event::ClaimCommitted { who: 1, commitment }.into());
+		assert_eq!(TemplateModule::commitment(&1), Some((commitment, 1)));
+
+		// The hash is not disclosed, so it is only claimed once it is revealed.
+		assert!(!TemplateModule::claim_exists(DEFAULT_NAMESPACE, claim));
+		System::set_block_number(4);
+		assert_ok!(TemplateModule::reveal_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Image,
+			HashAlgo::Sha2_256,
+			salt
+		));
+		System::assert_has_event(
+			Event::ClaimCreated {
+				who: 1,
+				namespace: DEFAULT_NAMESPACE,
+				claim,
+				kind: ClaimKind::Image,
+				algo: HashAlgo::Sha2_256,
+				id: 0,
+				cid: None,
+			}
+			.into(),
+		);
+		System::assert_last_event(
+			Event::ClaimRevealed { who: 1, namespace: DEFAULT_NAMESPACE, claim }.into(),
+		);
+		assert_eq!(TemplateModule::claim_info(DEFAULT_NAMESPACE, claim), Some((1, 4)));
+		assert_eq!(TemplateModule::commitment(&1), None);
+
+		// The commitment is used up.
+		assert_noop!(
+			TemplateModule::reveal_claim(
+				RuntimeOrigin::signed(1),
+				7,
+				claim,
+				ClaimKind::Image,
+				HashAlgo::Sha2_256,
+				salt
+			),
+			Error::<Test>::CommitmentNotFound
+		);
+	});
+}
+
+#[test]
+fn reveal_claim_checks_the_commitment() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		let salt = [7; 32];
+		let reveal = |who, claim, salt| {
+			TemplateModule::reveal_claim(
+				RuntimeOrigin::signed(who),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				salt,
+			)
+		};
+		assert_noop!(reveal(1, claim, salt), Error::<Test>::CommitmentNotFound);
+
+		assert_ok!(TemplateModule::commit_claim(
+			RuntimeOrigin::signed(1),
+			TemplateModule::commitment_of(&1, DEFAULT_NAMESPACE, &claim, &salt)
+		));
+		System::set_block_number(3);
+		assert_noop!(reveal(1, claim, salt), Error::<Test>::RevealTooEarly);
+
+		System::set_block_number(4);
+		assert_noop!(reveal(1, claim, [8; 32]), Error::<Test>::BadReveal);
+		assert_noop!(reveal(1, H256::repeat_byte(2), salt), Error::<Test>::BadReveal);
+		// Knowing the claim and salt is not enough without a commitment of one's own.
+		assert_noop!(reveal(2, claim, salt), Error::<Test>::CommitmentNotFound);
+		// The commitment names the namespace it is revealed in.
+		assert_noop!(
+			TemplateModule::reveal_claim(
+				RuntimeOrigin::signed(1),
+				7,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				salt
+			),
+			Error::<Test>::BadReveal
+		);
+
+		// It also names the committer, so a copy of it made by another account is worthless.
+		let (commitment, _) = TemplateModule::commitment(&1).unwrap();
+		assert_ok!(TemplateModule::commit_claim(RuntimeOrigin::signed(2), commitment));
+		System::set_block_number(7);
+		assert_noop!(reveal(2, claim, salt), Error::<Test>::BadReveal);
+	});
+}
+
+#[test]
+fn claims_made_after_a_commitment_give_way_to_its_reveal() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (claim, earlier) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		let salt = [7; 32];
+		create_claims_for(3, &[earlier]);
+		for (who, claim) in [(1, claim), (2, earlier)] {
+			assert_ok!(TemplateModule::commit_claim(
+				RuntimeOrigin::signed(who),
+				TemplateModule::commitment_of(&who, DEFAULT_NAMESPACE, &claim, &salt)
+			));
+		}
+		let reveal = |who, claim| {
+			TemplateModule::reveal_claim(
+				RuntimeOrigin::signed(who),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				salt,
+			)
+		};
+
+		// A front-runner who sees the reveal coming claims the hash directly.
+		System::set_block_number(2);
+		create_claims_for(2, &[claim]);
+		assert_eq!(Balances::reserved_balance(2), CLAIM_DEPOSIT);
+
+		System::set_block_number(4);
+		assert_ok!(reveal(1, claim));
+		System::assert_has_event(
+			Event::ClaimSuperseded { namespace: DEFAULT_NAMESPACE, claim, by_owner: 2, id: 1 }
+				.into(),
+		);
+		assert_eq!(TemplateModule::claim_info(DEFAULT_NAMESPACE, claim), Some((1, 4)));
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		// A claim made no later than the commitment still stands.
+		assert_noop!(reveal(2, earlier), Error::<Test>::AlreadyClaimed);
+
+		// The revealed claim counts as made at its commitment, so a later commitment cannot
+		// take it over in turn.
+		assert_ok!(TemplateModule::commit_claim(
+			RuntimeOrigin::signed(2),
+			TemplateModule::commitment_of(&2, DEFAULT_NAMESPACE, &claim, &salt)
+		));
+		System::set_block_number(7);
+		assert_noop!(reveal(2, claim), Error::<Test>::AlreadyClaimed);
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn a_new_commitment_replaces_the_previous_one() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		let salt = [7; 32];
+		assert_ok!(TemplateModule::commit_claim(
+			RuntimeOrigin::signed(1),
+			TemplateModule::commitment_of(&1, DEFAULT_NAMESPACE, &first, &salt)
+		));
+		System::set_block_number(4);
+		assert_ok!(TemplateModule::commit_claim(
+			RuntimeOrigin::signed(1),
+			TemplateModule::commitment_of(&1, DEFAULT_NAMESPACE, &second, &salt)
+		));
+
+		// The delay starts over with the new commitment.
+		let reveal = |claim| {
+			TemplateModule::reveal_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				salt,
+			)
+		};
+		assert_noop!(reveal(second), Error::<Test>::RevealTooEarly);
+		System::set_block_number(7);
+		assert_noop!(reveal(first), Error::<Test>::BadReveal);
+		assert_ok!(reveal(second));
+	});
+}
+
+#[test]
+fn only_owner_revocations_leave_a_tombstone() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+
+		// Without the flag, revoked hashes can be claimed again straight away.
+		create_claims_for(1, &[claim]);
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert_eq!(TemplateModule::revoked_claim(DEFAULT_NAMESPACE, &claim), None);
+		create_claims_for(2, &[claim]);
+
+		// With it, force-revoked claims are still removed for good.
+		TombstoneRevoked::set(true);
+		assert_ok!(TemplateModule::force_revoke_claim(
+			RuntimeOrigin::root(),
+			DEFAULT_NAMESPACE,
+			claim,
+			false
+		));
+		assert_eq!(TemplateModule::revoked_claim(DEFAULT_NAMESPACE, &claim), None);
+		create_claims_for(2, &[claim]);
+
+		// Whereas every claim revoked in bulk leaves one.
+		assert_ok!(TemplateModule::revoke_all(RuntimeOrigin::signed(2)));
+		assert!(TemplateModule::revoked_claim(DEFAULT_NAMESPACE, &claim).is_some());
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn resolve_claim_reports_the_age_at_the_current_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		let claim = H256::repeat_byte(1);
+		assert_eq!(TemplateModule::resolve_claim(DEFAULT_NAMESPACE, claim), None);
+		create_claims_for(1, &[claim]);
+		assert_eq!(TemplateModule::resolve_claim(DEFAULT_NAMESPACE, claim), Some((1, 3, 0)));
+
+		System::set_block_number(10);
+		assert_eq!(TemplateModule::resolve_claim(DEFAULT_NAMESPACE, claim), Some((1, 3, 7)));
+		assert_eq!(TemplateModule::resolve_claim(7, claim), None);
+	});
+}
+
+#[test]
+fn create_claim_fails_when_account_owns_too_many_claims() {
+	new_test_ext().execute_with(|| {
+		for block in 1..=3 {
+			System::set_block_number(block);
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				H256::repeat_byte(block as u8),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				H256::repeat_byte(4),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::TooManyClaims
+		);
+		// Other accounts are not affected by the cap.
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			H256::repeat_byte(4),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+	});
+}
+
+#[test]
+fn owner_index_follows_claims() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			first,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(11),
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			second,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(11),
+			None,
+			None
+		));
+		assert_eq!(
+			indexed_claims(1),
+			vec![(DEFAULT_NAMESPACE, first), (DEFAULT_NAMESPACE, second)]
+		);
+
+		assert_ok!(TemplateModule::transfer_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			first,
+			2
+		));
+		assert_eq!(indexed_claims(1), vec![(DEFAULT_NAMESPACE, second)]);
+		assert_eq!(indexed_claims(2), vec![(DEFAULT_NAMESPACE, first)]);
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			second
+		));
+		assert!(indexed_claims(1).is_empty());
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+
+		run_to_block(11);
+		assert!(indexed_claims(2).is_empty());
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn transfer_claim_fails_when_destination_owns_too_many_claims() {
+	new_test_ext().execute_with(|| {
+		for block in 1..=3 {
+			System::set_block_number(block);
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				H256::repeat_byte(block as u8),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		let claim = H256::repeat_byte(4);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			TemplateModule::transfer_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, claim, 2),
+			Error::<Test>::TooManyClaims
+		);
+	});
+}
+
+#[test]
+fn try_state_detects_an_out_of_sync_owner_index() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		ClaimsByOwner::<Test>::remove(1, (DEFAULT_NAMESPACE, claim));
+		assert!(TemplateModule::do_try_state(System::block_number()).is_err());
+	});
+}
+
+#[test]
+fn create_and_revoke_claim_reserve_and_return_the_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(1), CLAIM_DEPOSIT);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - CLAIM_DEPOSIT);
+
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn create_claim_fails_without_enough_balance_for_the_deposit() {
+	new_test_ext().execute_with(|| {
+		// Account 4 has no funds at all.
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(4),
+				DEFAULT_NAMESPACE,
+				H256::repeat_byte(1),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn revoke_claim_returns_the_stored_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		// Pretend the claim was made while the deposit was lower; only that much may be returned.
+		Claims::<Test>::mutate(DEFAULT_NAMESPACE, claim, |info| info.as_mut().unwrap().deposit = 4);
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert_eq!(Balances::reserved_balance(1), CLAIM_DEPOSIT - 4);
+	});
+}
+
+#[test]
+fn revoke_claim_sends_a_deposit_too_small_to_revive_the_owner_to_the_dust_destination() {
+	use frame_support::traits::fungible::Mutate;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (kept, revoked) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		for claim in [kept, revoked] {
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		// Drain the owner down to the existential deposit, then raise it so that the returned
+		// deposit is not enough to bring the free balance back above it. The deposit held for the
+		// other claim keeps the account from being reaped on the spot.
+		Balances::set_balance(&1, 0);
+		assert_eq!(Balances::free_balance(1), 1);
+		ExistentialDeposit::set(1 + CLAIM_DEPOSIT + 1);
+		Balances::set_balance(&TREASURY, INITIAL_BALANCE);
+		let issuance = Balances::total_issuance();
+
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			revoked
+		));
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, revoked));
+		// The deposit is not lost, but moved to the dust destination.
+		assert_eq!(Balances::free_balance(1), 1);
+		assert_eq!(Balances::reserved_balance(1), CLAIM_DEPOSIT);
+		assert_eq!(Balances::free_balance(TREASURY), INITIAL_BALANCE + CLAIM_DEPOSIT);
+		assert_eq!(Balances::total_issuance(), issuance);
+		System::assert_has_event(
+			Event::ClaimDepositDusted {
+				namespace: DEFAULT_NAMESPACE,
+				claim: revoked,
+				amount: CLAIM_DEPOSIT,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn deposits_are_held_under_the_claim_deposit_reason() {
+	use frame_support::traits::fungible::InspectHold;
+
+	new_test_ext().execute_with(|| {
+		let reason = RuntimeHoldReason::TemplateModule(HoldReason::ClaimDeposit);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(Balances::balance_on_hold(&reason, &1), CLAIM_DEPOSIT);
+		assert_eq!(Balances::total_balance_on_hold(&1), CLAIM_DEPOSIT);
+
+		assert_ok!(TemplateModule::transfer_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			2
+		));
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 0);
+		assert_eq!(Balances::balance_on_hold(&reason, &2), CLAIM_DEPOSIT);
+
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert_eq!(Balances::balance_on_hold(&reason, &2), 0);
+	});
+}
+
+#[test]
+fn transfer_claim_moves_the_deposit_to_the_new_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::transfer_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			2
+		));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - CLAIM_DEPOSIT);
+		assert_eq!(Balances::reserved_balance(2), CLAIM_DEPOSIT);
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE + CLAIM_DEPOSIT);
+	});
+}
+
+#[test]
+fn expired_claims_return_the_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			H256::repeat_byte(1),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(11),
+			None,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(1), CLAIM_DEPOSIT);
+		run_to_block(11);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn force_revoke_claim_works_regardless_of_owner() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			Some(b"illegal".to_vec()),
+			None,
+			None,
+			None
+		));
+
+		assert_ok!(TemplateModule::force_revoke_claim(
+			RuntimeOrigin::root(),
+			DEFAULT_NAMESPACE,
+			claim,
+			false
+		));
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		assert!(!ClaimMetadata::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		assert!(indexed_claims(1).is_empty());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+		System::assert_last_event(
+			Event::ClaimForceRevoked { namespace: DEFAULT_NAMESPACE, claim, by_owner: 1, id: 0 }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn force_revoke_claim_can_slash_the_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		let metadata = b"illegal".to_vec();
+		let deposit = CLAIM_DEPOSIT + METADATA_DEPOSIT_PER_BYTE * metadata.len() as u64;
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			Some(metadata),
+			None,
+			None,
+			None
+		));
+		let issuance = Balances::total_issuance();
+
+		assert_ok!(TemplateModule::force_revoke_claim(
+			RuntimeOrigin::root(),
+			DEFAULT_NAMESPACE,
+			claim,
+			true
+		));
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		assert!(!ClaimMetadata::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		assert_eq!(TemplateModule::claim_count(), 0);
+		// The deposit is moved to the slash destination rather than returned.
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - deposit);
+		assert_eq!(Balances::free_balance(TREASURY), deposit);
+		assert_eq!(Balances::total_issuance(), issuance);
+		System::assert_has_event(
+			Event::ClaimDepositSlashed { namespace: DEFAULT_NAMESPACE, claim, amount: deposit }
+				.into(),
+		);
+		System::assert_last_event(
+			Event::ClaimForceRevoked { namespace: DEFAULT_NAMESPACE, claim, by_owner: 1, id: 0 }
+				.into(),
+		);
+		assert_ok!(TemplateModule::do_try_state(1));
+	});
+}
+
+#[test]
+fn force_revoke_claim_requires_force_origin() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		// Not even the owner may use the forced variant.
+		assert_noop!(
+			TemplateModule::force_revoke_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				false
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::force_revoke_claim(
+				RuntimeOrigin::root(),
+				DEFAULT_NAMESPACE,
+				H256::repeat_byte(2),
+				false
+			),
+			Error::<Test>::NoSuchClaim
+		);
+	});
+}
+
+#[test]
+fn create_claims_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		assert_ok!(TemplateModule::create_claims(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			vec![first, second].try_into().unwrap(),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			false
+		));
+		assert_eq!(
+			indexed_claims(1),
+			vec![(DEFAULT_NAMESPACE, first), (DEFAULT_NAMESPACE, second)]
+		);
+		assert_eq!(Balances::reserved_balance(1), 2 * CLAIM_DEPOSIT);
+
+		let events: Vec<_> = System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::TemplateModule(event) => Some(event),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(
+			events,
+			vec![
+				Event::ClaimCreated {
+					namespace: DEFAULT_NAMESPACE,
+					who: 1,
+					claim: first,
+					kind: ClaimKind::Document,
+					algo: HashAlgo::Blake2_256,
+					id: 0,
+					cid: None,
+				},
+				Event::ClaimCreated {
+					namespace: DEFAULT_NAMESPACE,
+					who: 1,
+					claim: second,
+					kind: ClaimKind::Document,
+					algo: HashAlgo::Blake2_256,
+					id: 1,
+					cid: None,
+				},
+				Event::ClaimsBatchCreated { namespace: DEFAULT_NAMESPACE, who: 1, count: 2 },
+			]
+		);
+	});
+}
+
+#[test]
+fn revoke_all_revokes_claims_in_every_namespace() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			first,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			Some(vec![1, 2, 3]),
+			None,
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			7,
+			second,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			second,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		System::reset_events();
+
+		assert_ok!(TemplateModule::revoke_all(RuntimeOrigin::signed(1)));
+
+		assert!(TemplateModule::claims_of(&1).is_empty());
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, first));
+		assert!(!ClaimMetadata::<Test>::contains_key(DEFAULT_NAMESPACE, first));
+		assert!(!Claims::<Test>::contains_key(7, second));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		// The claims of other accounts are left alone.
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &second), Some(2));
+		// The claims are revoked in the order of the owner index.
+		assert_eq!(System::events().len(), 3);
+		System::assert_has_event(
+			Event::ClaimRevoked {
+				who: 1,
+				namespace: DEFAULT_NAMESPACE,
+				claim: first,
+				created_at: 1,
+				deposit_returned: CLAIM_DEPOSIT + 3 * METADATA_DEPOSIT_PER_BYTE,
+				id: 0,
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			Event::ClaimRevoked {
+				who: 1,
+				namespace: 7,
+				claim: second,
+				created_at: 1,
+				deposit_returned: CLAIM_DEPOSIT,
+				id: 1,
+			}
+			.into(),
+		);
+		System::assert_last_event(
+			Event::AllClaimsRevoked { who: 1, count: 2, remaining: 0 }.into(),
+		);
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn revoke_all_is_bounded_by_max_batch_size() {
+	new_test_ext().execute_with(|| {
+		for block in 1..=3 {
+			System::set_block_number(block);
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				H256::repeat_byte(block as u8),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+
+		// Only the claims revoked are charged for, along with their history.
+		let weight = |count: u32| revoke_all_weight(count, 0);
+
+		// `MaxBatchSize` is 2 in the mock.
+		let post_info = TemplateModule::revoke_all(RuntimeOrigin::signed(1)).unwrap();
+		assert_eq!(post_info.actual_weight, Some(weight(2)));
+		System::assert_last_event(
+			Event::AllClaimsRevoked { who: 1, count: 2, remaining: 1 }.into(),
+		);
+		assert_eq!(TemplateModule::claims_of(&1).len(), 1);
+
+		let post_info = TemplateModule::revoke_all(RuntimeOrigin::signed(1)).unwrap();
+		assert_eq!(post_info.actual_weight, Some(weight(1)));
+		System::assert_last_event(
+			Event::AllClaimsRevoked { who: 1, count: 1, remaining: 0 }.into(),
+		);
+		assert!(TemplateModule::claims_of(&1).is_empty());
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		// Nothing left to revoke.
+		let post_info = TemplateModule::revoke_all(RuntimeOrigin::signed(1)).unwrap();
+		assert_eq!(post_info.actual_weight, Some(weight(0)));
+		System::assert_last_event(
+			Event::AllClaimsRevoked { who: 1, count: 0, remaining: 0 }.into(),
+		);
+	});
+}
+
+// The weight of `revoke_all` revoking `count` claims and skipping `skipped`, with the history of
+// the claims revoked.
+fn revoke_all_weight(count: u32, skipped: u32) -> Weight {
+	use crate::weights::WeightInfo;
+
+	<() as WeightInfo>::revoke_all(count, skipped)
+		.saturating_add(DbWeight::get().reads_writes(count.into(), count.into()))
+}
+
+#[test]
+fn revoke_all_goes_past_the_locked_claims() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MaxClaimsPerAccount::set(5);
+		let claims: Vec<_> = (1..=5).map(H256::repeat_byte).collect();
+		create_claims_for(1, &claims);
+		// Lock more claims than fit in a batch, among those the owner index yields first.
+		let order: Vec<_> =
+			ClaimsByOwner::<Test>::iter_key_prefix(1).map(|(_, claim)| claim).collect();
+		for claim in &order[..3] {
+			Claims::<Test>::mutate(DEFAULT_NAMESPACE, claim, |info| {
+				info.as_mut().unwrap().lock_until = Some(5);
+			});
+		}
+
+		let post_info = TemplateModule::revoke_all(RuntimeOrigin::signed(1)).unwrap();
+		System::assert_last_event(
+			Event::AllClaimsRevoked { who: 1, count: 2, remaining: 3 }.into(),
+		);
+		assert!(order[..3]
+			.iter()
+			.all(|claim| TemplateModule::claim_exists(DEFAULT_NAMESPACE, *claim)));
+		assert!(order[3..]
+			.iter()
+			.all(|claim| !TemplateModule::claim_exists(DEFAULT_NAMESPACE, *claim)));
+		// The claims skipped are charged for as well.
+		assert_eq!(post_info.actual_weight, Some(revoke_all_weight(2, 3)));
+	});
+}
+
+#[test]
+fn revoke_all_goes_past_the_frozen_claims() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MaxClaimsPerAccount::set(5);
+		let claims: Vec<_> = (1..=5).map(H256::repeat_byte).collect();
+		create_claims_for(1, &claims);
+		// Freeze more claims than fit in a batch, among those the owner index yields first.
+		let order: Vec<_> =
+			ClaimsByOwner::<Test>::iter_key_prefix(1).map(|(_, claim)| claim).collect();
+		for claim in &order[..3] {
+			assert_ok!(TemplateModule::freeze_claim(
+				RuntimeOrigin::root(),
+				DEFAULT_NAMESPACE,
+				*claim
+			));
+		}
+
+		let post_info = TemplateModule::revoke_all(RuntimeOrigin::signed(1)).unwrap();
+		System::assert_last_event(
+			Event::AllClaimsRevoked { who: 1, count: 2, remaining: 3 }.into(),
+		);
+		assert!(order[..3]
+			.iter()
+			.all(|claim| TemplateModule::is_frozen(DEFAULT_NAMESPACE, claim)));
+		assert!(order[3..]
+			.iter()
+			.all(|claim| !TemplateModule::claim_exists(DEFAULT_NAMESPACE, *claim)));
+		assert_eq!(post_info.actual_weight, Some(revoke_all_weight(2, 3)));
+	});
+}
+
+#[test]
+fn revoke_all_charges_in_proportion_to_the_claims_revoked() {
+	use frame_support::dispatch::{GetDispatchInfo, Pays};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let declared = crate::Call::<Test>::revoke_all {}.get_dispatch_info().weight;
+		let revoke_all = |who: u64| {
+			let post_info = TemplateModule::revoke_all(RuntimeOrigin::signed(who)).unwrap();
+			assert_eq!(post_info.pays_fee, Pays::Yes);
+			post_info.actual_weight.unwrap()
+		};
+
+		// Account 1 owns no claim, 2 owns one and 3 owns `MaxBatchSize`, which is 2 in the mock.
+		create_claims_for(2, &[H256::repeat_byte(1)]);
+		create_claims_for(3, &[H256::repeat_byte(2), H256::repeat_byte(3)]);
+		let (none, one, full) = (revoke_all(1), revoke_all(2), revoke_all(3));
+
+		assert!(none.all_lt(one));
+		assert!(one.all_lt(full));
+		// The call is declared with a full batch, and the rest of a full index to skip.
+		assert!(full.all_lt(declared));
+		assert_eq!(declared, revoke_all_weight(2, 1));
+		assert_eq!(one.saturating_sub(none), full.saturating_sub(one));
+	});
+}
+
+#[test]
+fn create_claims_skips_pending_and_tombstoned_hashes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		TombstoneRevoked::set(true);
+		let (tombstoned, pending, fresh) =
+			(H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3));
+		create_claims_for(2, &[tombstoned]);
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			tombstoned
+		));
+		assert_ok!(TemplateModule::create_pending_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			pending,
+			3
+		));
+		let batch = |claims: Vec<H256>, skip_existing| {
+			TemplateModule::create_claims(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claims.try_into().unwrap(),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				skip_existing,
+			)
+		};
+
+		assert_noop!(batch(vec![fresh, pending], false), Error::<Test>::AlreadyClaimed);
+		assert_noop!(batch(vec![fresh, tombstoned], false), Error::<Test>::ClaimTombstoned);
+		assert_ok!(batch(vec![tombstoned, fresh], true));
+		System::assert_last_event(
+			Event::ClaimsBatchPartiallyCreated {
+				who: 1,
+				namespace: DEFAULT_NAMESPACE,
+				created: 1,
+				skipped: vec![tombstoned].try_into().unwrap(),
+			}
+			.into(),
+		);
+		assert_ok!(batch(vec![pending], true));
+		System::assert_last_event(
+			Event::ClaimsBatchPartiallyCreated {
+				who: 1,
+				namespace: DEFAULT_NAMESPACE,
+				created: 0,
+				skipped: vec![pending].try_into().unwrap(),
+			}
+			.into(),
+		);
+		assert_eq!(TemplateModule::claims_of(&1), vec![(DEFAULT_NAMESPACE, fresh)]);
+	});
+}
+
+#[test]
+fn create_claims_takes_over_expired_claims() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		for claim in [first, second] {
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				Some(3),
+				None,
+				None
+			));
+		}
+
+		// The claims have expired, but the sweep has not removed them yet.
+		System::set_block_number(3);
+		for (claim, skip_existing, id) in [(first, true, 0), (second, false, 1)] {
+			let result = TemplateModule::create_claims(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				vec![claim].try_into().unwrap(),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				skip_existing,
+			);
+			assert_ok!(result);
+			System::assert_has_event(
+				Event::ClaimExpired { namespace: DEFAULT_NAMESPACE, claim, id }.into(),
+			);
+			System::assert_last_event(
+				Event::ClaimsBatchCreated { who: 1, namespace: DEFAULT_NAMESPACE, count: 1 }.into(),
+			);
+		}
+		let mut claims = TemplateModule::claims_of(&1);
+		claims.sort();
+		assert_eq!(claims, vec![(DEFAULT_NAMESPACE, first), (DEFAULT_NAMESPACE, second)]);
+		assert_eq!(TemplateModule::claims_of(&2), vec![]);
+		assert!(!ExpiringAt::<Test>::contains_key(3));
+	});
+}
+
+#[test]
+fn create_claims_can_skip_existing_claims() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			first,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::create_claims(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			vec![first, second].try_into().unwrap(),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			true
+		));
+		assert_eq!(TemplateModule::claims_of(&1), vec![(DEFAULT_NAMESPACE, second)]);
+		assert_eq!(TemplateModule::claims_of(&2), vec![(DEFAULT_NAMESPACE, first)]);
+		System::assert_has_event(
+			Event::ClaimCreated {
+				who: 1,
+				namespace: DEFAULT_NAMESPACE,
+				claim: second,
+				kind: ClaimKind::Document,
+				algo: HashAlgo::Blake2_256,
+				id: 1,
+				cid: None,
+			}
+			.into(),
+		);
+		System::assert_last_event(
+			Event::ClaimsBatchPartiallyCreated {
+				who: 1,
+				namespace: DEFAULT_NAMESPACE,
+				created: 1,
+				skipped: vec![first].try_into().unwrap(),
+			}
+			.into(),
+		);
+
+		// Duplicates within the batch are skipped too.
+		let third = H256::repeat_byte(3);
+		assert_ok!(TemplateModule::create_claims(
+			RuntimeOrigin::signed(3),
+			DEFAULT_NAMESPACE,
+			vec![third, third].try_into().unwrap(),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			true
+		));
+		System::assert_last_event(
+			Event::ClaimsBatchPartiallyCreated {
+				who: 3,
+				namespace: DEFAULT_NAMESPACE,
+				created: 1,
+				skipped: vec![third].try_into().unwrap(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn create_claims_fails_on_existing_claims_unless_skipped() {
+	new_test_ext().execute_with(|| {
+		let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			second,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			TemplateModule::create_claims(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				vec![first, second].try_into().unwrap(),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				false
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+	});
+}
+
+// Run the migrations from version 5 on that have not run yet, so that the invariants of the
+// current layout can be checked.
+fn finish_migrations() {
+	migrations::v6::MigrateV5ToV6::<Test, Balances>::on_runtime_upgrade();
+	migrations::v7::MigrateV6ToV7::<Test>::on_runtime_upgrade();
+	migrations::v8::MigrateV7ToV8::<Test>::on_runtime_upgrade();
+	migrations::v9::MigrateV8ToV9::<Test>::on_runtime_upgrade();
+	migrations::v10::MigrateV9ToV10::<Test>::on_runtime_upgrade();
+	migrations::v11::MigrateV10ToV11::<Test>::on_runtime_upgrade();
+	migrations::v12::MigrateV11ToV12::<Test>::on_runtime_upgrade();
+	migrations::v13::MigrateV12ToV13::<Test>::on_runtime_upgrade();
+}
+
+#[test]
+fn reindex_claims_indexes_legacy_claims_over_several_calls() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let indexed = H256::repeat_byte(9);
+		create_claims_for(1, &[indexed]);
+		// Claims written before the owner index existed.
+		let legacy =
+			[(H256::repeat_byte(1), 1), (H256::repeat_byte(2), 2), (H256::repeat_byte(3), 2)];
+		for (id, (claim, owner)) in (1..).zip(legacy) {
+			Claims::<Test>::insert(DEFAULT_NAMESPACE, claim, claim_info(id, owner, 0, 0, None));
+		}
+		crate::NextClaimId::<Test>::put(4);
+		crate::ClaimCount::<Test>::put(4);
+
+		// `MaxBatchSize` is 2, so a larger limit still visits two of the four claims.
+		assert_ok!(TemplateModule::reindex_claims(RuntimeOrigin::signed(3), 10));
+		assert!(crate::ReindexCursor::<Test>::exists());
+		assert_ok!(TemplateModule::reindex_claims(RuntimeOrigin::signed(3), 2));
+		assert!(!crate::ReindexCursor::<Test>::exists());
+
+		let reindexed: Vec<_> = System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::TemplateModule(Event::ClaimsReindexed { count, more }) =>
+					Some((count, more)),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(reindexed.iter().map(|(_, more)| *more).collect::<Vec<_>>(), vec![true, false]);
+		// The claim that was already indexed is not indexed twice.
+		assert_eq!(reindexed.iter().map(|(count, _)| count).sum::<u32>(), 3);
+		let mut claims = TemplateModule::claims_of(&1);
+		claims.sort();
+		assert_eq!(
+			claims,
+			vec![(DEFAULT_NAMESPACE, H256::repeat_byte(1)), (DEFAULT_NAMESPACE, indexed)]
+		);
+		assert_eq!(TemplateModule::claim_count_of(&1), 2);
+		assert_eq!(TemplateModule::claim_count_of(&2), 2);
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn migration_v0_to_v1_adds_a_zero_deposit() {
+	new_test_ext().execute_with(|| {
+		let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		StorageVersion::new(0).put::<TemplateModule>();
+		migrations::v1::v0::Claims::<Test>::insert(first, (1, 3));
+		migrations::v1::v0::Claims::<Test>::insert(second, (2, 4));
+
+		migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(migrations::v1::Claims::<Test>::get(first), Some((1, 3, 0)));
+		assert_eq!(migrations::v1::Claims::<Test>::get(second), Some((2, 4, 0)));
+		assert_eq!(TemplateModule::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn migration_v0_to_v1_does_not_run_twice() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(10),
+			None,
+			None
+		));
+		// The pallet is already at version 1, so the claim must be left alone.
+		StorageVersion::new(1).put::<TemplateModule>();
+		migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(
+			Claims::<Test>::get(DEFAULT_NAMESPACE, claim),
+			Some(claim_info(0, 1, 0, CLAIM_DEPOSIT, Some(10)))
+		);
+	});
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn migration_v0_to_v1_keeps_every_claim() {
+	use frame_support::traits::UncheckedOnRuntimeUpgrade;
+	type Migration = migrations::v1::InnerMigrateV0ToV1<Test>;
+
+	new_test_ext().execute_with(|| {
+		for seed in 1..=3 {
+			migrations::v1::v0::Claims::<Test>::insert(H256::repeat_byte(seed), (1, 0));
+		}
+		let state = Migration::pre_upgrade().unwrap();
+		Migration::on_runtime_upgrade();
+		assert_ok!(Migration::post_upgrade(state.clone()));
+
+		// Losing a claim fails the check.
+		migrations::v1::Claims::<Test>::remove(H256::repeat_byte(1));
+		assert!(Migration::post_upgrade(state).is_err());
+	});
+}
+
+#[test]
+fn try_state_detects_claims_from_the_future() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::do_try_state(5));
+		assert!(TemplateModule::do_try_state(4).is_err());
+	});
+}
+
+#[test]
+fn offchain_worker_mirrors_changed_claims() {
+	use crate::offchain;
+	use frame_support::traits::Hooks;
+	use sp_core::offchain::{testing::TestOffchainExt, OffchainDbExt, OffchainWorkerExt};
+	use sp_runtime::offchain::storage::StorageValueRef;
+
+	let mirrored = |claim: &H256| {
+		StorageValueRef::persistent(&offchain::claim_key::<Test>(DEFAULT_NAMESPACE, claim))
+			.get::<(u64, u64)>()
+			.unwrap()
+	};
+
+	let mut ext = new_test_ext();
+	let (offchain, _state) = TestOffchainExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			first,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			second,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		TemplateModule::offchain_worker(1);
+		assert_eq!(mirrored(&first), Some((1, 1)));
+		assert_eq!(mirrored(&second), Some((1, 1)));
+		assert_eq!(StorageValueRef::persistent(offchain::LAST_INDEXED_KEY).get(), Ok(Some(1u64)));
+
+		System::reset_events();
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::transfer_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			first,
+			2
+		));
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			second
+		));
+		TemplateModule::offchain_worker(2);
+		assert_eq!(mirrored(&first), Some((2, 1)));
+		assert_eq!(mirrored(&second), None);
+		assert_eq!(StorageValueRef::persistent(offchain::LAST_INDEXED_KEY).get(), Ok(Some(2u64)));
+	});
+}
+
+#[test]
+fn migration_v1_to_v2_records_scheduled_expiries() {
+	new_test_ext().execute_with(|| {
+		let (scheduled, unscheduled) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		StorageVersion::new(1).put::<TemplateModule>();
+		migrations::v1::Claims::<Test>::insert(scheduled, (1, 3, CLAIM_DEPOSIT));
+		assert_ok!(migrations::v4::ExpiringAt::<Test>::try_append(13, scheduled));
+		// E.g. a claim registered at genesis, which was never scheduled to expire.
+		migrations::v1::Claims::<Test>::insert(unscheduled, (2, 0, 0));
+
+		migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+		use migrations::v2::ClaimInfo;
+		assert_eq!(
+			migrations::v2::Claims::<Test>::get(scheduled),
+			Some(ClaimInfo {
+				owner: 1,
+				created_at: 3,
+				deposit: CLAIM_DEPOSIT,
+				expires_at: Some(13)
+			})
+		);
+		assert_eq!(
+			migrations::v2::Claims::<Test>::get(unscheduled),
+			Some(ClaimInfo { owner: 2, created_at: 0, deposit: 0, expires_at: None })
+		);
+		assert_eq!(TemplateModule::on_chain_storage_version(), 2);
+	});
+}
+
+#[test]
+fn renew_claim_reschedules_the_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(11),
+			None,
+			None
+		));
+
+		System::set_block_number(6);
+		assert_ok!(TemplateModule::renew_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, claim));
+		System::assert_last_event(
+			Event::ClaimRenewed { namespace: DEFAULT_NAMESPACE, claim, new_expiry: 16 }.into(),
+		);
+		assert!(!ExpiringAt::<Test>::contains_key(11));
+		assert_eq!(ExpiringAt::<Test>::get(16).into_inner(), vec![(DEFAULT_NAMESPACE, claim)]);
+		// The original block number and the deposit are kept.
+		assert_eq!(
+			Claims::<Test>::get(DEFAULT_NAMESPACE, claim),
+			Some(claim_info(0, 1, 1, CLAIM_DEPOSIT, Some(16)))
+		);
+		assert_ok!(TemplateModule::do_try_state(6));
+
+		run_to_block(15);
+		assert!(Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		run_to_block(16);
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+	});
+}
+
+#[test]
+fn renew_claim_schedules_claims_without_an_expiry() {
+	let claim = H256::repeat_byte(1);
+	let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig {
+		system: Default::default(),
+		balances: Default::default(),
+		template_module: crate::GenesisConfig { initial_claims: vec![(claim, 1, 0)] },
+	}
+	.build_storage()
+	.unwrap()
+	.into();
+	ext.execute_with(|| {
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::renew_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, claim));
+		assert_eq!(
+			Claims::<Test>::get(DEFAULT_NAMESPACE, claim),
+			Some(ClaimInfo {
+				kind: ClaimKind::Other(0),
+				algo: HashAlgo::Other(0),
+				..claim_info(0, 1, 0, 0, Some(12))
+			})
+		);
+		assert_eq!(ExpiringAt::<Test>::get(12).into_inner(), vec![(DEFAULT_NAMESPACE, claim)]);
+	});
+}
+
+#[test]
+fn claim_history_records_the_lifecycle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::renew_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, claim));
+		System::set_block_number(3);
+		assert_ok!(TemplateModule::transfer_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			2
+		));
+		assert_eq!(
+			ClaimHistory::<Test>::get(DEFAULT_NAMESPACE, claim).into_inner(),
+			vec![
+				(HistoryAction::Created, 1),
+				(HistoryAction::Renewed, 2),
+				(HistoryAction::Transferred, 3)
+			]
+		);
+
+		// The history is full, so the oldest entry makes room, and it outlives the claim.
+		System::set_block_number(4);
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert_eq!(
+			ClaimHistory::<Test>::get(DEFAULT_NAMESPACE, claim).into_inner(),
+			vec![
+				(HistoryAction::Renewed, 2),
+				(HistoryAction::Transferred, 3),
+				(HistoryAction::Revoked, 4)
+			]
+		);
+	});
+}
+
+#[test]
+fn claim_history_records_expiry_as_removal() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(5),
+			None,
+			None
+		));
+		run_to_block(5);
+		assert_eq!(
+			ClaimHistory::<Test>::get(DEFAULT_NAMESPACE, claim).into_inner(),
+			vec![(HistoryAction::Created, 1), (HistoryAction::Revoked, 5)]
+		);
+		// Other namespaces have their own history.
+		assert!(!ClaimHistory::<Test>::contains_key(1, claim));
+	});
+}
+
+#[test]
+fn claim_history_is_not_recorded_when_disabled() {
+	new_test_ext().execute_with(|| {
+		TrackHistory::set(false);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert!(!ClaimHistory::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+	});
+}
+#[test]
+fn renew_claim_fails_for_non_owner_or_missing_claim() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_noop!(
+			TemplateModule::renew_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NoSuchClaim
+		);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			TemplateModule::renew_claim(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NotClaimOwner
+		);
+	});
+}
+
+#[test]
+fn claim_count_tracks_the_number_of_claims() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (first, second, third) =
+			(H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3));
+		assert_eq!(TemplateModule::claim_count(), 0);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			first,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::create_claims(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			vec![second].try_into().unwrap(),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			false
+		));
+		assert_eq!(TemplateModule::claim_count(), 2);
+
+		assert_ok!(TemplateModule::transfer_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			first,
+			3
+		));
+		assert_eq!(TemplateModule::claim_count(), 2);
+
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(3),
+			DEFAULT_NAMESPACE,
+			first
+		));
+		assert_ok!(TemplateModule::force_revoke_claim(
+			RuntimeOrigin::root(),
+			DEFAULT_NAMESPACE,
+			second,
+			false
+		));
+		assert_eq!(TemplateModule::claim_count(), 0);
+
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			third,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(12),
+			None,
+			None
+		));
+		assert_eq!(TemplateModule::claim_count(), 1);
+		run_to_block(12);
+		assert_eq!(TemplateModule::claim_count(), 0);
+		assert_ok!(TemplateModule::do_try_state(12));
+	});
+}
+
+#[test]
+fn claim_changes_are_reported() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (first, second, third) =
+			(H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3));
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			first,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::create_claims(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			vec![second].try_into().unwrap(),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			false
+		));
+		// Transfers are not reported, but the revocation is reported for the new owner.
+		assert_ok!(TemplateModule::transfer_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			first,
+			3
+		));
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(3),
+			DEFAULT_NAMESPACE,
+			first
+		));
+		run_to_block(11);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			third,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				third,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::AlreadyClaimed.with_weight(read_weight())
+		);
+		assert_ok!(TemplateModule::force_revoke_claim(
+			RuntimeOrigin::root(),
+			DEFAULT_NAMESPACE,
+			third,
+			true
+		));
+
+		assert_eq!(
+			ClaimChanges::take(),
+			vec![
+				ClaimChange::Created(DEFAULT_NAMESPACE, 1, first),
+				ClaimChange::Created(DEFAULT_NAMESPACE, 2, second),
+				ClaimChange::Revoked(DEFAULT_NAMESPACE, 3, first),
+				ClaimChange::Revoked(DEFAULT_NAMESPACE, 2, second),
+				ClaimChange::Created(DEFAULT_NAMESPACE, 1, third),
+				ClaimChange::Revoked(DEFAULT_NAMESPACE, 1, third),
+			]
+		);
+	});
+}
+#[test]
+fn claim_count_includes_genesis_claims() {
+	let storage = RuntimeGenesisConfig {
+		system: Default::default(),
+		balances: Default::default(),
+		template_module: crate::GenesisConfig {
+			initial_claims: vec![(H256::repeat_byte(1), 1, 0), (H256::repeat_byte(2), 2, 0)],
+		},
+	}
+	.build_storage()
+	.unwrap();
+	sp_io::TestExternalities::from(storage).execute_with(|| {
+		assert_eq!(TemplateModule::claim_count(), 2);
+	});
+}
+
+#[test]
+fn migration_v2_to_v3_counts_existing_claims() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(2).put::<TemplateModule>();
+		for (byte, owner) in [(1, 1), (2, 2)] {
+			let old =
+				migrations::v2::ClaimInfo { owner, created_at: 0, deposit: 0, expires_at: None };
+			migrations::v2::Claims::<Test>::insert(H256::repeat_byte(byte), old);
+		}
+
+		migrations::v3::MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::claim_count(), 2);
+		assert_eq!(TemplateModule::on_chain_storage_version(), 3);
+	});
+}
+
+#[test]
+fn migration_v3_to_v4_marks_existing_claims_as_other() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		StorageVersion::new(3).put::<TemplateModule>();
+		let old = migrations::v2::ClaimInfo {
+			owner: 1,
+			created_at: 3,
+			deposit: CLAIM_DEPOSIT,
+			expires_at: Some(13),
+		};
+		migrations::v2::Claims::<Test>::insert(claim, old);
+
+		migrations::v4::MigrateV3ToV4::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			migrations::v4::Claims::<Test>::get(claim),
+			Some(migrations::v4::ClaimInfo {
+				kind: ClaimKind::Other(0),
+				..old_claim_info(1, 3, CLAIM_DEPOSIT, Some(13))
+			})
+		);
+		assert_eq!(TemplateModule::on_chain_storage_version(), 4);
+	});
+}
+
+#[test]
+fn accept_transfer_moves_an_approved_claim() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::approve_transfer(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			2
+		));
+		System::assert_last_event(
+			Event::TransferApproved { namespace: DEFAULT_NAMESPACE, claim, owner: 1, to: 2 }.into(),
+		);
+
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::accept_transfer(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		System::assert_last_event(
+			Event::ClaimTransferred { namespace: DEFAULT_NAMESPACE, from: 1, to: 2, claim, id: 0 }
+				.into(),
+		);
+		assert_eq!(TemplateModule::claim_info(DEFAULT_NAMESPACE, claim), Some((2, 1)));
+		assert_eq!(Balances::reserved_balance(2), CLAIM_DEPOSIT);
+		assert!(!Approvals::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn accept_transfer_fails_without_approval() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_noop!(
+			TemplateModule::accept_transfer(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NoSuchClaim
+		);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			TemplateModule::accept_transfer(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NotApproved
+		);
+
+		assert_ok!(TemplateModule::approve_transfer(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			2
+		));
+		assert_noop!(
+			TemplateModule::accept_transfer(RuntimeOrigin::signed(3), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NotApproved
+		);
+	});
+}
+
+#[test]
+fn approve_transfer_checks_the_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_noop!(
+			TemplateModule::approve_transfer(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, claim, 2),
+			Error::<Test>::NoSuchClaim
+		);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			TemplateModule::approve_transfer(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, claim, 3),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			TemplateModule::approve_transfer(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, claim, 1),
+			Error::<Test>::CannotTransferToSelf
+		);
+	});
+}
+
+#[test]
+fn re_approving_replaces_the_previous_approval() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::approve_transfer(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			2
+		));
+		assert_ok!(TemplateModule::approve_transfer(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			3
+		));
+
+		assert_noop!(
+			TemplateModule::accept_transfer(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NotApproved
+		);
+		assert_ok!(TemplateModule::accept_transfer(
+			RuntimeOrigin::signed(3),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert_eq!(
+			TemplateModule::claim_info(DEFAULT_NAMESPACE, claim).map(|(owner, _)| owner),
+			Some(3)
+		);
+	});
+}
+
+#[test]
+fn approvals_are_cleared_when_the_claim_is_revoked_or_transferred() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::approve_transfer(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			2
+		));
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert!(!Approvals::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+
+		// A stale approval must not let anyone take over a claim made again later.
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			TemplateModule::accept_transfer(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NotApproved
+		);
+
+		assert_ok!(TemplateModule::approve_transfer(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			2
+		));
+		assert_ok!(TemplateModule::transfer_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			3
+		));
+		assert!(!Approvals::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn claims_can_be_inspected_through_the_trait() {
+	fn owner<I: ClaimInspect<u64, H256, u64>>(claim: &H256) -> Option<u64> {
+		I::owner_of(DEFAULT_NAMESPACE, claim)
+	}
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		let claim = H256::repeat_byte(1);
+		assert_eq!(owner::<TemplateModule>(&claim), None);
+		assert_eq!(
+			<TemplateModule as ClaimInspect<_, _, _>>::created_at(DEFAULT_NAMESPACE, &claim),
+			None
+		);
+
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(owner::<TemplateModule>(&claim), Some(1));
+		assert_eq!(
+			<TemplateModule as ClaimInspect<_, _, _>>::created_at(DEFAULT_NAMESPACE, &claim),
+			Some(3)
+		);
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &claim), Some(1));
+		assert_eq!(TemplateModule::created_at(DEFAULT_NAMESPACE, &claim), Some(3));
+	});
+}
+
+/// A pallet that only knows the claims through [`ClaimRegistry`], as another pallet of the runtime
+/// would. It is not part of the mock runtime, so the call plumbing generated for it goes unused,
+/// and the hooks generated for it with `try-runtime` are flagged by clippy.
+#[allow(dead_code, clippy::manual_inspect)]
+#[frame_support::pallet(dev_mode)]
+mod notary {
+	use crate::{ClaimInspect, ClaimRegistry};
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Registry: ClaimRegistry<AccountId = Self::AccountId, Hash = Self::Hash>;
+		type Inspect: ClaimInspect<Self::AccountId, Self::Hash, BlockNumberFor<Self>>;
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Claim `claim` for the signer, unless it is claimed already.
+		pub fn notarise(origin: OriginFor<T>, claim: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			if T::Registry::contains(&claim) {
+				return Ok(());
+			}
+			T::Registry::register(who, claim)
+		}
+
+		/// Go through all the claims of `owner`, as a call acting on each of them would.
+		pub fn audit(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+			T::Inspect::claims_of(&owner)?;
+			Ok(())
+		}
+	}
+}
+
+impl notary::Config for Test {
+	type Registry = TemplateModule;
+	type Inspect = TemplateModule;
+}
+
+#[test]
+fn iterating_getters_are_capped_within_a_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let max: u32 = <Test as crate::Config>::MaxIterationItems::get();
+		MaxClaimsPerAccount::set(max + 1);
+		for byte in 1..=max as u8 {
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				H256::repeat_byte(byte),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		assert_eq!(TemplateModule::try_claims_of(&1).unwrap().len(), max as usize);
+		assert_eq!(TemplateModule::try_claims_of_kind(ClaimKind::Document).unwrap().len(), 3);
+		assert_ok!(notary::Pallet::<Test>::audit(RuntimeOrigin::signed(2), 1));
+
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			H256::repeat_byte(0xff),
+			ClaimKind::Image,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			notary::Pallet::<Test>::audit(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::QueryTooLarge
+		);
+		assert!(matches!(TemplateModule::try_claims_of(&1), Err(Error::<Test>::QueryTooLarge)));
+		// Claims of other kinds count towards the cap, since they are visited all the same.
+		assert!(matches!(
+			TemplateModule::try_claims_of_kind(ClaimKind::Image),
+			Err(Error::<Test>::QueryTooLarge)
+		));
+		// The runtime API getters are not capped.
+		assert_eq!(TemplateModule::claims_of(&1).len(), max as usize + 1);
+		assert_eq!(TemplateModule::claims_of_kind(ClaimKind::Image).len(), 1);
+	});
+}
+
+#[test]
+fn other_pallets_can_make_claims_through_the_registry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert!(!<TemplateModule as ClaimRegistry>::contains(&claim));
+
+		assert_ok!(notary::Pallet::<Test>::notarise(RuntimeOrigin::signed(1), claim));
+		assert!(<TemplateModule as ClaimRegistry>::contains(&claim));
+		assert_eq!(<TemplateModule as ClaimRegistry>::owner(&claim), Some(1));
+		assert_eq!(Balances::reserved_balance(1), CLAIM_DEPOSIT);
+
+		// The claim is not taken over by a later caller.
+		assert_ok!(notary::Pallet::<Test>::notarise(RuntimeOrigin::signed(2), claim));
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &claim), Some(1));
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		// The registry still applies the pallet's own checks.
+		assert_ok!(TemplateModule::set_paused(RuntimeOrigin::root(), true));
+		assert_noop!(
+			notary::Pallet::<Test>::notarise(RuntimeOrigin::signed(2), H256::repeat_byte(2)),
+			Error::<Test>::Paused
+		);
+	});
+}
+
+#[test]
+fn duplicate_claims_are_rejected_from_the_pool() {
+	use crate::{Call, CheckDuplicateClaim};
+	use frame_support::dispatch::DispatchInfo;
+	use sp_runtime::{
+		traits::SignedExtension,
+		transaction_validity::{InvalidTransaction, TransactionValidity, ValidTransaction},
+	};
+
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		let call: RuntimeCall = Call::create_claim {
+			namespace: DEFAULT_NAMESPACE,
+			claim,
+			kind: ClaimKind::Document,
+			algo: HashAlgo::Blake2_256,
+			metadata: None,
+			expiry: None,
+			lock_until: None,
+			cid: None,
+		}
+		.into();
+		let info = DispatchInfo::default();
+		let validate = |call: &RuntimeCall| -> TransactionValidity {
+			CheckDuplicateClaim::<Test>::new().validate(&2, call, &info, 0)
+		};
+
+		assert_eq!(validate(&call), Ok(ValidTransaction::default()));
+
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(validate(&call), Err(InvalidTransaction::Stale.into()));
+		assert_eq!(
+			CheckDuplicateClaim::<Test>::new().pre_dispatch(&2, &call, &info, 0),
+			Err(InvalidTransaction::Stale.into())
+		);
+
+		// The same hash can still be claimed in another namespace.
+		let other_namespace: RuntimeCall = Call::create_claim {
+			namespace: 1,
+			claim,
+			kind: ClaimKind::Document,
+			algo: HashAlgo::Blake2_256,
+			metadata: None,
+			expiry: None,
+			lock_until: None,
+			cid: None,
+		}
+		.into();
+		assert_eq!(validate(&other_namespace), Ok(ValidTransaction::default()));
+		let default: RuntimeCall = Call::create_claim_default {
+			claim,
+			kind: ClaimKind::Document,
+			algo: HashAlgo::Blake2_256,
+			metadata: None,
+			expiry: None,
+			lock_until: None,
+			cid: None,
+		}
+		.into();
+		assert_eq!(validate(&default), Err(InvalidTransaction::Stale.into()));
+
+		// Other calls about the claim are left alone.
+		let revoke: RuntimeCall = Call::revoke_claim { namespace: DEFAULT_NAMESPACE, claim }.into();
+		assert_eq!(validate(&revoke), Ok(ValidTransaction::default()));
+
+		// A hash waiting for its witness cannot be claimed outright either.
+		assert_ok!(TemplateModule::create_pending_claim(RuntimeOrigin::signed(1), 1, claim, 3));
+		assert_eq!(validate(&other_namespace), Err(InvalidTransaction::Stale.into()));
+	});
+}
+
+#[test]
+fn the_same_hash_can_be_claimed_in_different_namespaces() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(2),
+			7,
+			claim,
+			ClaimKind::Image,
+			HashAlgo::Blake2_256,
+			Some(b"app 7".to_vec()),
+			None,
+			None,
+			None
+		));
+		System::assert_last_event(
+			Event::ClaimCreated {
+				who: 2,
+				namespace: 7,
+				claim,
+				kind: ClaimKind::Image,
+				algo: HashAlgo::Blake2_256,
+				id: 1,
+				cid: None,
+			}
+			.into(),
+		);
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(3),
+				7,
+				claim,
+				ClaimKind::Image,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::AlreadyClaimed.with_weight(read_weight())
+		);
+
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &claim), Some(1));
+		assert_eq!(TemplateModule::owner_of(7, &claim), Some(2));
+		assert_eq!(TemplateModule::claims_in_namespace(7), vec![claim]);
+		assert!(TemplateModule::claims_in_namespace(8).is_empty());
+		assert_eq!(TemplateModule::claims_of(&2), vec![(7, claim)]);
+		assert_eq!(TemplateModule::claim_count(), 2);
+
+		// Revoking the claim in one namespace leaves the other one alone.
+		assert_ok!(TemplateModule::revoke_claim(RuntimeOrigin::signed(2), 7, claim));
+		assert_eq!(ClaimMetadata::<Test>::get(7, claim), None);
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &claim), Some(1));
+		assert_noop!(
+			TemplateModule::revoke_claim(RuntimeOrigin::signed(1), 7, claim),
+			Error::<Test>::NoSuchClaim
+		);
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn create_claim_free_waives_the_fee_of_exempt_accounts() {
+	use frame_support::dispatch::Pays;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+
+		let post_info =
+			TemplateModule::create_claim_free(RuntimeOrigin::signed(FEE_EXEMPT), claim).unwrap();
+		assert_eq!(post_info.pays_fee, Pays::No);
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &claim), Some(FEE_EXEMPT));
+		assert_eq!(Balances::reserved_balance(FEE_EXEMPT), CLAIM_DEPOSIT);
+		System::assert_last_event(
+			Event::ClaimCreated {
+				who: FEE_EXEMPT,
+				namespace: DEFAULT_NAMESPACE,
+				claim,
+				kind: ClaimKind::Document,
+				algo: HashAlgo::Other(0),
+				id: 0,
+				cid: None,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn create_claim_free_is_rate_limited_by_the_min_claim_interval() {
+	new_test_ext().execute_with(|| {
+		MinClaimInterval::set(3);
+		let claims: Vec<_> = (1..=2).map(H256::repeat_byte).collect();
+		let create =
+			|claim| TemplateModule::create_claim_free(RuntimeOrigin::signed(FEE_EXEMPT), claim);
+
+		System::set_block_number(1);
+		assert_ok!(create(claims[0]));
+		assert_eq!(crate::LastClaimAt::<Test>::get(FEE_EXEMPT), Some(1));
+		assert_noop!(create(claims[1]), Error::<Test>::TooSoon);
+
+		// A free claim also holds back the other ways of claiming.
+		System::set_block_number(3);
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(FEE_EXEMPT),
+				DEFAULT_NAMESPACE,
+				claims[1],
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::TooSoon
+		);
+
+		System::set_block_number(4);
+		assert_ok!(create(claims[1]));
+		assert_eq!(crate::LastClaimAt::<Test>::get(FEE_EXEMPT), Some(4));
+	});
+}
+
+#[test]
+fn create_claim_free_rejects_accounts_that_are_not_exempt() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_noop!(
+			TemplateModule::create_claim_free(RuntimeOrigin::signed(1), claim),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::create_claim_free(RuntimeOrigin::root(), claim),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_claim_default_uses_the_default_namespace() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim_default(
+			RuntimeOrigin::signed(1),
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		System::assert_last_event(
+			Event::ClaimCreated {
+				who: 1,
+				namespace: DEFAULT_NAMESPACE,
+				claim,
+				kind: ClaimKind::Document,
+				algo: HashAlgo::Blake2_256,
+				id: 0,
+				cid: None,
+			}
+			.into(),
+		);
+		assert_eq!(TemplateModule::claims_in_namespace(DEFAULT_NAMESPACE), vec![claim]);
+		assert_noop!(
+			TemplateModule::create_claim_default(
+				RuntimeOrigin::signed(2),
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::AlreadyClaimed.with_weight(read_weight())
+		);
+	});
+}
+
+#[test]
+fn migration_v4_to_v5_moves_claims_to_the_default_namespace() {
+	new_test_ext().execute_with(|| {
+		let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		StorageVersion::new(4).put::<TemplateModule>();
+		migrations::v4::Claims::<Test>::insert(first, old_claim_info(1, 0, 0, Some(10)));
+		migrations::v4::Claims::<Test>::insert(second, old_claim_info(1, 0, 0, None));
+		assert_ok!(migrations::v4::ClaimsByOwner::<Test>::try_append(1, first));
+		assert_ok!(migrations::v4::ClaimsByOwner::<Test>::try_append(1, second));
+		assert_ok!(migrations::v4::ExpiringAt::<Test>::try_append(10, first));
+		migrations::v4::ClaimMetadata::<Test>::insert(
+			first,
+			ClaimMetadataOf::<Test>::truncate_from(vec![1, 2, 3]),
+		);
+		migrations::v4::Approvals::<Test>::insert(second, 2);
+		crate::ClaimCount::<Test>::put(2);
+
+		migrations::v5::MigrateV4ToV5::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			migrations::v5::Claims::<Test>::get(DEFAULT_NAMESPACE, first),
+			Some(old_claim_info(1, 0, 0, Some(10)))
+		);
+		assert_eq!(
+			migrations::v5::Claims::<Test>::get(DEFAULT_NAMESPACE, second),
+			Some(old_claim_info(1, 0, 0, None))
+		);
+		assert_eq!(
+			migrations::v5::ClaimsByOwner::<Test>::get(1).into_inner(),
+			vec![(DEFAULT_NAMESPACE, first), (DEFAULT_NAMESPACE, second)]
+		);
+		assert_eq!(ExpiringAt::<Test>::get(10).into_inner(), vec![(DEFAULT_NAMESPACE, first)]);
+		assert_eq!(
+			ClaimMetadata::<Test>::get(DEFAULT_NAMESPACE, first).map(|m| m.into_inner()),
+			Some(vec![1, 2, 3])
+		);
+		assert_eq!(Approvals::<Test>::get(DEFAULT_NAMESPACE, second), Some(2));
+		assert_eq!(TemplateModule::on_chain_storage_version(), 5);
+		finish_migrations();
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn migration_v5_to_v6_moves_reserved_deposits_to_holds() {
+	use frame_support::traits::{fungible::InspectHold, ReservableCurrency};
+
+	new_test_ext().execute_with(|| {
+		let (first, second, genesis) =
+			(H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3));
+		StorageVersion::new(5).put::<TemplateModule>();
+		migrations::v5::Claims::<Test>::insert(
+			DEFAULT_NAMESPACE,
+			first,
+			old_claim_info(1, 0, CLAIM_DEPOSIT, None),
+		);
+		migrations::v5::Claims::<Test>::insert(
+			DEFAULT_NAMESPACE,
+			second,
+			old_claim_info(2, 0, CLAIM_DEPOSIT, None),
+		);
+		migrations::v5::Claims::<Test>::insert(
+			DEFAULT_NAMESPACE,
+			genesis,
+			old_claim_info(1, 0, 0, None),
+		);
+		assert_ok!(migrations::v5::ClaimsByOwner::<Test>::try_append(
+			1,
+			(DEFAULT_NAMESPACE, first)
+		));
+		assert_ok!(migrations::v5::ClaimsByOwner::<Test>::try_append(
+			2,
+			(DEFAULT_NAMESPACE, second)
+		));
+		assert_ok!(migrations::v5::ClaimsByOwner::<Test>::try_append(
+			1,
+			(DEFAULT_NAMESPACE, genesis)
+		));
+		crate::ClaimCount::<Test>::put(3);
+		assert_ok!(Balances::reserve(&1, CLAIM_DEPOSIT));
+		// Account 2 somehow has less reserved than its claim records.
+		assert_ok!(Balances::reserve(&2, 4));
+
+		migrations::v6::MigrateV5ToV6::<Test, Balances>::on_runtime_upgrade();
+
+		let reason = RuntimeHoldReason::TemplateModule(HoldReason::ClaimDeposit);
+		assert_eq!(Balances::balance_on_hold(&reason, &1), CLAIM_DEPOSIT);
+		assert_eq!(Balances::reserved_balance(1), CLAIM_DEPOSIT);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - CLAIM_DEPOSIT);
+		// Only what could be moved is recorded as the deposit.
+		assert_eq!(Balances::balance_on_hold(&reason, &2), 4);
+		let deposit =
+			|claim| migrations::v5::Claims::<Test>::get(DEFAULT_NAMESPACE, claim).unwrap().deposit;
+		assert_eq!(deposit(second), 4);
+		assert_eq!(deposit(genesis), 0);
+		assert_eq!(TemplateModule::on_chain_storage_version(), 6);
+		finish_migrations();
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn migration_v6_to_v7_indexes_existing_claims_by_block() {
+	new_test_ext().execute_with(|| {
+		MaxClaimsPerBlock::set(1);
+		let (first, second, third) =
+			(H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3));
+		StorageVersion::new(6).put::<TemplateModule>();
+		migrations::v5::Claims::<Test>::insert(
+			DEFAULT_NAMESPACE,
+			first,
+			old_claim_info(1, 3, CLAIM_DEPOSIT, None),
+		);
+		migrations::v5::Claims::<Test>::insert(
+			DEFAULT_NAMESPACE,
+			second,
+			old_claim_info(2, 4, CLAIM_DEPOSIT, None),
+		);
+		migrations::v5::Claims::<Test>::insert(
+			DEFAULT_NAMESPACE,
+			third,
+			old_claim_info(2, 4, CLAIM_DEPOSIT, None),
+		);
+
+		migrations::v7::MigrateV6ToV7::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::claims_created_in_block(3), vec![(DEFAULT_NAMESPACE, first)]);
+		// Only one of the claims of block 4 fits.
+		assert_eq!(TemplateModule::claims_created_in_block(4).len(), 1);
+		assert_eq!(TemplateModule::on_chain_storage_version(), 7);
+	});
+}
+
+#[test]
+fn migration_v7_to_v8_numbers_existing_claims_in_creation_order() {
+	new_test_ext().execute_with(|| {
+		let (first, second, third) =
+			(H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3));
+		StorageVersion::new(7).put::<TemplateModule>();
+		for (claim, created_at) in [(first, 2), (second, 7), (third, 0)] {
+			let old = old_claim_info(1, created_at, 0, None);
+			migrations::v5::Claims::<Test>::insert(DEFAULT_NAMESPACE, claim, old);
+		}
+
+		migrations::v8::MigrateV7ToV8::<Test>::on_runtime_upgrade();
+
+		let id = |claim| migrations::v8::Claims::<Test>::get(DEFAULT_NAMESPACE, claim).unwrap().id;
+		assert_eq!((id(third), id(first), id(second)), (0, 1, 2));
+		assert_eq!(crate::NextClaimId::<Test>::get(), 3);
+		assert_eq!(
+			migrations::v8::Claims::<Test>::get(DEFAULT_NAMESPACE, second),
+			Some(migrations::v8::ClaimInfo {
+				id: 2,
+				owner: 1,
+				created_at: 7,
+				deposit: 0,
+				expires_at: None,
+				kind: ClaimKind::Document,
+			})
+		);
+		assert_eq!(TemplateModule::on_chain_storage_version(), 8);
+	});
+}
+
+#[test]
+fn migration_v8_to_v9_marks_the_algorithm_of_existing_claims_as_other() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		StorageVersion::new(8).put::<TemplateModule>();
+		let old = migrations::v8::ClaimInfo {
+			id: 4,
+			owner: 1,
+			created_at: 3,
+			deposit: CLAIM_DEPOSIT,
+			expires_at: Some(13),
+			kind: ClaimKind::Image,
+		};
+		migrations::v8::Claims::<Test>::insert(DEFAULT_NAMESPACE, claim, old);
+
+		migrations::v9::MigrateV8ToV9::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			migrations::v9::Claims::<Test>::get(DEFAULT_NAMESPACE, claim),
+			Some(migrations::v9::ClaimInfo {
+				id: 4,
+				owner: 1,
+				created_at: 3,
+				deposit: CLAIM_DEPOSIT,
+				expires_at: Some(13),
+				kind: ClaimKind::Image,
+				algo: HashAlgo::Other(0),
+			})
+		);
+		assert_eq!(TemplateModule::on_chain_storage_version(), 9);
+	});
+}
+
+#[test]
+fn migration_v9_to_v10_counts_the_claims_of_each_owner() {
+	new_test_ext().execute_with(|| {
+		create_claims_for(1, &[H256::repeat_byte(1), H256::repeat_byte(2)]);
+		create_claims_for(2, &[H256::repeat_byte(3)]);
+		index_claims_as_before_v12();
+		// Forget the counts, as if the claims had been made before they were kept.
+		let _ = crate::ClaimCountByOwner::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(9).put::<TemplateModule>();
+
+		migrations::v10::MigrateV9ToV10::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TemplateModule::claim_count_of(&1), 2);
+		assert_eq!(TemplateModule::claim_count_of(&2), 1);
+		assert_eq!(TemplateModule::on_chain_storage_version(), 10);
+		<migrations::v12::InnerMigrateV11ToV12<Test> as UncheckedOnRuntimeUpgrade>::on_runtime_upgrade();
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn migration_v10_to_v11_leaves_existing_claims_unlocked() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		StorageVersion::new(10).put::<TemplateModule>();
+		let old = migrations::v9::ClaimInfo {
+			id: 4,
+			owner: 1,
+			created_at: 3,
+			deposit: CLAIM_DEPOSIT,
+			expires_at: Some(13),
+			kind: ClaimKind::Image,
+			algo: HashAlgo::Sha2_256,
+		};
+		migrations::v9::Claims::<Test>::insert(DEFAULT_NAMESPACE, claim, old);
+
+		migrations::v11::MigrateV10ToV11::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			migrations::v11::Claims::<Test>::get(DEFAULT_NAMESPACE, claim),
+			Some(migrations::v11::ClaimInfo {
+				id: 4,
+				owner: 1,
+				created_at: 3,
+				deposit: CLAIM_DEPOSIT,
+				expires_at: Some(13),
+				kind: ClaimKind::Image,
+				algo: HashAlgo::Sha2_256,
+				lock_until: None,
+			})
+		);
+		assert_eq!(TemplateModule::on_chain_storage_version(), 11);
+	});
+}
+
+#[test]
+fn migration_v12_to_v13_leaves_existing_claims_without_a_manager() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		StorageVersion::new(12).put::<TemplateModule>();
+		let old = migrations::v11::ClaimInfo {
+			id: 4,
+			owner: 1,
+			created_at: 3,
+			deposit: CLAIM_DEPOSIT,
+			expires_at: Some(13),
+			kind: ClaimKind::Image,
+			algo: HashAlgo::Sha2_256,
+			lock_until: Some(8),
+		};
+		migrations::v11::Claims::<Test>::insert(DEFAULT_NAMESPACE, claim, old);
+
+		migrations::v13::MigrateV12ToV13::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			Claims::<Test>::get(DEFAULT_NAMESPACE, claim),
+			Some(ClaimInfo {
+				kind: ClaimKind::Image,
+				algo: HashAlgo::Sha2_256,
+				lock_until: Some(8),
+				..claim_info(4, 1, 3, CLAIM_DEPOSIT, Some(13))
+			})
+		);
+		assert_eq!(TemplateModule::on_chain_storage_version(), 13);
+	});
+}
+
+#[test]
+fn migration_v11_to_v12_gives_every_indexed_claim_its_own_entry() {
+	new_test_ext().execute_with(|| {
+		let (first, second, third) =
+			(H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3));
+		create_claims_for(1, &[first, second]);
+		create_claims_for(2, &[third]);
+		index_claims_as_before_v12();
+		StorageVersion::new(11).put::<TemplateModule>();
+
+		migrations::v12::MigrateV11ToV12::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			indexed_claims(1),
+			vec![(DEFAULT_NAMESPACE, first), (DEFAULT_NAMESPACE, second)]
+		);
+		assert_eq!(indexed_claims(2), vec![(DEFAULT_NAMESPACE, third)]);
+		assert!(!migrations::v5::ClaimsByOwner::<Test>::contains_key(1));
+		assert_eq!(TemplateModule::claim_count_of(&1), 2);
+		assert_eq!(TemplateModule::on_chain_storage_version(), 12);
+		assert_ok!(TemplateModule::do_try_state(System::block_number()));
+	});
+}
+
+#[test]
+fn create_claim_refunds_the_weight_of_a_lost_race() {
+	use frame_support::dispatch::GetDispatchInfo;
+
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		let call = crate::Call::<Test>::create_claim {
+			namespace: DEFAULT_NAMESPACE,
+			claim,
+			kind: ClaimKind::Document,
+			algo: HashAlgo::Blake2_256,
+			metadata: None,
+			expiry: None,
+			lock_until: None,
+			cid: None,
+		};
+		let info = call.get_dispatch_info();
+
+		let won = TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None,
+		)
+		.unwrap();
+		let lost = TemplateModule::create_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None,
+		)
+		.unwrap_err()
+		.post_info;
+
+		// Nothing had expired, so the weight of removing an expired claim is refunded.
+		assert_eq!(
+			won.calc_actual_weight(&info),
+			info.weight - TemplateModule::expired_claim_weight()
+		);
+		assert_eq!(lost.actual_weight, Some(read_weight()));
+		assert!(lost.calc_actual_weight(&info).all_lt(info.weight));
+	});
+}
+
+#[test]
+fn enforcing_the_allowlist_charges_for_reading_it() {
+	use frame_support::dispatch::GetDispatchInfo;
+
+	new_test_ext().execute_with(|| {
+		let call = crate::Call::<Test>::create_claim {
+			namespace: DEFAULT_NAMESPACE,
+			claim: H256::repeat_byte(1),
+			kind: ClaimKind::Document,
+			algo: HashAlgo::Blake2_256,
+			metadata: None,
+			expiry: None,
+			lock_until: None,
+			cid: None,
+		};
+		let plain = call.get_dispatch_info().weight;
+		EnforceAllowlist::set(true);
+		let allowlisted = call.get_dispatch_info().weight;
+
+		// The mock uses the `()` weights, which are in terms of `RocksDbWeight`. The allowlisted
+		// path costs one more read, give or take its bookkeeping.
+		let read = frame_support::weights::constants::RocksDbWeight::get().reads(1);
+		let extra = allowlisted - plain;
+		assert!(extra.all_gte(read));
+		assert!(extra.ref_time() < 2 * read.ref_time());
+	});
+}
+
+#[test]
+fn limits_are_exposed_as_constants_in_the_metadata() {
+	use codec::Encode;
+
+	let constants = TemplateModule::pallet_constants_metadata();
+	let value = |name: &str| {
+		constants
+			.iter()
+			.find(|constant| constant.name == name)
+			.unwrap_or_else(|| panic!("`{name}` is not in the metadata"))
+			.value
+			.clone()
+	};
+	assert_eq!(value("MaxClaimMetadataLen"), 32u32.encode());
+	assert_eq!(value("MaxClaimsPerAccount"), 3u32.encode());
+	assert_eq!(value("ClaimDeposit"), CLAIM_DEPOSIT.encode());
+	assert_eq!(value("ClaimLifetime"), 10u64.encode());
+}
+
+#[test]
+fn pending_claims_hold_the_hash_until_their_witness_confirms() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_pending_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			2
+		));
+		System::assert_last_event(
+			Event::ClaimPending {
+				who: 1,
+				witness: 2,
+				namespace: DEFAULT_NAMESPACE,
+				claim,
+				expires_at: 6,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), CLAIM_DEPOSIT);
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		assert_eq!(
+			PendingExpiringAt::<Test>::get(6).into_inner(),
+			vec![(DEFAULT_NAMESPACE, claim)]
+		);
+
+		// Nobody else can take the hash while it is pending.
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(3),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+		assert_noop!(
+			TemplateModule::create_pending_claim(
+				RuntimeOrigin::signed(3),
+				DEFAULT_NAMESPACE,
+				claim,
+				2
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+
+		assert_ok!(TemplateModule::confirm_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert!(!PendingClaims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		assert_eq!(TemplateModule::claim_info(DEFAULT_NAMESPACE, claim), Some((1, 1)));
+		assert_eq!(Balances::reserved_balance(1), CLAIM_DEPOSIT);
+		System::assert_has_event(
+			Event::ClaimCreated {
+				who: 1,
+				namespace: DEFAULT_NAMESPACE,
+				claim,
+				kind: ClaimKind::Document,
+				algo: HashAlgo::Other(0),
+				id: 0,
+				cid: None,
+			}
+			.into(),
+		);
+		System::assert_last_event(
+			Event::ClaimConfirmed { witness: 2, namespace: DEFAULT_NAMESPACE, claim }.into(),
+		);
+		assert_eq!(crate::Pallet::<Test>::do_try_state(System::block_number()), Ok(()));
+	});
+}
+
+#[test]
+fn confirm_claim_only_accepts_the_named_witness() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_noop!(
+			TemplateModule::confirm_claim(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NoSuchPendingClaim
+		);
+		assert_ok!(TemplateModule::create_pending_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			2
+		));
+		for who in [1, 3] {
+			assert_noop!(
+				TemplateModule::confirm_claim(RuntimeOrigin::signed(who), DEFAULT_NAMESPACE, claim),
+				Error::<Test>::WitnessMismatch
+			);
+		}
+		// The claim is only pending in the namespace it was made in.
+		assert_noop!(
+			TemplateModule::confirm_claim(RuntimeOrigin::signed(2), 1, claim),
+			Error::<Test>::NoSuchPendingClaim
+		);
+	});
+}
+
+#[test]
+fn pending_claims_are_dropped_after_the_timeout() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_pending_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			2
+		));
+
+		run_to_block(5);
+		assert!(PendingClaims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+
+		run_to_block(6);
+		assert!(!PendingClaims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		assert!(!PendingExpiringAt::<Test>::contains_key(6));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+		System::assert_last_event(
+			Event::PendingClaimExpired { who: 1, namespace: DEFAULT_NAMESPACE, claim }.into(),
+		);
+		assert_noop!(
+			TemplateModule::confirm_claim(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NoSuchPendingClaim
+		);
+		// The hash is free to be claimed again.
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(3),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+	});
+}
+
+#[test]
+fn the_timeout_skips_claims_made_pending_again() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_pending_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			2
+		));
+		assert_ok!(TemplateModule::confirm_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		run_to_block(3);
+		assert_ok!(TemplateModule::create_pending_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			3
+		));
+
+		// The first schedule is stale, so only the second one drops the claim.
+		run_to_block(6);
+		assert!(PendingClaims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+		assert_eq!(crate::Pallet::<Test>::do_try_state(System::block_number()), Ok(()));
+		run_to_block(8);
+		assert!(!PendingClaims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+	});
+}
+
+#[test]
+fn the_timeout_hook_is_weighed_by_the_size_of_the_schedule() {
+	use crate::WeightInfo;
+	use frame_support::{traits::Hooks, weights::constants::RocksDbWeight};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let weight_for = <Test as crate::Config>::WeightInfo::on_initialize_pending_timeout;
+		// The schedule is read even when nothing is due.
+		let empty = TemplateModule::on_initialize(2);
+		assert_eq!(empty, weight_for(0));
+		assert!(empty.all_gte(RocksDbWeight::get().reads(1)));
+
+		for (who, byte) in [(1, 1), (2, 2)] {
+			assert_ok!(TemplateModule::create_pending_claim(
+				RuntimeOrigin::signed(who),
+				DEFAULT_NAMESPACE,
+				H256::repeat_byte(byte),
+				3
+			));
+		}
+		// One of the claims is confirmed, but the hook is still charged for both.
+		assert_ok!(TemplateModule::confirm_claim(
+			RuntimeOrigin::signed(3),
+			DEFAULT_NAMESPACE,
+			H256::repeat_byte(1)
+		));
+		let full = TemplateModule::on_initialize(6);
+		assert_eq!(full, weight_for(2));
+		assert!(full.all_gt(weight_for(1)) && weight_for(1).all_gt(empty));
+		assert!(!PendingClaims::<Test>::contains_key(DEFAULT_NAMESPACE, H256::repeat_byte(2)));
+	});
+}
+
+#[test]
+fn transfer_claim_bulk_moves_every_claim_to_the_destination() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		create_claims_for(1, &[first, second]);
+
+		assert_ok!(TemplateModule::transfer_claim_bulk(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			vec![first, second].try_into().unwrap(),
+			2
+		));
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &first), Some(2));
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &second), Some(2));
+		assert!(indexed_claims(1).is_empty());
+		assert_eq!(
+			indexed_claims(2),
+			vec![(DEFAULT_NAMESPACE, first), (DEFAULT_NAMESPACE, second)]
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 2 * CLAIM_DEPOSIT);
+		System::assert_has_event(
+			Event::ClaimTransferred {
+				from: 1,
+				to: 2,
+				namespace: DEFAULT_NAMESPACE,
+				claim: first,
+				id: 0,
+			}
+			.into(),
+		);
+		System::assert_last_event(
+			Event::ClaimsBulkTransferred { from: 1, to: 2, namespace: DEFAULT_NAMESPACE, count: 2 }
+				.into(),
+		);
+		assert_eq!(crate::Pallet::<Test>::do_try_state(System::block_number()), Ok(()));
+	});
+}
+
+#[test]
+fn transfer_claim_bulk_moves_nothing_unless_the_signer_owns_every_claim() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (mine, theirs) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		create_claims_for(1, &[mine]);
+		create_claims_for(2, &[theirs]);
+
+		assert_noop!(
+			TemplateModule::transfer_claim_bulk(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				vec![mine, theirs].try_into().unwrap(),
+				3
+			),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			TemplateModule::transfer_claim_bulk(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				vec![mine, H256::repeat_byte(3)].try_into().unwrap(),
+				3
+			),
+			Error::<Test>::NoSuchClaim
+		);
+		assert_noop!(
+			TemplateModule::transfer_claim_bulk(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				vec![mine].try_into().unwrap(),
+				1
+			),
+			Error::<Test>::CannotTransferToSelf
+		);
+	});
+}
+
+#[test]
+fn transfer_claim_bulk_moves_a_repeated_hash_once() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		create_claims_for(1, &[claim]);
+
+		assert_ok!(TemplateModule::transfer_claim_bulk(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			vec![claim, claim].try_into().unwrap(),
+			2
+		));
+		assert_eq!(indexed_claims(2), vec![(DEFAULT_NAMESPACE, claim)]);
+		System::assert_last_event(
+			Event::ClaimsBulkTransferred { from: 1, to: 2, namespace: DEFAULT_NAMESPACE, count: 1 }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn set_paused_requires_the_force_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TemplateModule::set_paused(RuntimeOrigin::signed(1), true),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_claim_lifetime_overrides_the_configured_lifetime() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let create = |who: u64, claim: H256| {
+			assert_ok!(TemplateModule::create_claims(
+				RuntimeOrigin::signed(who),
+				DEFAULT_NAMESPACE,
+				vec![claim].try_into().unwrap(),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				false
+			));
+			Claims::<Test>::get(DEFAULT_NAMESPACE, claim).unwrap().expires_at
+		};
+		let [a, b, c, d] = [1, 2, 3, 4].map(H256::repeat_byte);
+
+		// The mock's lifetime is ten blocks.
+		assert_eq!(create(1, a), Some(11));
+
+		assert_ok!(TemplateModule::set_claim_lifetime(RuntimeOrigin::root(), Some(4)));
+		System::assert_last_event(Event::ClaimLifetimeChanged { lifetime: Some(4) }.into());
+		assert_eq!(TemplateModule::claim_lifetime(), 4);
+		assert_eq!(create(1, b), Some(5));
+		// Claims that were already scheduled keep their expiry.
+		assert_eq!(Claims::<Test>::get(DEFAULT_NAMESPACE, a).unwrap().expires_at, Some(11));
+
+		// A lifetime of zero disables expiry, renewals included.
+		assert_ok!(TemplateModule::set_claim_lifetime(RuntimeOrigin::root(), Some(0)));
+		assert_eq!(create(2, c), None);
+		assert_noop!(
+			TemplateModule::renew_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, a),
+			Error::<Test>::ExpiryDisabled
+		);
+
+		// Clearing the override restores the configured lifetime.
+		assert_ok!(TemplateModule::set_claim_lifetime(RuntimeOrigin::root(), None));
+		System::assert_last_event(Event::ClaimLifetimeChanged { lifetime: None }.into());
+		assert_eq!(TemplateModule::claim_lifetime(), 10);
+		assert_eq!(create(2, d), Some(11));
+	});
+}
+
+#[test]
+fn renew_claim_uses_the_overridden_lifetime() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		create_claims_for(1, &[claim]);
+
+		assert_ok!(TemplateModule::set_claim_lifetime(RuntimeOrigin::root(), Some(3)));
+		assert_ok!(TemplateModule::renew_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, claim));
+		assert_eq!(Claims::<Test>::get(DEFAULT_NAMESPACE, claim).unwrap().expires_at, Some(4));
+
+		run_to_block(4);
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+	});
+}
+
+#[test]
+fn set_claim_lifetime_requires_the_force_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TemplateModule::set_claim_lifetime(RuntimeOrigin::signed(1), Some(1)),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn the_manager_can_renew_and_update_metadata_but_not_revoke_or_transfer() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		create_claims_for(1, &[claim]);
+
+		assert_ok!(TemplateModule::set_manager(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			Some(2)
+		));
+		System::assert_last_event(
+			Event::ClaimManagerSet { namespace: DEFAULT_NAMESPACE, claim, manager: Some(2) }.into(),
+		);
+		assert_eq!(Claims::<Test>::get(DEFAULT_NAMESPACE, claim).unwrap().manager, Some(2));
+
+		System::set_block_number(3);
+		assert_ok!(TemplateModule::renew_claim(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, claim));
+		assert_eq!(Claims::<Test>::get(DEFAULT_NAMESPACE, claim).unwrap().expires_at, Some(13));
+		// The manager can shrink the metadata, releasing its deposit to the owner.
+		assert_ok!(TemplateModule::set_claim_metadata(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			Some(vec![1; 4])
+		));
+		assert_ok!(TemplateModule::set_claim_metadata(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			claim,
+			Some(vec![2; 2])
+		));
+		assert_eq!(Balances::reserved_balance(1), CLAIM_DEPOSIT + 2 * METADATA_DEPOSIT_PER_BYTE);
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		assert_noop!(
+			TemplateModule::revoke_claim(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			TemplateModule::transfer_claim(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, claim, 2),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			TemplateModule::set_manager(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				claim,
+				Some(3)
+			),
+			Error::<Test>::NotClaimOwner
+		);
+		// Any other account is still turned away.
+		assert_noop!(
+			TemplateModule::renew_claim(RuntimeOrigin::signed(3), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NotClaimOwner
+		);
+	});
+}
+
+#[test]
+fn the_manager_cannot_grow_the_deposit_held_from_the_owner() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		create_claims_for(1, &[claim]);
+		assert_ok!(TemplateModule::set_manager(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			Some(2)
+		));
+
+		assert_noop!(
+			TemplateModule::set_claim_metadata(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				claim,
+				Some(vec![1; 4])
+			),
+			Error::<Test>::DepositIncreaseByManager
+		);
+		assert_eq!(Balances::reserved_balance(1), CLAIM_DEPOSIT);
+
+		// Metadata of the same length costs the same, so the manager may replace it.
+		assert_ok!(TemplateModule::set_claim_metadata(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			Some(vec![1; 4])
+		));
+		assert_ok!(TemplateModule::set_claim_metadata(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			claim,
+			Some(vec![2; 4])
+		));
+		assert_eq!(
+			ClaimMetadata::<Test>::get(DEFAULT_NAMESPACE, claim).unwrap().into_inner(),
+			vec![2; 4]
+		);
+		assert_eq!(Balances::reserved_balance(1), CLAIM_DEPOSIT + 4 * METADATA_DEPOSIT_PER_BYTE);
+	});
+}
+
+#[test]
+fn the_manager_loses_its_rights_when_cleared_or_when_the_claim_changes_owner() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		create_claims_for(1, &[claim]);
+		assert_ok!(TemplateModule::set_manager(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			Some(2)
+		));
+
+		assert_ok!(TemplateModule::set_manager(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			None
+		));
+		assert_noop!(
+			TemplateModule::renew_claim(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NotClaimOwner
+		);
+
+		assert_ok!(TemplateModule::set_manager(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			Some(2)
+		));
+		assert_ok!(TemplateModule::transfer_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			3
+		));
+		assert_eq!(Claims::<Test>::get(DEFAULT_NAMESPACE, claim).unwrap().manager, None);
+		assert_noop!(
+			TemplateModule::renew_claim(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, claim),
+			Error::<Test>::NotClaimOwner
+		);
+	});
+}
+
+#[test]
+fn the_allowlist_is_ignored_unless_enforced() {
+	new_test_ext().execute_with(|| {
+		create_claims_for(1, &[H256::repeat_byte(1)]);
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &H256::repeat_byte(1)), Some(1));
+	});
+}
+
+#[test]
+fn only_allowlisted_accounts_create_claims_when_enforced() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		EnforceAllowlist::set(true);
+		let (claim, pending) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		let create = |who: u64| {
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(who),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None,
+			)
+		};
+		assert_noop!(create(1), Error::<Test>::NotAllowlisted);
+		assert_noop!(
+			TemplateModule::create_pending_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				pending,
+				2
+			),
+			Error::<Test>::NotAllowlisted
+		);
+
+		assert_ok!(TemplateModule::add_to_allowlist(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::AddedToAllowlist { who: 1 }.into());
+		assert_noop!(create(2), Error::<Test>::NotAllowlisted);
+		assert_ok!(create(1));
+		assert_ok!(TemplateModule::create_pending_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			pending,
+			2
+		));
+
+		// The pending claim is only created if its owner is still allowed when it is confirmed.
+		assert_ok!(TemplateModule::remove_from_allowlist(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::RemovedFromAllowlist { who: 1 }.into());
+		assert_noop!(
+			TemplateModule::confirm_claim(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, pending),
+			Error::<Test>::NotAllowlisted
+		);
+		// The claims already made are kept.
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &claim), Some(1));
+	});
+}
+
+#[test]
+fn the_allowlist_is_managed_by_the_force_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TemplateModule::add_to_allowlist(RuntimeOrigin::signed(1), 1),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			TemplateModule::remove_from_allowlist(RuntimeOrigin::signed(1), 1),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn claims_keep_time_with_the_block_number_provider() {
+	use frame_support::traits::Hooks;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		ProvidedBlockNumber::set(Some(100));
+		let claim = H256::repeat_byte(1);
+		let create = |expiry| {
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				Some(expiry),
+				None,
+				None,
+			)
+		};
+
+		// The expiry is in the future of this chain, but not of the provider.
+		assert_noop!(create(50), Error::<Test>::ExpiryInPast.with_weight(Weight::zero()));
+		assert_ok!(create(102));
+		assert_eq!(TemplateModule::claim_info(DEFAULT_NAMESPACE, claim), Some((1, 100)));
+		assert_eq!(TemplateModule::claims_created_in_block(100), vec![(DEFAULT_NAMESPACE, claim)]);
+		assert_ok!(TemplateModule::do_try_state(100));
+		TemplateModule::on_idle(1, Weight::MAX);
+		assert_eq!(CleanupCursor::<Test>::get(), Some(101));
+
+		// The provider may move on by several blocks at once, and the claim still expires.
+		System::set_block_number(2);
+		ProvidedBlockNumber::set(Some(105));
+		TemplateModule::on_idle(2, Weight::MAX);
+		assert!(!TemplateModule::claim_exists(DEFAULT_NAMESPACE, claim));
+	});
+}
+
+#[test]
+fn stats_reflect_the_registry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(TemplateModule::stats(), ClaimStats::default());
+
+		create_claims_for(1, &[H256::repeat_byte(1)]);
+		for (who, byte, expiry) in [(2, 2, 2), (2, 3, 2), (3, 4, 3)] {
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(who),
+				DEFAULT_NAMESPACE,
+				H256::repeat_byte(byte),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				Some(expiry),
+				None,
+				None
+			));
+		}
+		assert_ok!(TemplateModule::set_paused(RuntimeOrigin::root(), true));
+
+		assert_eq!(
+			TemplateModule::stats(),
+			ClaimStats { total: 4, expiring_next_block: 2, paused: true }
+		);
+	});
+}
+
+#[test]
+fn claims_cannot_be_created_while_paused() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (kept, claim) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		create_claims_for(1, &[kept]);
+
+		assert_ok!(TemplateModule::set_paused(RuntimeOrigin::root(), true));
+		System::assert_last_event(Event::PausedStateChanged { paused: true }.into());
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::Paused
+		);
+		assert_noop!(
+			TemplateModule::create_claims(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				vec![claim].try_into().unwrap(),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				false
+			),
+			Error::<Test>::Paused
+		);
+		assert_noop!(
+			TemplateModule::create_pending_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				2
+			),
+			Error::<Test>::Paused
+		);
+		// Owners can still get out.
+		assert_ok!(TemplateModule::revoke_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, kept));
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		assert_ok!(TemplateModule::set_paused(RuntimeOrigin::root(), false));
+		System::assert_last_event(Event::PausedStateChanged { paused: false }.into());
+		create_claims_for(1, &[claim]);
+	});
+}
+
+#[test]
+fn restores_and_confirmations_go_through_the_pause() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		TombstoneRevoked::set(true);
+		let (revoked, pending) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		create_claims_for(1, &[revoked]);
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			revoked
+		));
+		assert_ok!(TemplateModule::create_pending_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			pending,
+			2
+		));
+
+		assert_ok!(TemplateModule::set_paused(RuntimeOrigin::root(), true));
+		assert_noop!(
+			TemplateModule::create_claim_free(RuntimeOrigin::signed(FEE_EXEMPT), revoked),
+			Error::<Test>::Paused
+		);
+		assert_ok!(TemplateModule::restore_claim(
+			RuntimeOrigin::root(),
+			DEFAULT_NAMESPACE,
+			revoked
+		));
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &revoked), Some(1));
+		assert_ok!(TemplateModule::confirm_claim(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			pending
+		));
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &pending), Some(1));
+		assert_eq!(Balances::reserved_balance(1), 2 * CLAIM_DEPOSIT);
+	});
+}
+
+#[test]
+fn the_expiry_sweep_is_weighed_by_the_blocks_it_visits() {
+	use crate::WeightInfo;
+	use frame_support::traits::Hooks;
+
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let weight_for = <Test as crate::Config>::WeightInfo::on_initialize_expire;
+		let claim = H256::repeat_byte(1);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(5),
+			None,
+			None
+		));
+		assert_eq!(CleanupCursor::<Test>::get(), Some(2));
+
+		// Nothing expires in blocks 2 to 4, but each of them is visited.
+		System::set_block_number(4);
+		assert_eq!(TemplateModule::on_idle(4, Weight::MAX), weight_for(3));
+		assert_eq!(CleanupCursor::<Test>::get(), Some(5));
+
+		System::set_block_number(5);
+		assert_eq!(
+			TemplateModule::on_idle(5, Weight::MAX),
+			weight_for(1).saturating_add(TemplateModule::expired_claim_weight())
+		);
+		assert!(!Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+
+		// The cursor is read even when no block is due.
+		assert_eq!(TemplateModule::on_idle(5, Weight::MAX), weight_for(0));
+		assert!(weight_for(0).all_gt(Weight::zero()));
+	});
+}
+
+#[test]
+fn on_idle_removes_expired_claims_as_the_remaining_weight_allows() {
+	use crate::WeightInfo;
+	use frame_support::traits::Hooks;
+
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let claims = [H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3)];
+		for (who, claim, expiry) in [(1, claims[0], 3), (2, claims[1], 3), (3, claims[2], 4)] {
+			assert_ok!(TemplateModule::create_claim(
+				RuntimeOrigin::signed(who),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				Some(expiry),
+				None,
+				None
+			));
+		}
+		run_to_block(2);
+		assert_eq!(CleanupCursor::<Test>::get(), Some(3));
+
+		// Just enough for the cursor, one bucket and one claim.
+		let limit = <Test as crate::Config>::WeightInfo::on_initialize_expire(1)
+			.saturating_add(TemplateModule::expired_claim_weight());
+		let exists = |claim| Claims::<Test>::contains_key(DEFAULT_NAMESPACE, claim);
+
+		// Blocks 3 and 4 were too full for the sweep to run at all.
+		System::set_block_number(5);
+		assert_eq!(TemplateModule::on_idle(5, DbWeight::get().reads(1)), Weight::zero());
+		assert!(claims.iter().all(exists));
+
+		assert_eq!(TemplateModule::on_idle(5, limit), limit);
+		assert!(!exists(&claims[0]));
+		assert!(exists(&claims[1]) && exists(&claims[2]));
+		assert_eq!(ExpiringAt::<Test>::get(3).into_inner(), vec![(DEFAULT_NAMESPACE, claims[1])]);
+		assert_eq!(CleanupCursor::<Test>::get(), Some(3));
+
+		System::set_block_number(6);
+		assert_eq!(TemplateModule::on_idle(6, limit), limit);
+		assert!(!exists(&claims[1]) && exists(&claims[2]));
+		assert!(!ExpiringAt::<Test>::contains_key(3));
+		assert_eq!(CleanupCursor::<Test>::get(), Some(4));
+
+		System::set_block_number(7);
+		assert_eq!(TemplateModule::on_idle(7, limit), limit);
+		assert!(!exists(&claims[2]));
+		System::assert_last_event(
+			Event::ClaimExpired { namespace: DEFAULT_NAMESPACE, claim: claims[2], id: 2 }.into(),
+		);
+		assert_eq!(CleanupCursor::<Test>::get(), Some(5));
+
+		// With weight to spare, the sweep catches up with the current block.
+		System::set_block_number(8);
+		TemplateModule::on_idle(8, Weight::MAX);
+		assert_eq!(CleanupCursor::<Test>::get(), Some(9));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_ok!(TemplateModule::do_try_state(8));
+	});
+}
+
+#[test]
+fn purge_expired_catches_up_with_overdue_claims_in_chunks() {
+	use frame_support::traits::Hooks;
+
+	new_test_ext().execute_with(|| {
+		MaxClaimsPerAccount::set(10);
+		run_to_block(1);
+		let claims: Vec<_> = (1..=6).map(H256::repeat_byte).collect();
+		// Two claims expire in each of blocks 2 to 4.
+		for (expiry, pair) in (2..).zip(claims.chunks(2)) {
+			for claim in pair {
+				assert_ok!(TemplateModule::create_claim(
+					RuntimeOrigin::signed(1),
+					DEFAULT_NAMESPACE,
+					*claim,
+					ClaimKind::Document,
+					HashAlgo::Blake2_256,
+					None,
+					Some(expiry),
+					None,
+					None
+				));
+			}
+		}
+		TemplateModule::on_idle(1, Weight::MAX);
+		// Blocks 2 to 4 were too full for the sweep to run at all.
+		System::set_block_number(4);
+
+		// `MaxBatchSize` is 2 in the mock, so each call removes one block of claims.
+		for removed in [2, 4] {
+			let post_info = TemplateModule::purge_expired(RuntimeOrigin::signed(2), 10).unwrap();
+			assert!(post_info
+				.actual_weight
+				.unwrap()
+				.all_lte(TemplateModule::purge_expired_weight(10)));
+			assert_eq!(TemplateModule::claim_count_of(&1), 6 - removed);
+			System::assert_last_event(Event::ExpiredClaimsPurged { count: 2, more: true }.into());
+		}
+		assert_ok!(TemplateModule::purge_expired(RuntimeOrigin::signed(2), 10));
+		System::assert_last_event(Event::ExpiredClaimsPurged { count: 2, more: false }.into());
+
+		assert_eq!(TemplateModule::claim_count(), 0);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		for claim in claims {
+			System::assert_has_event(
+				Event::ClaimExpired {
+					namespace: DEFAULT_NAMESPACE,
+					claim,
+					id: u64::from(claim.0[0]) - 1,
+				}
+				.into(),
+			);
+		}
+		assert_eq!(CleanupCursor::<Test>::get(), Some(5));
+
+		// Nothing is left to purge.
+		assert_ok!(TemplateModule::purge_expired(RuntimeOrigin::signed(2), 10));
+		System::assert_last_event(Event::ExpiredClaimsPurged { count: 0, more: false }.into());
+		assert_ok!(TemplateModule::do_try_state(4));
+	});
+}
+
+#[test]
+fn owners_add_and_remove_tags() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		create_claims_for(1, &[claim]);
+
+		assert_ok!(TemplateModule::add_tag(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			b"zeta".to_vec()
+		));
+		System::assert_last_event(
+			Event::ClaimTagAdded {
+				namespace: DEFAULT_NAMESPACE,
+				claim,
+				tag: b"zeta".to_vec().try_into().unwrap(),
+			}
+			.into(),
+		);
+		assert_ok!(TemplateModule::add_tag(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			b"alpha".to_vec()
+		));
+		assert_eq!(
+			TemplateModule::tags_of(DEFAULT_NAMESPACE, &claim),
+			vec![b"alpha".to_vec(), b"zeta".to_vec()]
+		);
+
+		assert_ok!(TemplateModule::remove_tag(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			b"zeta".to_vec()
+		));
+		System::assert_last_event(
+			Event::ClaimTagRemoved {
+				namespace: DEFAULT_NAMESPACE,
+				claim,
+				tag: b"zeta".to_vec().try_into().unwrap(),
+			}
+			.into(),
+		);
+		assert_eq!(TemplateModule::tags_of(DEFAULT_NAMESPACE, &claim), vec![b"alpha".to_vec()]);
+		assert_noop!(
+			TemplateModule::remove_tag(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				b"zeta".to_vec()
+			),
+			Error::<Test>::NoSuchTag
+		);
+
+		// Removing the last tag leaves no entry behind.
+		assert_ok!(TemplateModule::remove_tag(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			b"alpha".to_vec()
+		));
+		assert!(!ClaimTags::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+	});
+}
+
+#[test]
+fn add_tag_rejects_duplicates_and_too_many_tags() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		create_claims_for(1, &[claim]);
+		assert_ok!(TemplateModule::add_tag(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			b"one".to_vec()
+		));
+
+		assert_noop!(
+			TemplateModule::add_tag(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				b"one".to_vec()
+			),
+			Error::<Test>::DuplicateTag
+		);
+
+		// The mock allows two tags per claim.
+		assert_ok!(TemplateModule::add_tag(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			b"two".to_vec()
+		));
+		assert_noop!(
+			TemplateModule::add_tag(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				b"three".to_vec()
+			),
+			Error::<Test>::TooManyTags
+		);
+		// A full set still reports a duplicate as such.
+		assert_noop!(
+			TemplateModule::add_tag(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				b"two".to_vec()
+			),
+			Error::<Test>::DuplicateTag
+		);
+	});
+}
+
+#[test]
+fn add_tag_fails_for_bad_callers_and_input() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		assert_noop!(
+			TemplateModule::add_tag(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				b"tag".to_vec()
+			),
+			Error::<Test>::NoSuchClaim
+		);
+		create_claims_for(1, &[claim]);
+		assert_noop!(
+			TemplateModule::add_tag(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				claim,
+				b"tag".to_vec()
+			),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			TemplateModule::remove_tag(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				claim,
+				b"tag".to_vec()
+			),
+			Error::<Test>::NotClaimOwner
+		);
+		// The mock allows tags of up to eight bytes.
+		assert_noop!(
+			TemplateModule::add_tag(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				vec![b'a'; 9]
+			),
+			Error::<Test>::TagTooLong
+		);
+	});
+}
+
+#[test]
+fn revoke_claim_clears_tags() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(1);
+		create_claims_for(1, &[claim]);
+		assert_ok!(TemplateModule::add_tag(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim,
+			b"tag".to_vec()
+		));
+
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			claim
+		));
+		assert!(!ClaimTags::<Test>::contains_key(DEFAULT_NAMESPACE, claim));
+
+		// A new claim on the same hash starts without tags.
+		create_claims_for(2, &[claim]);
+		assert!(TemplateModule::tags_of(DEFAULT_NAMESPACE, &claim).is_empty());
+	});
+}
+
+#[test]
+fn owners_set_and_clear_aliases() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		create_claims_for(1, &[first, second]);
+		let set_alias = |claim, alias: &[u8]| {
+			TemplateModule::set_alias(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				alias.to_vec(),
+			)
+		};
+
+		assert_ok!(set_alias(first, b"report"));
+		System::assert_last_event(
+			Event::ClaimAliasSet {
+				namespace: DEFAULT_NAMESPACE,
+				claim: first,
+				alias: b"report".to_vec().try_into().unwrap(),
+			}
+			.into(),
+		);
+		assert_eq!(TemplateModule::resolve_alias(DEFAULT_NAMESPACE, b"report"), Some(first));
+		assert_eq!(TemplateModule::resolve_alias(7, b"report"), None);
+
+		// A new alias replaces the previous one, which is freed.
+		assert_ok!(set_alias(first, b"draft"));
+		assert_eq!(TemplateModule::resolve_alias(DEFAULT_NAMESPACE, b"report"), None);
+		assert_eq!(TemplateModule::resolve_alias(DEFAULT_NAMESPACE, b"draft"), Some(first));
+		assert_ok!(set_alias(second, b"report"));
+
+		assert_ok!(TemplateModule::clear_alias(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, first));
+		System::assert_last_event(
+			Event::ClaimAliasCleared {
+				namespace: DEFAULT_NAMESPACE,
+				claim: first,
+				alias: b"draft".to_vec().try_into().unwrap(),
+			}
+			.into(),
+		);
+		assert_eq!(TemplateModule::resolve_alias(DEFAULT_NAMESPACE, b"draft"), None);
+		assert_eq!(TemplateModule::alias_of(DEFAULT_NAMESPACE, &first), None);
+		assert_noop!(
+			TemplateModule::clear_alias(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, first),
+			Error::<Test>::AliasNotFound
+		);
+		assert_ok!(TemplateModule::do_try_state(1));
+	});
+}
+
+#[test]
+fn aliases_are_unique_within_a_namespace() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (first, second, third) =
+			(H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3));
+		create_claims_for(1, &[first, second]);
+		create_claims_for(2, &[third]);
+		assert_ok!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(2),
+			7,
+			first,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(TemplateModule::set_alias(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			first,
+			b"report".to_vec()
+		));
+
+		// Neither the same owner, nor another one, nor the claim itself can take it again.
+		for (who, claim) in [(1, second), (2, third), (1, first)] {
+			assert_noop!(
+				TemplateModule::set_alias(
+					RuntimeOrigin::signed(who),
+					DEFAULT_NAMESPACE,
+					claim,
+					b"report".to_vec()
+				),
+				Error::<Test>::AliasTaken
+			);
+		}
+		// Other namespaces have their own aliases.
+		assert_ok!(TemplateModule::set_alias(
+			RuntimeOrigin::signed(2),
+			7,
+			first,
+			b"report".to_vec()
+		));
+		assert_eq!(TemplateModule::resolve_alias(7, b"report"), Some(first));
+
+		// Only the owner names a claim, and only with an alias that fits.
+		assert_noop!(
+			TemplateModule::set_alias(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				second,
+				b"other".to_vec()
+			),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			TemplateModule::clear_alias(RuntimeOrigin::signed(2), DEFAULT_NAMESPACE, first),
+			Error::<Test>::NotClaimOwner
+		);
+		// The mock allows aliases of up to eight bytes.
+		assert_noop!(
+			TemplateModule::set_alias(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				second,
+				b"too-long-alias".to_vec()
+			),
+			Error::<Test>::AliasTooLong
+		);
+		assert_eq!(TemplateModule::resolve_alias(DEFAULT_NAMESPACE, b"too-long-alias"), None);
+	});
+}
+
+#[test]
+fn removing_a_claim_frees_its_alias() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (first, second) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		create_claims_for(1, &[first]);
+		create_claims_for(2, &[second]);
+		assert_ok!(TemplateModule::set_alias(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			first,
+			b"report".to_vec()
+		));
+
+		// The alias follows the claim to its new owner.
+		assert_ok!(TemplateModule::transfer_claim(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			first,
+			3
+		));
+		assert_eq!(TemplateModule::resolve_alias(DEFAULT_NAMESPACE, b"report"), Some(first));
+
+		assert_ok!(TemplateModule::revoke_claim(
+			RuntimeOrigin::signed(3),
+			DEFAULT_NAMESPACE,
+			first
+		));
+		assert_eq!(TemplateModule::resolve_alias(DEFAULT_NAMESPACE, b"report"), None);
+		assert!(!crate::ClaimAliases::<Test>::contains_key(DEFAULT_NAMESPACE, first));
+		assert_ok!(TemplateModule::set_alias(
+			RuntimeOrigin::signed(2),
+			DEFAULT_NAMESPACE,
+			second,
+			b"report".to_vec()
+		));
+		assert_ok!(TemplateModule::do_try_state(1));
+	});
+}
+
+#[test]
+fn claims_are_rate_limited_by_the_min_claim_interval() {
+	new_test_ext().execute_with(|| {
+		MinClaimInterval::set(3);
+		let claims: Vec<_> = (1..=4).map(H256::repeat_byte).collect();
+		let create = |claim| {
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claim,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None,
+			)
+		};
+
+		System::set_block_number(1);
+		assert_ok!(create(claims[0]));
+		assert_eq!(crate::LastClaimAt::<Test>::get(1), Some(1));
+		assert_noop!(create(claims[1]), Error::<Test>::TooSoon);
+		// Other accounts are not held back.
+		create_claims_for(2, &[claims[1]]);
+
+		// One block short of the interval.
+		System::set_block_number(3);
+		assert_noop!(create(claims[2]), Error::<Test>::TooSoon);
+		assert_noop!(
+			TemplateModule::create_pending_claim(
+				RuntimeOrigin::signed(1),
+				DEFAULT_NAMESPACE,
+				claims[2],
+				2
+			),
+			Error::<Test>::TooSoon
+		);
+
+		// Exactly the interval later, and the clock starts again.
+		System::set_block_number(4);
+		assert_ok!(create(claims[2]));
+		assert_eq!(crate::LastClaimAt::<Test>::get(1), Some(4));
+
+		// A batch counts as a single claim.
+		System::set_block_number(7);
+		assert_ok!(TemplateModule::revoke_all(RuntimeOrigin::signed(1)));
+		assert_ok!(TemplateModule::create_claims(
+			RuntimeOrigin::signed(1),
+			DEFAULT_NAMESPACE,
+			vec![claims[0], claims[3]].try_into().unwrap(),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			false
+		));
+		assert_noop!(create(claims[2]), Error::<Test>::TooSoon);
+	});
+}
+
+#[test]
+fn claims_are_not_rate_limited_by_default() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_claims_for(1, &[H256::repeat_byte(1), H256::repeat_byte(2)]);
+		assert!(!crate::LastClaimAt::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn create_claim_fails_once_the_total_claim_capacity_is_reached() {
+	new_test_ext().execute_with(|| {
+		MaxTotalClaims::set(3);
+		let [a, b, c, d] = [1, 2, 3, 4].map(H256::repeat_byte);
+		create_claims_for(1, &[a, b]);
+		create_claims_for(2, &[c]);
+
+		// The cap is chain-wide, so an account far from its own limit is turned away too.
+		assert_noop!(
+			TemplateModule::create_claim(
+				RuntimeOrigin::signed(2),
+				DEFAULT_NAMESPACE,
+				d,
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::ClaimCapacityReached
+		);
+		assert_noop!(
+			TemplateModule::create_claims(
+				RuntimeOrigin::signed(2),
+				7,
+				vec![d].try_into().unwrap(),
+				ClaimKind::Document,
+				HashAlgo::Blake2_256,
+				false
+			),
+			Error::<Test>::ClaimCapacityReached
+		);
+
+		// Revoking a claim frees room for another.
+		assert_ok!(TemplateModule::revoke_claim(RuntimeOrigin::signed(1), DEFAULT_NAMESPACE, a));
+		create_claims_for(2, &[d]);
+		assert_eq!(
+			TemplateModule::claim_info(DEFAULT_NAMESPACE, d).map(|(owner, _)| owner),
+			Some(2)
+		);
+	});
+}
+
+#[test]
+fn claim_from_storage_key_recovers_the_key_of_a_claim() {
+	new_test_ext().execute_with(|| {
+		let claim = H256::repeat_byte(7);
+		let raw = Claims::<Test>::hashed_key_for(3, claim);
+		assert_eq!(TemplateModule::claim_from_storage_key(&raw), Some((3, claim)));
+
+		// The keys listed from storage decode the same way.
+		create_claims_for(1, &[claim]);
+		let prefix = Claims::<Test>::final_prefix();
+		let key = sp_io::storage::next_key(&prefix).unwrap();
+		assert_eq!(TemplateModule::claim_from_storage_key(&key), Some((DEFAULT_NAMESPACE, claim)));
+
+		assert_eq!(TemplateModule::claim_from_storage_key(&raw[..raw.len() - 1]), None);
+		assert_eq!(TemplateModule::claim_from_storage_key(&[&raw[..], &[0]].concat()), None);
+		assert_eq!(TemplateModule::claim_from_storage_key(&prefix), None);
+		// A key of another map with the same hashers.
+		let metadata = ClaimMetadata::<Test>::hashed_key_for(3, claim);
+		assert_eq!(TemplateModule::claim_from_storage_key(&metadata), None);
+		// A hash that does not match the key after it.
+		let mut tampered = raw.clone();
+		tampered[32] ^= 1;
+		assert_eq!(TemplateModule::claim_from_storage_key(&tampered), None);
+	});
+}
+
+#[test]
+fn every_storage_item_reports_its_bounded_size() {
+	use codec::MaxEncodedLen;
+	use frame_support::traits::StorageInfoTrait;
+
+	// The largest encoded key of each hasher, from the mock's `u16` namespaces, `H256` claims,
+	// `u64` accounts and `u64` block numbers.
+	let namespace_key = 16 + 2;
+	let claim_key = 16 + 32;
+	let account_key = 16 + 8;
+	let block_key = 8 + 8;
+	let claim_ref = 2 + 32;
+	let claim_list = |max: u32| 1 + max * claim_ref;
+	let by_claim = namespace_key + claim_key;
+	// Every item is listed here, so a new one must be added along with its bounds.
+	let expected: [(&str, Option<u32>, u32); 29] = [
+		("Something", Some(1), 4),
+		("Claims", None, by_claim + ClaimInfoOf::<Test>::max_encoded_len() as u32),
+		("ClaimCount", Some(1), 4),
+		("NextClaimId", Some(1), 8),
+		("Paused", Some(1), 1),
+		("ClaimLifetimeOverride", Some(1), 8),
+		("ClaimsByOwner", None, account_key + 16 + claim_ref),
+		("ClaimCountByOwner", None, account_key + 4),
+		("ClaimsByBlock", None, block_key + claim_list(MaxClaimsPerBlock::get())),
+		// Up to 32 bytes of metadata, behind a one-byte length.
+		("ClaimMetadata", None, by_claim + 1 + 32),
+		// Up to two tags of eight bytes each, every one behind a one-byte length.
+		("ClaimTags", None, by_claim + 1 + 2 * (1 + 8)),
+		("ExpiringAt", None, block_key + claim_list(2)),
+		("CleanupCursor", Some(1), 8),
+		("ReindexCursor", Some(1), claim_ref),
+		("PendingClaims", None, by_claim + crate::PendingClaimOf::<Test>::max_encoded_len() as u32),
+		("PendingExpiringAt", None, block_key + claim_list(2)),
+		("Approvals", None, by_claim + 8),
+		("ClaimHistory", None, by_claim + crate::ClaimHistoryOf::<Test>::max_encoded_len() as u32),
+		("Allowlist", None, account_key),
+		("ClaimsRoot", Some(1), 32),
+		// Aliases of up to eight bytes, behind a one-byte length.
+		("Aliases", None, namespace_key + 16 + 1 + 8 + 32),
+		("ClaimAliases", None, by_claim + 1 + 8),
+		("LastClaimAt", None, account_key + 8),
+		("FrozenClaims", None, by_claim),
+		("RevokedClaims", None, by_claim + crate::RevokedClaimOf::<Test>::max_encoded_len() as u32),
+		// Identifiers of up to 64 bytes, behind a length that takes two bytes from 64 on.
+		("ClaimCids", None, by_claim + 2 + 64),
+		// Notes of up to 16 bytes, behind a one-byte length.
+		("ClaimNotes", None, by_claim + 1 + 16),
+		("ClaimCommittedAt", None, by_claim + 8),
+		("Commitments", None, account_key + 32 + 8),
+	];
+
+	let info = TemplateModule::storage_info();
+	for item in &info {
+		let name = String::from_utf8_lossy(&item.storage_name);
+		assert!(item.max_size.is_some(), "{name} is unbounded");
+	}
+	let actual: Vec<_> = info
+		.iter()
+		.map(|item| {
+			(
+				String::from_utf8(item.storage_name.clone()).unwrap(),
+				item.max_values,
+				item.max_size.unwrap(),
+			)
+		})
+		.collect();
+	let expected: Vec<_> = expected
+		.iter()
+		.map(|(name, max_values, max_size)| (name.to_string(), *max_values, *max_size))
+		.collect();
+	assert_eq!(actual, expected);
+}
+
+#[test]
+fn calls_keep_their_call_index() {
+	use crate::Call;
+	use codec::Encode;
+	use frame_support::traits::GetCallName;
+
+	let ns = DEFAULT_NAMESPACE;
+	let claim = H256::repeat_byte(1);
+	let batch = || vec![claim].try_into().unwrap();
+	// Signed transactions and clients encode calls by these indices, so a call must never change
+	// its place.
+	let expected: [(Call<Test>, u8); 35] = [
+		(Call::do_something { something: 0 }, 0),
+		(Call::cause_error {}, 1),
+		(
+			Call::create_claim {
+				namespace: ns,
+				claim,
+				kind: ClaimKind::Document,
+				algo: HashAlgo::Blake2_256,
+				metadata: None,
+				expiry: None,
+				lock_until: None,
+				cid: None,
+			},
+			2,
+		),
+		(Call::revoke_claim { namespace: ns, claim }, 3),
+		(Call::transfer_claim { namespace: ns, claim, dest: 2 }, 4),
+		(Call::force_revoke_claim { namespace: ns, claim, slash: false }, 5),
+		(
+			Call::create_claims {
+				namespace: ns,
+				claims: batch(),
+				kind: ClaimKind::Document,
+				algo: HashAlgo::Blake2_256,
+				skip_existing: false,
+			},
+			6,
+		),
+		(Call::renew_claim { namespace: ns, claim }, 7),
+		(Call::approve_transfer { namespace: ns, claim, to: 2 }, 8),
+		(Call::accept_transfer { namespace: ns, claim }, 9),
+		(
+			Call::create_claim_default {
+				claim,
+				kind: ClaimKind::Document,
+				algo: HashAlgo::Blake2_256,
+				metadata: None,
+				expiry: None,
+				lock_until: None,
+				cid: None,
+			},
+			10,
+		),
+		(Call::set_claim_metadata { namespace: ns, claim, metadata: None }, 11),
+		(Call::revoke_all {}, 12),
+		(Call::create_pending_claim { namespace: ns, claim, witness: 2 }, 13),
+		(Call::confirm_claim { namespace: ns, claim }, 14),
+		(Call::transfer_claim_bulk { namespace: ns, claims: batch(), dest: 2 }, 15),
+		(Call::set_paused { paused: true }, 16),
+		(Call::reindex_claims { limit: 1 }, 17),
+		(Call::create_claim_free { claim }, 18),
+		(Call::purge_expired { max: 1 }, 19),
+		(Call::add_tag { namespace: ns, claim, tag: b"tag".to_vec() }, 20),
+		(Call::remove_tag { namespace: ns, claim, tag: b"tag".to_vec() }, 21),
+		(Call::set_claim_lifetime { blocks: None }, 22),
+		(Call::set_manager { namespace: ns, claim, manager: None }, 23),
+		(Call::add_to_allowlist { who: 2 }, 24),
+		(Call::remove_from_allowlist { who: 2 }, 25),
+		(Call::set_alias { namespace: ns, claim, alias: b"alias".to_vec() }, 26),
+		(Call::clear_alias { namespace: ns, claim }, 27),
+		(Call::freeze_claim { namespace: ns, claim }, 28),
+		(Call::thaw_claim { namespace: ns, claim }, 29),
+		(Call::restore_claim { namespace: ns, claim }, 30),
+		(Call::commit_claim { commitment: claim }, 31),
+		(
+			Call::reveal_claim {
+				namespace: ns,
+				claim,
+				kind: ClaimKind::Document,
+				algo: HashAlgo::Blake2_256,
+				salt: [0; 32],
+			},
+			32,
+		),
+		(Call::set_owner_note { namespace: ns, claim, note: None }, 33),
+		(Call::revoke_claim_by_preimage { data: vec![] }, 34),
+	];
+	// Every call is listed above, so a new one must be added to the list, with a new index.
+	assert_eq!(Call::<Test>::get_call_names().len(), expected.len());
+	for (call, index) in expected {
+		assert_eq!(call.encode()[0], index, "{} moved", call.get_call_name());
+	}
+}
+
+#[test]
+fn events_name_the_claim_they_are_about() {
+	let ns = 7;
+	let claim = H256::repeat_byte(1);
+	let about = |event: Event<Test>| (event, Some(claim));
+	let tag = || b"tag".to_vec().try_into().unwrap();
+	let alias = || b"alias".to_vec().try_into().unwrap();
+	let expected = [
+		(Event::SomethingStored { something: 0, who: 1 }, None),
+		about(Event::ClaimCreated {
+			who: 1,
+			namespace: ns,
+			claim,
+			kind: ClaimKind::Document,
+			algo: HashAlgo::Blake2_256,
+			id: 0,
+			cid: None,
+		}),
+		about(Event::ClaimRevoked {
+			who: 1,
+			namespace: ns,
+			claim,
+			created_at: 1,
+			deposit_returned: 0,
+			id: 0,
+		}),
+		(Event::ClaimsBatchCreated { who: 1, namespace: ns, count: 2 }, None),
+		(
+			Event::ClaimsBatchPartiallyCreated {
+				who: 1,
+				namespace: ns,
+				created: 1,
+				skipped: vec![claim].try_into().unwrap(),
+			},
+			None,
+		),
+		about(Event::ClaimTransferred { from: 1, to: 2, namespace: ns, claim, id: 0 }),
+		about(Event::TransferApproved { namespace: ns, claim, owner: 1, to: 2 }),
+		about(Event::ClaimForceRevoked { namespace: ns, claim, by_owner: 1, id: 0 }),
+		about(Event::ClaimDepositSlashed { namespace: ns, claim, amount: 1 }),
+		about(Event::ClaimDepositDusted { namespace: ns, claim, amount: 1 }),
+		about(Event::ClaimRenewed { namespace: ns, claim, new_expiry: 2 }),
+		about(Event::ClaimExpired { namespace: ns, claim, id: 0 }),
+		(Event::ExpiredClaimsPurged { count: 1, more: false }, None),
+		(Event::AllClaimsRevoked { who: 1, count: 1, remaining: 0 }, None),
+		about(Event::ClaimMetadataUpdated { namespace: ns, claim }),
+		about(Event::ClaimPending { who: 1, witness: 2, namespace: ns, claim, expires_at: 2 }),
+		about(Event::ClaimConfirmed { witness: 2, namespace: ns, claim }),
+		about(Event::PendingClaimExpired { who: 1, namespace: ns, claim }),
+		(Event::ClaimsBulkTransferred { from: 1, to: 2, namespace: ns, count: 2 }, None),
+		(Event::PausedStateChanged { paused: true }, None),
+		about(Event::ClaimManagerSet { namespace: ns, claim, manager: None }),
+		(Event::AddedToAllowlist { who: 1 }, None),
+		(Event::RemovedFromAllowlist { who: 1 }, None),
+		(Event::ClaimLifetimeChanged { lifetime: None }, None),
+		(Event::ClaimsReindexed { count: 1, more: false }, None),
+		about(Event::ClaimTagAdded { namespace: ns, claim, tag: tag() }),
+		about(Event::ClaimTagRemoved { namespace: ns, claim, tag: tag() }),
+		about(Event::ClaimAliasSet { namespace: ns, claim, alias: alias() }),
+		about(Event::ClaimAliasCleared { namespace: ns, claim, alias: alias() }),
+		about(Event::ClaimFrozen { namespace: ns, claim }),
+		about(Event::ClaimThawed { namespace: ns, claim }),
+		about(Event::ClaimRestored { who: 1, namespace: ns, claim }),
+		(Event::ClaimCommitted { who: 1, commitment: claim }, None),
+		about(Event::ClaimRevealed { who: 1, namespace: ns, claim }),
+		about(Event::OwnerNoteSet { namespace: ns, claim, note: None }),
+		about(Event::ClaimSuperseded { namespace: ns, claim, by_owner: 1, id: 0 }),
+	];
+	// Every variant is listed above, so a new one must be added to the list.
+	let scale_info::TypeDef::Variant(variants) =
+		<Event<Test> as scale_info::TypeInfo>::type_info().type_def
+	else {
+		panic!("`Event` is an enum");
+	};
+	assert_eq!(variants.variants.len(), expected.len());
+	for (event, about) in expected {
+		assert_eq!(event.claim(), about.as_ref(), "{event:?}");
+		if about.is_some() {
+			assert_eq!(event.namespace(), Some(ns), "{event:?}");
+		}
+	}
+	// Batches name their namespace, but no single claim.
+	let batch = Event::<Test>::ClaimsBatchCreated { who: 1, namespace: ns, count: 2 };
+	assert_eq!(batch.namespace(), Some(ns));
+	assert_eq!(Event::<Test>::PausedStateChanged { paused: true }.namespace(), None);
+}
+
+#[test]
+fn claim_events_carry_the_hash_of_the_claim_as_their_topic() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = H256::repeat_byte(1);
+		let topic = <Test as frame_system::Config>::Hashing::hash_of(&claim);
+		create_claims_for(1, &[claim]);
+		assert_ok!(TemplateModule::set_paused(RuntimeOrigin::root(), true));
+
+		let records = System::events();
+		let created = records
+			.iter()
+			.position(|record| {
+				matches!(record.event, RuntimeEvent::TemplateModule(Event::ClaimCreated { .. }))
+			})
+			.expect("the claim was created");
+		assert_eq!(records[created].topics, vec![topic]);
+		assert_eq!(System::event_topics(topic), vec![(1, created as u32)]);
+		let paused = records.last().expect("the pallet was paused");
+		assert_eq!(paused.event, Event::PausedStateChanged { paused: true }.into());
+		assert!(paused.topics.is_empty());
+	});
+}
+
+#[test]
+fn error_variants_keep_their_module_error_index() {
+	use sp_runtime::ModuleError;
+
+	// Clients match on these indices, so a variant must never change its place.
+	let expected = [
+		(Error::<Test>::NoneValue, 0),
+		(Error::<Test>::StorageOverflow, 1),
+		(Error::<Test>::AlreadyClaimed, 2),
+		(Error::<Test>::NoSuchClaim, 3),
+		(Error::<Test>::NotClaimOwner, 4),
+		(Error::<Test>::CannotTransferToSelf, 5),
+		(Error::<Test>::MetadataTooLong, 6),
+		(Error::<Test>::TooManyExpiringInBlock, 7),
+		(Error::<Test>::TooManyClaims, 8),
+		(Error::<Test>::InsufficientBalance, 9),
+		(Error::<Test>::ExpiryDisabled, 10),
+		(Error::<Test>::NotApproved, 11),
+		(Error::<Test>::InvalidClaimHash, 12),
+		(Error::<Test>::ExpiryInPast, 13),
+		(Error::<Test>::ExpiryOverflow, 14),
+		(Error::<Test>::BlockClaimLimitReached, 15),
+		(Error::<Test>::ClaimIdsExhausted, 16),
+		(Error::<Test>::NoSuchPendingClaim, 17),
+		(Error::<Test>::WitnessMismatch, 18),
+		(Error::<Test>::Paused, 19),
+		(Error::<Test>::ClaimLocked, 20),
+		(Error::<Test>::TagTooLong, 21),
+		(Error::<Test>::TooManyTags, 22),
+		(Error::<Test>::DuplicateTag, 23),
+		(Error::<Test>::NoSuchTag, 24),
+		(Error::<Test>::ClaimCapacityReached, 25),
+		(Error::<Test>::NotAllowlisted, 26),
+		(Error::<Test>::AliasTaken, 27),
+		(Error::<Test>::AliasNotFound, 28),
+		(Error::<Test>::AliasTooLong, 29),
+		(Error::<Test>::TooSoon, 30),
+		(Error::<Test>::QueryTooLarge, 31),
+		(Error::<Test>::ClaimFrozen, 32),
+		(Error::<Test>::InvalidCid, 33),
+		(Error::<Test>::ClaimTombstoned, 34),
+		(Error::<Test>::NotTombstoned, 35),
+		(Error::<Test>::CommitmentNotFound, 36),
+		(Error::<Test>::RevealTooEarly, 37),
+		(Error::<Test>::BadReveal, 38),
+		(Error::<Test>::NoteTooLong, 39),
+		(Error::<Test>::PreimageTooLong, 40),
+		(Error::<Test>::DepositIncreaseByManager, 41),
+	];
+	// Every variant is listed above, so a new one must be added to the list, at the end.
+	let scale_info::TypeDef::Variant(variants) =
+		<Error<Test> as scale_info::TypeInfo>::type_info().type_def
+	else {
+		panic!("`Error` is an enum");
+	};
+	assert_eq!(variants.variants.len(), expected.len());
+	let pallet_index = <TemplateModule as frame_support::traits::PalletInfoAccess>::index() as u8;
+	for (error, index) in expected {
+		let name = format!("{error:?}");
+		assert!(
+			matches!(
+				DispatchError::from(error),
+				DispatchError::Module(ModuleError { index: p, error: [e, 0, 0, 0], .. })
+					if p == pallet_index && e == index
+			),
+			"{name} moved from index {index}",
+		);
+	}
+}
@@ -0,0 +1,114 @@
+//! Traits through which other pallets can build on the claims registry.
+
+use crate::{ClaimKind, Claims, Config, HashAlgo, NamespaceId, Pallet, DEFAULT_NAMESPACE};
+use alloc::vec::Vec;
+use frame_support::dispatch::DispatchResult;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::DispatchError;
+
+/// Read access to a registry of claims.
+///
+/// Pallets that need to know who owns a claim, such as a marketplace, should be generic over this
+/// trait rather than depend on this pallet directly.
+pub trait ClaimInspect<AccountId, Hash, BlockNumber> {
+	/// The account that owns `claim` in `namespace`, if it exists.
+	fn owner_of(namespace: NamespaceId, claim: &Hash) -> Option<AccountId>;
+
+	/// The block number at which `claim` was originally made in `namespace`, if it exists.
+	fn created_at(namespace: NamespaceId, claim: &Hash) -> Option<BlockNumber>;
+
+	/// All the claims owned by `owner`, in every namespace, or an error if there are too many to
+	/// read within a block.
+	fn claims_of(owner: &AccountId) -> Result<Vec<(NamespaceId, Hash)>, DispatchError>;
+}
+
+impl<T: Config> ClaimInspect<T::AccountId, T::ClaimId, BlockNumberFor<T>> for Pallet<T> {
+	fn owner_of(namespace: NamespaceId, claim: &T::ClaimId) -> Option<T::AccountId> {
+		Pallet::<T>::owner_of(namespace, claim)
+	}
+
+	fn created_at(namespace: NamespaceId, claim: &T::ClaimId) -> Option<BlockNumberFor<T>> {
+		Pallet::<T>::created_at(namespace, claim)
+	}
+
+	fn claims_of(owner: &T::AccountId) -> Result<Vec<(NamespaceId, T::ClaimId)>, DispatchError> {
+		Pallet::<T>::try_claims_of(owner).map_err(Into::into)
+	}
+}
+
+/// A registry of claims in the default namespace that other pallets can both read and add to.
+///
+/// The runtime can hand this pallet to another one as, say, `T::Registry: ClaimRegistry`, without
+/// that pallet depending on this one or on its storage.
+pub trait ClaimRegistry {
+	/// The accounts that own claims.
+	type AccountId;
+	/// The hashes that are claimed.
+	type Hash;
+
+	/// Whether `claim` is claimed.
+	fn contains(claim: &Self::Hash) -> bool;
+
+	/// The account that owns `claim`, if it is claimed.
+	fn owner(claim: &Self::Hash) -> Option<Self::AccountId>;
+
+	/// Claim `claim` for `who`.
+	fn register(who: Self::AccountId, claim: Self::Hash) -> DispatchResult;
+}
+
+/// Claims registered through this trait are made as with [`Pallet::create_claim_free`], except
+/// that the deposit is held from `who` as usual. [`Config::MinClaimInterval`] is left for the
+/// calling pallet to enforce.
+impl<T: Config> ClaimRegistry for Pallet<T> {
+	type AccountId = T::AccountId;
+	type Hash = T::ClaimId;
+
+	fn contains(claim: &T::ClaimId) -> bool {
+		Claims::<T>::contains_key(DEFAULT_NAMESPACE, claim)
+	}
+
+	fn owner(claim: &T::ClaimId) -> Option<T::AccountId> {
+		Pallet::<T>::owner_of(DEFAULT_NAMESPACE, claim)
+	}
+
+	fn register(who: T::AccountId, claim: T::ClaimId) -> DispatchResult {
+		Pallet::<T>::ensure_not_paused()?;
+		Pallet::<T>::do_create_claim(
+			&who,
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Other(0),
+			None,
+			None,
+			None,
+			None,
+		)
+	}
+}
+
+/// A handler for changes to the claims registry.
+///
+/// Pallets that keep state derived from claims, such as a reputation score, can be notified
+/// through [`Config::OnClaimChanged`] instead of scanning the registry. Several handlers can be
+/// combined in a tuple. The handlers run as part of the call that changed the claim, and their cost
+/// is not included in its weight, so they should be cheap.
+pub trait OnClaimChanged<AccountId, Hash> {
+	/// `claim` has been created in `namespace`, owned by `owner`.
+	fn on_created(namespace: NamespaceId, owner: &AccountId, claim: &Hash);
+
+	/// `claim` has been removed from `namespace` while owned by `owner`, whether it was revoked,
+	/// force-revoked or expired.
+	fn on_revoked(namespace: NamespaceId, owner: &AccountId, claim: &Hash);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId, Hash> OnClaimChanged<AccountId, Hash> for Tuple {
+	fn on_created(namespace: NamespaceId, owner: &AccountId, claim: &Hash) {
+		for_tuples!( #( Tuple::on_created(namespace, owner, claim); )* );
+	}
+
+	fn on_revoked(namespace: NamespaceId, owner: &AccountId, claim: &Hash) {
+		for_tuples!( #( Tuple::on_revoked(namespace, owner, claim); )* );
+	}
+}
@@ -0,0 +1,135 @@
+//! Named genesis presets of the runtime.
+//!
+//! The presets are exposed through the `GenesisBuilder` runtime API, so they can be listed and
+//! built by tools such as `chain-spec-builder` without going through the node.
+
+use crate::{
+	AccountId, AuraConfig, BalancesConfig, BlockNumber, GrandpaConfig, Hash, RuntimeGenesisConfig,
+	SudoConfig, TemplateModuleConfig,
+};
+use alloc::{vec, vec::Vec};
+use pallet_grandpa::AuthorityId as GrandpaId;
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_genesis_builder::PresetId;
+use sp_keyring::{AccountKeyring, Ed25519Keyring};
+use sp_runtime::traits::{BlakeTwo256, Hash as _};
+
+/// A local testnet whose template pallet starts out with a handful of demo claims.
+pub const LOCAL_TESTNET_WITH_CLAIMS_PRESET: &str = "local_testnet_with_claims";
+
+/// The documents behind the demo claims, with the account owning each of them.
+const DEMO_CLAIMS: [(&[u8], AccountKeyring); 4] = [
+	(b"demo claim: alice #1", AccountKeyring::Alice),
+	(b"demo claim: alice #2", AccountKeyring::Alice),
+	(b"demo claim: bob #1", AccountKeyring::Bob),
+	(b"demo claim: bob #2", AccountKeyring::Bob),
+];
+
+/// The claims seeded by [`LOCAL_TESTNET_WITH_CLAIMS_PRESET`].
+///
+/// The claimed hashes are the `BlakeTwo256` hashes of fixed strings, so they are the same on every
+/// chain built from the preset.
+pub fn demo_claims() -> Vec<(Hash, AccountId, BlockNumber)> {
+	DEMO_CLAIMS
+		.iter()
+		.map(|(document, owner)| (BlakeTwo256::hash(document), owner.to_account_id(), 0))
+		.collect()
+}
+
+/// Configure initial storage state for FRAME modules.
+fn testnet_genesis(
+	initial_authorities: Vec<(AuraId, GrandpaId)>,
+	root_key: AccountId,
+	endowed_accounts: Vec<AccountId>,
+	initial_claims: Vec<(Hash, AccountId, BlockNumber)>,
+) -> serde_json::Value {
+	let config = RuntimeGenesisConfig {
+		balances: BalancesConfig {
+			// Configure endowed accounts with initial balance of 1 << 60.
+			balances: endowed_accounts.iter().cloned().map(|k| (k, 1u128 << 60)).collect(),
+		},
+		aura: AuraConfig { authorities: initial_authorities.iter().map(|x| x.0.clone()).collect() },
+		grandpa: GrandpaConfig {
+			authorities: initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect(),
+			..Default::default()
+		},
+		sudo: SudoConfig {
+			// Assign network admin rights.
+			key: Some(root_key),
+		},
+		template_module: TemplateModuleConfig { initial_claims },
+		..Default::default()
+	};
+
+	serde_json::to_value(config).expect("Could not build genesis config.")
+}
+
+/// The genesis of [`LOCAL_TESTNET_WITH_CLAIMS_PRESET`].
+fn local_testnet_with_claims_genesis() -> serde_json::Value {
+	testnet_genesis(
+		// Initial PoA authorities
+		vec![
+			(AccountKeyring::Alice.public().into(), Ed25519Keyring::Alice.public().into()),
+			(AccountKeyring::Bob.public().into(), Ed25519Keyring::Bob.public().into()),
+		],
+		// Sudo account
+		AccountKeyring::Alice.to_account_id(),
+		// Pre-funded accounts
+		AccountKeyring::iter().map(|k| k.to_account_id()).collect(),
+		demo_claims(),
+	)
+}
+
+/// Provides the JSON representation of the genesis config for the given preset `id`.
+pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
+	let config = match core::str::from_utf8(id.as_ref()).ok()? {
+		LOCAL_TESTNET_WITH_CLAIMS_PRESET => local_testnet_with_claims_genesis(),
+		_ => return None,
+	};
+	Some(
+		serde_json::to_string(&config)
+			.expect("serialization to json is expected to work. qed.")
+			.into_bytes(),
+	)
+}
+
+/// The ids of all the presets provided by the runtime.
+pub fn preset_names() -> Vec<PresetId> {
+	vec![PresetId::from(LOCAL_TESTNET_WITH_CLAIMS_PRESET)]
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::storage::storage_prefix;
+	use sp_runtime::BuildStorage;
+
+	fn preset_config(id: &'static str) -> RuntimeGenesisConfig {
+		let preset = get_preset(&PresetId::from(id)).expect("the preset is listed; qed");
+		serde_json::from_slice(&preset).expect("the preset is a valid genesis config")
+	}
+
+	#[test]
+	fn presets_are_listed() {
+		for id in preset_names() {
+			assert!(get_preset(&id).is_some());
+		}
+		assert!(get_preset(&PresetId::from("no_such_preset")).is_none());
+	}
+
+	#[test]
+	fn local_testnet_with_claims_seeds_the_demo_claims() {
+		let config = preset_config(LOCAL_TESTNET_WITH_CLAIMS_PRESET);
+		let initial_claims = &config.template_module.initial_claims;
+		assert_eq!(initial_claims, &demo_claims());
+		assert!(initial_claims
+			.iter()
+			.any(|(_, owner, _)| owner == &AccountKeyring::Alice.into()));
+		assert!(initial_claims.iter().any(|(_, owner, _)| owner == &AccountKeyring::Bob.into()));
+
+		let storage = config.build_storage().expect("the preset builds");
+		let prefix = storage_prefix(b"TemplateModule", b"Claims");
+		let claims = storage.top.keys().filter(|key| key.starts_with(&prefix)).count();
+		assert_eq!(claims, DEMO_CLAIMS.len());
+	}
+}
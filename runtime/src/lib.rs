@@ -4,8 +4,9 @@
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 extern crate alloc;
-use alloc::{vec, vec::Vec};
+use alloc::vec::Vec;
 use pallet_grandpa::AuthorityId as GrandpaId;
+use scale_info::TypeInfo;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
@@ -13,12 +14,13 @@ use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, MultiSignature,
+	ApplyExtrinsicResult, MultiSignature, RuntimeDebug,
 };
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
+use codec::{Decode, Encode, MaxEncodedLen};
 pub use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
 	traits::{
@@ -35,9 +37,10 @@ pub use frame_support::{
 };
 use frame_support::{
 	genesis_builder_helper::{build_state, get_preset},
-	traits::VariantCountOf,
+	traits::{EitherOfDiverse, InstanceFilter, VariantCountOf},
 };
 pub use frame_system::Call as SystemCall;
+use frame_system::EnsureRoot;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
@@ -48,6 +51,11 @@ pub use sp_runtime::{Perbill, Permill};
 /// Import the template pallet.
 pub use pallet_template;
 
+pub mod genesis_config_presets;
+
+#[cfg(test)]
+mod tests;
+
 /// An index to a block.
 pub type BlockNumber = u32;
 
@@ -101,12 +109,14 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// The version of the runtime specification. A full node will not attempt to use its native
 	//   runtime in substitute for the on-chain Wasm runtime unless all of `spec_name`,
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
-	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
+	// This value started at 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 102,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	// Bumped whenever the extrinsic format changes, such as when `SignedExtra` gains
+	//   `CheckDuplicateClaim`.
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -225,7 +235,7 @@ impl pallet_balances::Config for Runtime {
 	type FreezeIdentifier = RuntimeFreezeReason;
 	type MaxFreezes = VariantCountOf<RuntimeFreezeReason>;
 	type RuntimeHoldReason = RuntimeHoldReason;
-	type RuntimeFreezeReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
 }
 
 parameter_types! {
@@ -247,10 +257,116 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const ClaimCouncilMotionDuration: BlockNumber = 3 * DAYS;
+	pub MaxProposalWeight: Weight = Perbill::from_percent(50) * BlockWeights::get().max_block;
+}
+
+/// The collective of the claim administrators.
+pub type ClaimCouncilInstance = pallet_collective::Instance1;
+
+impl pallet_collective::Config<ClaimCouncilInstance> for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Proposal = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type MotionDuration = ClaimCouncilMotionDuration;
+	type MaxProposals = ConstU32<100>;
+	type MaxMembers = ConstU32<100>;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+	type SetMembersOrigin = EnsureRoot<AccountId>;
+	type MaxProposalWeight = MaxProposalWeight;
+}
+
+/// The origin of the claim administrators: root, or at least half of the claim council.
+pub type EnsureClaimAdmin = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	pallet_collective::EnsureProportionAtLeast<AccountId, ClaimCouncilInstance, 1, 2>,
+>;
+
+/// The origin of the accounts that register claims without fees: the members of the claim
+/// council.
+pub type EnsureFeeExempt = pallet_collective::EnsureMember<AccountId, ClaimCouncilInstance>;
+
 /// Configure the pallet-template in pallets/template.
+///
+/// The limits are taken from
+/// [`SolochainDefaultConfig`](`struct@pallet_template::config_preludes::SolochainDefaultConfig`),
+/// and only the types that depend on this runtime are given here.
+#[derive_impl(pallet_template::config_preludes::SolochainDefaultConfig)]
 impl pallet_template::Config for Runtime {
-	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
+	type ClaimId = Hash;
+	type ClaimLifetime = ConstU32<{ 365 * DAYS }>;
+	type Fungible = Balances;
+	// There is no treasury in this runtime, so slashed deposits are burned.
+	type SlashDestination = ();
+	type DustDestination = <Runtime as pallet_balances::Config>::DustRemoval;
+	type ClaimDeposit = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+	type MetadataDepositPerByte = ConstU128<{ EXISTENTIAL_DEPOSIT / 10 }>;
+	type ClaimAdminOrigin = EnsureClaimAdmin;
+	type FeeExemptOrigin = EnsureFeeExempt;
+	type MaxQueryRange = ConstU32<100>;
+	type PendingTimeout = ConstU32<{ 7 * DAYS }>;
+	type MinClaimInterval = ConstU32<0>;
+	type RevealDelay = ConstU32<MINUTES>;
+	type BlockNumberProvider = System;
+}
+
+/// The kinds of proxy that an account can give to a delegate.
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+	Default,
+)]
+pub enum ProxyType {
+	/// The delegate can make any call on behalf of the account.
+	#[default]
+	Any,
+	/// The delegate can only make the calls of the template pallet, such as registering claims
+	/// from a hot key on behalf of a cold wallet.
+	Claims,
+}
+
+impl InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, c: &RuntimeCall) -> bool {
+		match self {
+			ProxyType::Any => true,
+			ProxyType::Claims => matches!(c, RuntimeCall::TemplateModule(..)),
+		}
+	}
+
+	fn is_superset(&self, o: &Self) -> bool {
+		match (self, o) {
+			(x, y) if x == y => true,
+			(ProxyType::Any, _) => true,
+			_ => false,
+		}
+	}
+}
+
+impl pallet_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+	type ProxyDepositFactor = ConstU128<{ EXISTENTIAL_DEPOSIT }>;
+	type MaxProxies = ConstU32<32>;
+	type WeightInfo = pallet_proxy::weights::SubstrateWeight<Runtime>;
+	type MaxPending = ConstU32<32>;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+	type AnnouncementDepositFactor = ConstU128<{ 2 * EXISTENTIAL_DEPOSIT }>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -294,6 +410,12 @@ mod runtime {
 	// Include the custom logic from the pallet-template in the runtime.
 	#[runtime::pallet_index(7)]
 	pub type TemplateModule = pallet_template;
+
+	#[runtime::pallet_index(8)]
+	pub type Proxy = pallet_proxy;
+
+	#[runtime::pallet_index(9)]
+	pub type ClaimCouncil = pallet_collective<Instance1>;
 }
 
 /// The address format for describing accounts.
@@ -312,13 +434,28 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_template::CheckDuplicateClaim<Runtime>,
 );
 
 /// All migrations of the runtime, aside from the ones declared in the pallets.
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_template::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_template::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_template::migrations::v3::MigrateV2ToV3<Runtime>,
+	pallet_template::migrations::v4::MigrateV3ToV4<Runtime>,
+	pallet_template::migrations::v5::MigrateV4ToV5<Runtime>,
+	pallet_template::migrations::v6::MigrateV5ToV6<Runtime, Balances>,
+	pallet_template::migrations::v7::MigrateV6ToV7<Runtime>,
+	pallet_template::migrations::v8::MigrateV7ToV8<Runtime>,
+	pallet_template::migrations::v9::MigrateV8ToV9<Runtime>,
+	pallet_template::migrations::v10::MigrateV9ToV10<Runtime>,
+	pallet_template::migrations::v11::MigrateV10ToV11<Runtime>,
+	pallet_template::migrations::v12::MigrateV11ToV12<Runtime>,
+	pallet_template::migrations::v13::MigrateV12ToV13<Runtime>,
+);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -337,12 +474,21 @@ pub type Executive = frame_executive::Executive<
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benches {
+	use super::Runtime;
+
+	// Implemented here rather than in `dispatch_benchmark`, as newer compilers reject trait
+	// implementations nested in a function body.
+	impl frame_system_benchmarking::Config for Runtime {}
+	impl frame_benchmarking::baseline::Config for Runtime {}
+
 	frame_benchmarking::define_benchmarks!(
 		[frame_benchmarking, BaselineBench::<Runtime>]
 		[frame_system, SystemBench::<Runtime>]
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
 		[pallet_sudo, Sudo]
+		[pallet_collective, ClaimCouncil]
+		[pallet_proxy, Proxy]
 		[pallet_template, TemplateModule]
 	);
 }
@@ -515,6 +661,101 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_template_runtime_api::TemplateApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+		fn get_claim(claim: Hash) -> Option<(AccountId, BlockNumber)> {
+			TemplateModule::claim_info(pallet_template::DEFAULT_NAMESPACE, claim)
+		}
+
+		fn claims_of(owner: AccountId) -> Vec<Hash> {
+			TemplateModule::claims_of(&owner)
+				.into_iter()
+				.filter_map(|(namespace, claim)| {
+					(namespace == pallet_template::DEFAULT_NAMESPACE).then_some(claim)
+				})
+				.collect()
+		}
+
+		fn claims_of_kind(kind: pallet_template::ClaimKind) -> Vec<Hash> {
+			TemplateModule::claims_of_kind(kind)
+				.into_iter()
+				.filter_map(|(namespace, claim)| {
+					(namespace == pallet_template::DEFAULT_NAMESPACE).then_some(claim)
+				})
+				.collect()
+		}
+
+		fn claims_of_paged(
+			owner: AccountId,
+			start_key: Option<Hash>,
+			limit: u32,
+		) -> (Vec<Hash>, Option<Hash>) {
+			TemplateModule::claims_of_paged(
+				&owner,
+				pallet_template::DEFAULT_NAMESPACE,
+				start_key,
+				limit,
+			)
+		}
+
+		fn claims_paged(
+			start_key: Option<Hash>,
+			limit: u32,
+		) -> (Vec<(Hash, AccountId, BlockNumber)>, Option<Hash>) {
+			TemplateModule::claims_paged(pallet_template::DEFAULT_NAMESPACE, start_key, limit)
+		}
+
+		fn resolve_claim(claim: Hash) -> Option<(AccountId, BlockNumber, BlockNumber)> {
+			TemplateModule::resolve_claim(pallet_template::DEFAULT_NAMESPACE, claim)
+		}
+
+		fn verify_preimage(
+			namespace: Option<pallet_template::NamespaceId>,
+			data: Vec<u8>,
+		) -> Option<(AccountId, BlockNumber)> {
+			let claim = TemplateModule::claim_of_preimage(&data)?;
+			TemplateModule::claim_info(
+				namespace.unwrap_or(pallet_template::DEFAULT_NAMESPACE),
+				claim,
+			)
+		}
+
+		fn claims_between(
+			from: BlockNumber,
+			to: BlockNumber,
+		) -> Option<Vec<(Hash, AccountId)>> {
+			TemplateModule::claims_between(from, to).map(|claims| {
+				claims
+					.into_iter()
+					.filter_map(|(namespace, claim, owner)| {
+						(namespace == pallet_template::DEFAULT_NAMESPACE).then_some((claim, owner))
+					})
+					.collect()
+			})
+		}
+
+		fn claims_root() -> Hash {
+			TemplateModule::claims_root()
+		}
+
+		fn resolve_alias(alias: Vec<u8>) -> Option<Hash> {
+			TemplateModule::resolve_alias(pallet_template::DEFAULT_NAMESPACE, &alias)
+		}
+
+		fn stats() -> pallet_template::ClaimStats {
+			TemplateModule::stats()
+		}
+
+		fn all_claims_paged(
+			start_key: Option<(pallet_template::NamespaceId, Hash)>,
+			limit: u32,
+		) -> (
+			Vec<(pallet_template::NamespaceId, Hash, AccountId, BlockNumber)>,
+			Option<(pallet_template::NamespaceId, Hash)>,
+		) {
+			TemplateModule::all_claims_paged(start_key, limit)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -542,9 +783,6 @@ impl_runtime_apis! {
 			use frame_system_benchmarking::Pallet as SystemBench;
 			use baseline::Pallet as BaselineBench;
 
-			impl frame_system_benchmarking::Config for Runtime {}
-			impl baseline::Config for Runtime {}
-
 			use frame_support::traits::WhitelistedStorageKeys;
 			let whitelist: Vec<TrackedStorageKey> = AllPalletsWithSystem::whitelisted_storage_keys();
 
@@ -584,11 +822,11 @@ impl_runtime_apis! {
 		}
 
 		fn get_preset(id: &Option<sp_genesis_builder::PresetId>) -> Option<Vec<u8>> {
-			get_preset::<RuntimeGenesisConfig>(id, |_| None)
+			get_preset::<RuntimeGenesisConfig>(id, genesis_config_presets::get_preset)
 		}
 
 		fn preset_names() -> Vec<sp_genesis_builder::PresetId> {
-			vec![]
+			genesis_config_presets::preset_names()
 		}
 	}
 }
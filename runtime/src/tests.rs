@@ -0,0 +1,336 @@
+//! Tests of the pallets working together in the runtime.
+
+use crate::{
+	AccountId, Balances, BalancesConfig, BuildStorage, ClaimCouncil, Executive, Header, Proxy,
+	ProxyType, Runtime, RuntimeCall, RuntimeEvent, RuntimeGenesisConfig, RuntimeOrigin,
+	SignedExtra, SignedPayload, TemplateModule, TransactionPayment, UncheckedExtrinsic,
+	EXISTENTIAL_DEPOSIT,
+};
+use codec::Encode;
+use frame_support::{dispatch::GetDispatchInfo, traits::fungible::InspectHold};
+use pallet_template::{weights::WeightInfo, ClaimKind, HashAlgo, DEFAULT_NAMESPACE};
+use sp_keyring::AccountKeyring;
+use sp_runtime::{
+	generic::Era,
+	traits::{BlakeTwo256, Dispatchable, Hash as _, Header as _},
+};
+
+fn new_test_ext(endowed: &[AccountId]) -> sp_io::TestExternalities {
+	let storage = RuntimeGenesisConfig {
+		balances: BalancesConfig {
+			balances: endowed.iter().cloned().map(|k| (k, 1u128 << 60)).collect(),
+		},
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap();
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| frame_system::Pallet::<crate::Runtime>::set_block_number(1));
+	ext
+}
+
+/// Sign `call` as `signer`, with their next nonce and no tip, the way a wallet would.
+fn signed_extrinsic(signer: AccountKeyring, call: RuntimeCall) -> UncheckedExtrinsic {
+	let nonce = frame_system::Pallet::<Runtime>::account_nonce(signer.to_account_id());
+	let extra: SignedExtra = (
+		frame_system::CheckNonZeroSender::new(),
+		frame_system::CheckSpecVersion::new(),
+		frame_system::CheckTxVersion::new(),
+		frame_system::CheckGenesis::new(),
+		frame_system::CheckEra::from(Era::Immortal),
+		frame_system::CheckNonce::from(nonce),
+		frame_system::CheckWeight::new(),
+		pallet_transaction_payment::ChargeTransactionPayment::from(0),
+		pallet_template::CheckDuplicateClaim::new(),
+	);
+	let payload = SignedPayload::new(call, extra).unwrap();
+	let signature = payload.using_encoded(|payload| signer.sign(payload));
+	let (call, extra, _) = payload.deconstruct();
+	UncheckedExtrinsic::new_signed(call, signer.to_account_id().into(), signature.into(), extra)
+}
+
+#[test]
+fn create_claim_extrinsic_pays_fees_and_holds_the_deposit() {
+	let alice = AccountKeyring::Alice.to_account_id();
+	new_test_ext(core::slice::from_ref(&alice)).execute_with(|| {
+		Executive::initialize_block(&Header::new(
+			1,
+			Default::default(),
+			Default::default(),
+			Default::default(),
+			Default::default(),
+		));
+		let initial = Balances::free_balance(&alice);
+		let claim = BlakeTwo256::hash(b"signed document");
+		let xt = signed_extrinsic(
+			AccountKeyring::Alice,
+			RuntimeCall::TemplateModule(pallet_template::Call::create_claim {
+				namespace: DEFAULT_NAMESPACE,
+				claim,
+				kind: ClaimKind::Document,
+				algo: HashAlgo::Blake2_256,
+				metadata: None,
+				expiry: None,
+				lock_until: None,
+				cid: None,
+			}),
+		);
+		let info = xt.get_dispatch_info();
+		// The call must be weighed with the benchmarked weights, not the unit weights of `()`.
+		assert!(info
+			.weight
+			.all_gte(pallet_template::weights::SubstrateWeight::<Runtime>::create_claim()));
+		let fee = TransactionPayment::compute_fee(xt.encoded_size() as u32, &info, 0);
+		assert!(fee > 0);
+
+		assert_eq!(Executive::apply_extrinsic(xt), Ok(Ok(())));
+
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &claim), Some(alice.clone()));
+		let events: Vec<_> =
+			frame_system::Pallet::<Runtime>::events().into_iter().map(|r| r.event).collect();
+		// Part of the fee is refunded, since there was no expired claim to remove first.
+		let actual_fee = events
+			.iter()
+			.find_map(|event| match event {
+				RuntimeEvent::TransactionPayment(
+					pallet_transaction_payment::Event::TransactionFeePaid {
+						who,
+						actual_fee,
+						tip: 0,
+					},
+				) if *who == alice => Some(*actual_fee),
+				_ => None,
+			})
+			.unwrap();
+		assert!(actual_fee > 0 && actual_fee < fee);
+		let deposit = 10 * EXISTENTIAL_DEPOSIT;
+		let reason = pallet_template::HoldReason::ClaimDeposit.into();
+		assert_eq!(Balances::balance_on_hold(&reason, &alice), deposit);
+		assert_eq!(Balances::free_balance(&alice), initial - actual_fee - deposit);
+		assert!(events.contains(&RuntimeEvent::TemplateModule(
+			pallet_template::Event::ClaimCreated {
+				who: alice.clone(),
+				namespace: DEFAULT_NAMESPACE,
+				claim,
+				kind: ClaimKind::Document,
+				algo: HashAlgo::Blake2_256,
+				id: 0,
+				cid: None,
+			}
+		)));
+	});
+}
+
+#[test]
+fn the_pool_accepts_taking_over_an_expired_claim() {
+	use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
+
+	let [alice, bob] =
+		[AccountKeyring::Alice, AccountKeyring::Bob].map(|account| account.to_account_id());
+	new_test_ext(&[alice.clone(), bob.clone()]).execute_with(|| {
+		let claim = BlakeTwo256::hash(b"expiring document");
+		assert!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(alice.clone()),
+			DEFAULT_NAMESPACE,
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			Some(3),
+			None,
+			None,
+		)
+		.is_ok());
+		let take_over = || {
+			signed_extrinsic(
+				AccountKeyring::Bob,
+				RuntimeCall::TemplateModule(pallet_template::Call::create_claim {
+					namespace: DEFAULT_NAMESPACE,
+					claim,
+					kind: ClaimKind::Document,
+					algo: HashAlgo::Blake2_256,
+					metadata: None,
+					expiry: None,
+					lock_until: None,
+					cid: None,
+				}),
+			)
+		};
+		let validate = |xt| {
+			Executive::validate_transaction(TransactionSource::External, xt, Default::default())
+		};
+
+		// The pool validates against the next block, which is still before the expiry.
+		assert_eq!(validate(take_over()), Err(InvalidTransaction::Stale.into()));
+
+		// Once the claim has expired, the takeover gets into the pool before the sweep has
+		// removed the claim, and goes through.
+		frame_system::Pallet::<Runtime>::set_block_number(5);
+		assert!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &claim).is_some());
+		assert!(validate(take_over()).is_ok());
+		Executive::initialize_block(&Header::new(
+			6,
+			Default::default(),
+			Default::default(),
+			Default::default(),
+			Default::default(),
+		));
+		assert_eq!(Executive::apply_extrinsic(take_over()), Ok(Ok(())));
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &claim), Some(bob));
+	});
+}
+
+#[test]
+fn delegated_create_claim_is_owned_by_the_real_account() {
+	let real = AccountKeyring::Alice.to_account_id();
+	let delegate = AccountKeyring::Bob.to_account_id();
+	new_test_ext(&[real.clone(), delegate.clone()]).execute_with(|| {
+		let claim = BlakeTwo256::hash(b"cold wallet document");
+		let call = RuntimeCall::TemplateModule(pallet_template::Call::create_claim_default {
+			claim,
+			kind: ClaimKind::Document,
+			algo: HashAlgo::Blake2_256,
+			metadata: None,
+			expiry: None,
+			lock_until: None,
+			cid: None,
+		});
+
+		assert!(Proxy::add_proxy(
+			RuntimeOrigin::signed(real.clone()),
+			delegate.clone().into(),
+			ProxyType::Claims,
+			0,
+		)
+		.is_ok());
+		let proxied = RuntimeCall::Proxy(pallet_proxy::Call::proxy {
+			real: real.clone().into(),
+			force_proxy_type: None,
+			call: Box::new(call),
+		});
+		assert!(proxied.dispatch(RuntimeOrigin::signed(delegate.clone())).is_ok());
+		frame_system::Pallet::<Runtime>::assert_last_event(
+			pallet_proxy::Event::ProxyExecuted { result: Ok(()) }.into(),
+		);
+
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &claim), Some(real.clone()));
+		assert_eq!(TemplateModule::claims_of(&delegate), vec![]);
+		let reason = pallet_template::HoldReason::ClaimDeposit.into();
+		assert!(Balances::balance_on_hold(&reason, &real) > 0);
+		assert_eq!(Balances::balance_on_hold(&reason, &delegate), 0);
+
+		// A claims proxy cannot move the real account's funds.
+		let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+			dest: delegate.clone().into(),
+			value: EXISTENTIAL_DEPOSIT,
+		});
+		let proxied = RuntimeCall::Proxy(pallet_proxy::Call::proxy {
+			real: real.clone().into(),
+			force_proxy_type: None,
+			call: Box::new(transfer),
+		});
+		assert!(proxied.dispatch(RuntimeOrigin::signed(delegate.clone())).is_ok());
+		frame_system::Pallet::<Runtime>::assert_last_event(
+			pallet_proxy::Event::ProxyExecuted {
+				result: Err(frame_system::Error::<Runtime>::CallFiltered.into()),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn claim_council_vote_force_revokes_a_claim() {
+	let [alice, bob, charlie] =
+		[AccountKeyring::Alice, AccountKeyring::Bob, AccountKeyring::Charlie]
+			.map(|account| account.to_account_id());
+	new_test_ext(&[alice.clone(), bob.clone(), charlie.clone()]).execute_with(|| {
+		let members = vec![alice.clone(), bob.clone(), charlie.clone()];
+		assert!(ClaimCouncil::set_members(RuntimeOrigin::root(), members, None, 0).is_ok());
+		let claim = BlakeTwo256::hash(b"disputed document");
+		assert!(TemplateModule::create_claim_default(
+			RuntimeOrigin::signed(charlie.clone()),
+			claim,
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None,
+		)
+		.is_ok());
+
+		let proposal = RuntimeCall::TemplateModule(pallet_template::Call::force_revoke_claim {
+			namespace: DEFAULT_NAMESPACE,
+			claim,
+			slash: true,
+		});
+		let length = proposal.encoded_size() as u32;
+		let weight = proposal.get_dispatch_info().weight;
+		let hash = BlakeTwo256::hash_of(&proposal);
+		// Two of the three members must approve, so the proposal waits for a vote.
+		assert!(ClaimCouncil::propose(
+			RuntimeOrigin::signed(alice.clone()),
+			2,
+			Box::new(proposal),
+			length
+		)
+		.is_ok());
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &claim), Some(charlie.clone()));
+
+		for member in [&alice, &bob] {
+			assert!(
+				ClaimCouncil::vote(RuntimeOrigin::signed(member.clone()), hash, 0, true).is_ok()
+			);
+		}
+		assert!(ClaimCouncil::close(RuntimeOrigin::signed(bob), hash, 0, weight, length).is_ok());
+
+		assert_eq!(TemplateModule::owner_of(DEFAULT_NAMESPACE, &claim), None);
+		assert!(frame_system::Pallet::<Runtime>::events().iter().any(|record| matches!(
+			&record.event,
+			RuntimeEvent::TemplateModule(pallet_template::Event::ClaimForceRevoked { claim: revoked, .. })
+				if *revoked == claim
+		)));
+	});
+}
+
+#[test]
+fn verify_preimage_hashes_the_data_like_the_runtime() {
+	use pallet_template_runtime_api::runtime_decl_for_template_api::TemplateApiV11;
+
+	let alice = AccountKeyring::Alice.to_account_id();
+	new_test_ext(core::slice::from_ref(&alice)).execute_with(|| {
+		let data = b"signed document".to_vec();
+		assert!(TemplateModule::create_claim(
+			RuntimeOrigin::signed(alice.clone()),
+			DEFAULT_NAMESPACE,
+			BlakeTwo256::hash(&data),
+			ClaimKind::Document,
+			HashAlgo::Blake2_256,
+			None,
+			None,
+			None,
+			None,
+		)
+		.is_ok());
+
+		let verify = <Runtime as TemplateApiV11<
+			crate::Block,
+			AccountId,
+			crate::Hash,
+			crate::BlockNumber,
+		>>::verify_preimage;
+		assert_eq!(verify(None, data.clone()), Some((alice.clone(), 1)));
+		assert_eq!(verify(Some(DEFAULT_NAMESPACE), data.clone()), Some((alice, 1)));
+		assert_eq!(verify(None, b"tampered document".to_vec()), None);
+		assert_eq!(verify(Some(DEFAULT_NAMESPACE + 1), data), None);
+	});
+}
+
+#[test]
+fn the_pallets_pass_their_integrity_tests() {
+	use frame_support::traits::IntegrityTest;
+
+	sp_io::TestExternalities::default()
+		.execute_with(<crate::AllPalletsWithSystem as IntegrityTest>::integrity_test);
+}